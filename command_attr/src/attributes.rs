@@ -130,6 +130,51 @@ pub fn parse_values(attr: &Attribute) -> Result<Values> {
     }
 }
 
+/// Parses the `#[bucket]` option, which additionally accepts a maximum
+/// concurrency in its list form: `#[bucket(name, max_concurrency = n)]`.
+pub fn parse_bucket(attr: &Attribute) -> Result<(String, Option<u32>)> {
+    let meta = match attr.parse_meta()? {
+        Meta::NameValue(meta) => return Ok((meta.lit.to_str(), None)),
+        Meta::List(meta) => meta,
+        Meta::Path(_) => {
+            return Err(Error::new(
+                attr.span(),
+                "the attribute must be in of these forms:\n\
+                 0: `#[bucket = <name>]`\n\
+                 1: `#[bucket(<name>)]`\n\
+                 2: `#[bucket(<name>, max_concurrency = <n>)]`",
+            ))
+        }
+    };
+
+    let mut nested = meta.nested.into_iter();
+
+    let name = match nested.next() {
+        Some(NestedMeta::Lit(l)) => l.to_str(),
+        Some(NestedMeta::Meta(Meta::Path(path))) => to_ident(path)?.to_string(),
+        _ => return Err(Error::new(attr.span(), "expected the name of the bucket")),
+    };
+
+    let mut max_concurrency = None;
+
+    for meta in nested {
+        match meta {
+            NestedMeta::Meta(Meta::NameValue(meta)) if meta.path.is_ident("max_concurrency") => {
+                max_concurrency = Some(match &meta.lit {
+                    Lit::Int(l) if l.base10_parse::<u32>()? == 0 => {
+                        return Err(Error::new(l.span(), "`max_concurrency` must be at least 1"));
+                    }
+                    Lit::Int(l) => l.base10_parse::<u32>()?,
+                    l => return Err(Error::new(l.span(), "invalid integer")),
+                });
+            }
+            meta => return Err(Error::new(meta.span(), "expected `max_concurrency = <n>`")),
+        }
+    }
+
+    Ok((name, max_concurrency))
+}
+
 #[derive(Debug, Clone)]
 struct DisplaySlice<'a, T>(&'a [T]);
 
@@ -337,4 +382,4 @@ macro_rules! attr_option_num {
     }
 }
 
-attr_option_num!(u16, u32, usize);
+attr_option_num!(u16, u32, u64, usize);
@@ -8,7 +8,7 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{
     parse::{Error, Parse, ParseStream, Result},
     parse_macro_input, parse_quote,
@@ -75,16 +75,43 @@ macro_rules! match_options {
 /// | `#[allowed_roles(roles)]`                                                    | Set of roles the user must possess.                                                                      | `roles` is a comma separated list of role names.                                                                                                                                                                                 |
 /// | `#[help_available]` </br> `#[help_available(b)]`                             | If the command should be displayed in the help message.                                                  | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                                                                                                                                                  |
 /// | `#[only_in(ctx)]`                                                            | Which environment the command can be executed in.                                                        | `ctx` is a string with the accepted values `guild`/`guilds` and `dm`/`dms` (Direct Message).                                                                                                                                     |
-/// | `#[bucket(name)]` </br> `#[bucket = name]`                                   | What bucket will impact this command.                                                                    | `name` is a string containing the bucket's name.</br> Refer to [the bucket example in the standard framework](https://docs.rs/serenity/*/serenity/framework/standard/struct.StandardFramework.html#method.bucket) for its usage. |
+/// | `#[bucket(name)]` </br> `#[bucket = name]` </br> `#[bucket(name, max_concurrency = n)]` | What bucket will impact this command.                                                         | `name` is a string containing the bucket's name.</br> `n` is the maximum number of invocations of this command that may run at the same time, overriding the bucket's.</br> Refer to [the bucket example in the standard framework](https://docs.rs/serenity/*/serenity/framework/standard/struct.StandardFramework.html#method.bucket) for its usage. |
 /// | `#[owners_only]` </br> `#[owners_only(b)]`                                   | If this command is exclusive to owners.                                                                  | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                                                                                                                                                  |
 /// | `#[owner_privilege]` </br> `#[owner_privilege(b)]`                           | If owners can bypass certain options.                                                                    | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                                                                                                                                                  |
 /// | `#[sub_commands(commands)]`                                                  | The sub or children commands of this command. They are executed in the form: `this-command sub-command`. | `commands` is a comma separated list of identifiers referencing functions marked by the `#[command]` macro.                                                                                                                      |
+/// | `#[default_sub_command(cmd)]`                                                | A command to execute if none of the sub commands of this command are given.                              | `cmd` is an identifier referencing a function marked by the `#[command]` macro.                                                                                                                                                  |
 ///
 /// Documentation comments (`///`) applied onto the function are interpreted as sugar for the
 /// `#[description]` option. When more than one application of the option is performed,
 /// the text is delimited by newlines. This mimics the behaviour of regular doc-comments,
 /// which are sugar for the `#[doc = "..."]` attribute.
 ///
+/// ## Typed parameters
+///
+/// Instead of taking the raw `Args`, a command may declare typed parameters after the context
+/// and the message, which are parsed from the arguments in order:
+///
+/// - `T` requires an argument, converted via `ArgumentConvert`, which covers all `FromStr` types
+///   as well as models like `Member` or `Role` that are looked up by mention, ID, or name.
+/// - `Option<T>` is `None` if there is no argument or it cannot be converted, in which case the
+///   argument is left for the next parameter.
+/// - `Rest<T>` converts all of the remaining arguments as one, and must be the last parameter.
+///   `Option<Rest<T>>` allows them to be absent.
+///
+/// ```rust,ignore
+/// #[command]
+/// async fn warn(ctx: &Context, msg: &Message, user: UserId, amount: Option<u32>, reason: Rest<String>) -> CommandResult {
+///     ...
+/// }
+/// ```
+///
+/// The parameters are parsed before the command's bucket and the framework's `before` hook
+/// are applied. If an argument is missing or cannot be parsed, the command is not run and
+/// `DispatchError::ArgumentParse` is passed to the framework's dispatch error hook.
+/// Unless given, the `usage` (here `<user> [amount] <reason...>`) is generated from the parameters,
+/// as is `min_args` from the number of required parameters, and `max_args` when there is no
+/// `Rest` parameter.
+///
 /// # Notes
 /// The name of the command is parsed from the applied function,
 /// or may be specified inside the `#[command]` attribute, a lá `#[command("foobar")]`.
@@ -112,6 +139,16 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
 
     for attribute in &fun.attributes {
         let span = attribute.span();
+
+        if attribute.path.is_ident("bucket") {
+            let (bucket, max_concurrency) = propagate_err!(parse_bucket(attribute));
+
+            options.bucket = AsOption(Some(bucket));
+            options.max_concurrency = AsOption(max_concurrency);
+
+            continue;
+        }
+
         let values = propagate_err!(parse_values(attribute));
 
         let name = values.name.to_string();
@@ -143,7 +180,6 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
             _ => {
                 match_options!(name, values, options, span => [
                     checks;
-                    bucket;
                     aliases;
                     delimiters;
                     usage;
@@ -155,7 +191,8 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
                     only_in;
                     owners_only;
                     owner_privilege;
-                    sub_commands
+                    sub_commands;
+                    default_sub_command
                 ]);
             }
         }
@@ -164,6 +201,7 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
     let Options {
         checks,
         bucket,
+        max_concurrency,
         aliases,
         description,
         delimiters,
@@ -178,8 +216,34 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
         owners_only,
         owner_privilege,
         sub_commands,
+        default_sub_command,
     } = options;
 
+    let parameters = propagate_err!(create_parameters(&mut fun));
+
+    let (usage, min_args, max_args, parsing) = if parameters.is_empty() {
+        (usage, min_args, max_args, None)
+    } else {
+        let usage = usage.0.unwrap_or_else(|| generate_usage(&parameters));
+        let takes_rest = parameters
+            .iter()
+            .any(|(_, kind)| *kind == ParameterKind::Rest || *kind == ParameterKind::OptionalRest);
+        let required = parameters
+            .iter()
+            .filter(|(_, kind)| *kind == ParameterKind::Required || *kind == ParameterKind::Rest)
+            .count();
+
+        let min_args = AsOption(min_args.0.or(Some(required as u16)));
+        let max_args = match max_args.0 {
+            None if !takes_rest => AsOption(Some(parameters.len() as u16)),
+            max_args => AsOption(max_args),
+        };
+
+        let parsing = generate_parameter_parsing(&fun, &parameters, &usage);
+
+        (AsOption(Some(usage)), min_args, max_args, Some(parsing))
+    };
+
     propagate_err!(create_declaration_validations(&mut fun, DeclarFor::Command));
 
     let res = parse_quote!(serenity::framework::standard::CommandResult);
@@ -192,6 +256,11 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
         .into_iter()
         .map(|i| i.with_suffix(COMMAND))
         .collect::<Vec<_>>();
+    let default_sub_command = default_sub_command.map(|ident| {
+        let i = ident.with_suffix(COMMAND);
+
+        quote!(&#i)
+    });
     let body = fun.body;
     let ret = fun.ret;
 
@@ -202,15 +271,64 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
 
     let options_path = quote!(serenity::framework::standard::CommandOptions);
     let command_path = quote!(serenity::framework::standard::Command);
+    let command_error = quote!(serenity::framework::standard::CommandError);
 
     populate_fut_lifetimes_on_refs(&mut fun.args);
     let args = fun.args;
 
+    // With typed parameters, the arguments are parsed by a separate function
+    // the framework runs before applying the command's bucket, returning the
+    // command's body to run afterwards.
+    let (prepare, fun) = match parsing {
+        Some(parsing) => {
+            let prepare_name = format_ident!("__{}_prepare", name);
+            let (ctx, ctx_kind) = (&args[0].name, &args[0].kind);
+            let (msg, msg_kind) = (&args[1].name, &args[1].kind);
+
+            let fun = quote! {
+                #[doc(hidden)]
+                #visibility fn #prepare_name<'fut> (#(#args),*) -> ::serenity::futures::future::BoxFuture<'fut, ::std::result::Result<::serenity::futures::future::BoxFuture<'fut, #ret>, #command_error>> {
+                    use ::serenity::futures::future::FutureExt;
+
+                    async move {
+                        #(#parsing)*
+
+                        Ok(async move { #(#body)* }.boxed())
+                    }.boxed()
+                }
+
+                #visibility fn #name<'fut> (
+                    #ctx: #ctx_kind,
+                    #msg: #msg_kind,
+                    __args: serenity::framework::standard::Args,
+                ) -> ::serenity::futures::future::BoxFuture<'fut, #ret> {
+                    use ::serenity::futures::future::FutureExt;
+
+                    async move { #prepare_name(#ctx, #msg, __args).await?.await }.boxed()
+                }
+            };
+
+            (quote!(Some(#prepare_name)), fun)
+        },
+        None => {
+            let fun = quote! {
+                #visibility fn #name<'fut> (#(#args),*) -> ::serenity::futures::future::BoxFuture<'fut, #ret> {
+                    use ::serenity::futures::future::FutureExt;
+
+                    async move { #(#body)* }.boxed()
+                }
+            };
+
+            (quote!(None), fun)
+        },
+    };
+
     (quote! {
         #(#cooked)*
         pub static #options: #options_path = #options_path {
             checks: #checks,
             bucket: #bucket,
+            max_concurrency: #max_concurrency,
             names: &[#_name, #(#aliases),*],
             desc: #description,
             delimiters: &[#(#delimiters),*],
@@ -225,19 +343,17 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
             owners_only: #owners_only,
             owner_privilege: #owner_privilege,
             sub_commands: &[#(&#sub_commands),*],
+            default_sub_command: #default_sub_command,
         };
 
         #(#cooked2)*
         pub static #n: #command_path = #command_path {
             fun: #name,
+            prepare: #prepare,
             options: &#options,
         };
 
-        #visibility fn #name<'fut> (#(#args),*) -> ::serenity::futures::future::BoxFuture<'fut, #ret> {
-            use ::serenity::futures::future::FutureExt;
-
-            async move { #(#body)* }.boxed()
-        }
+        #fun
     })
     .into()
 }
@@ -259,6 +375,7 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
 /// | `#[ungrouped_label(s)]` </br> `#[ungrouped_label = s]`                                                                                        | Ungrouped commands label.                                                                                                                                                                                                                        | `s` is a string                                                                                            |
 /// | `#[grouped_label(s)]` </br> `#[grouped_label = s]`                                                                                            | Grouped commands label.                                                                                                                                                                                                                          | `s` is a string                                                                                            |
 /// | `#[sub_commands_label(s)]` </br> `#[sub_commands_label = s]`                                                                                  | Sub commands label.                                                                                                          | `s` is a string
+/// | `#[default_sub_command_label(s)]` </br> `#[default_sub_command_label = s]`                                                                    | Label marking the default sub command of a command.                                                                          | `s` is a string
 /// | `#[description_label(s)]` </br> `#[description_label = s]`                                                                                    | Label at the start of the description.                                                                                                                                                                                                           | `s` is a string                                                                                            |
 /// | `#[aliases_label(s)]` </br> `#[aliases_label= s]`                                                                                             | Label for a command's aliases.                                                                                                                                                                                                                   | `s` is a string                                                                                            |
 /// | `#[guild_only_text(s)]` </br> `#[guild_only_text = s]`                                                                                        | When a command is specific to guilds only.                                                                                                                                                                                                       | `s` is a string                                                                                            |
@@ -278,6 +395,7 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
 /// | `#[embed_success_colour(n)]`                                                                                                                  | Colour that the help-embed will use normally.                                                                                                                                                                                                    | `n` is a name to one of the provided constants of the `Colour` struct.                                     |
 /// | `#[max_levenshtein_distance(n)]`                                                                                                              | How much should the help command search for a similiar name.</br> Indicator for a nested guild. The prefix will be repeated based on what kind of level the item sits. A sub-group would be level two, a sub-sub-group would be level three.     | `n` is a 64-bit, unsigned integer.                                                                         |
 /// | `#[indention_prefix(s)]` </br> `#[indention_prefix = s]`                                                                                      | The prefix used to express how deeply nested a command or group is.                                                                                                                                                                              | `s` is a string                                                                                            |
+/// | `#[page_timeout(n)]`                                                                                                                          | How many seconds the paginated help waits for reactions to turn its pages.                                                                                                                                                                       | `n` is a 64-bit, unsigned integer.                                                                         |
 ///
 /// [`command`]: attr.command.html
 #[proc_macro_attribute]
@@ -336,8 +454,10 @@ pub fn help(attr: TokenStream, input: TokenStream) -> TokenStream {
             strikethrough_commands_tip_in_dm;
             strikethrough_commands_tip_in_guild;
             sub_commands_label;
+            default_sub_command_label;
             max_levenshtein_distance;
-            indention_prefix
+            indention_prefix;
+            page_timeout
         ]);
     }
 
@@ -419,6 +539,7 @@ pub fn help(attr: TokenStream, input: TokenStream) -> TokenStream {
         guild_only_text,
         checks_label,
         sub_commands_label,
+        default_sub_command_label,
         dm_only_text,
         dm_and_guild_text,
         available_text,
@@ -436,6 +557,7 @@ pub fn help(attr: TokenStream, input: TokenStream) -> TokenStream {
         embed_success_colour,
         max_levenshtein_distance,
         indention_prefix,
+        page_timeout,
     } = options;
 
     let strikethrough_commands_tip_in_dm = AsOption(strikethrough_commands_tip_in_dm);
@@ -477,6 +599,7 @@ pub fn help(attr: TokenStream, input: TokenStream) -> TokenStream {
             guild_only_text: #guild_only_text,
             checks_label: #checks_label,
             sub_commands_label: #sub_commands_label,
+            default_sub_command_label: #default_sub_command_label,
             dm_only_text: #dm_only_text,
             dm_and_guild_text: #dm_and_guild_text,
             available_text: #available_text,
@@ -494,6 +617,7 @@ pub fn help(attr: TokenStream, input: TokenStream) -> TokenStream {
             embed_success_colour: #embed_success_colour,
             max_levenshtein_distance: #max_levenshtein_distance,
             indention_prefix: #indention_prefix,
+            page_timeout: #page_timeout,
         };
 
         #(#cooked2)*
@@ -765,7 +889,7 @@ pub fn check(_attr: TokenStream, input: TokenStream) -> TokenStream {
     (quote! {
         pub static #name: #check = #check {
             name: #n2,
-            function: #n,
+            function: serenity::framework::standard::CheckFunction::Static(#n),
             display_in_help: #display_in_help,
             check_in_help: #check_in_help
         };
@@ -451,6 +451,7 @@ impl ToTokens for Checks {
 pub struct Options {
     pub checks: Checks,
     pub bucket: AsOption<String>,
+    pub max_concurrency: AsOption<u32>,
     pub aliases: Vec<String>,
     pub description: AsOption<String>,
     pub delimiters: Vec<String>,
@@ -465,6 +466,7 @@ pub struct Options {
     pub owners_only: bool,
     pub owner_privilege: bool,
     pub sub_commands: Vec<Ident>,
+    pub default_sub_command: AsOption<Ident>,
 }
 
 impl Options {
@@ -518,6 +520,7 @@ pub struct HelpOptions {
     pub grouped_label: String,
     pub aliases_label: String,
     pub sub_commands_label: String,
+    pub default_sub_command_label: String,
     pub guild_only_text: String,
     pub checks_label: String,
     pub dm_only_text: String,
@@ -537,6 +540,7 @@ pub struct HelpOptions {
     pub embed_success_colour: Colour,
     pub max_levenshtein_distance: usize,
     pub indention_prefix: String,
+    pub page_timeout: u64,
 }
 
 impl Default for HelpOptions {
@@ -553,6 +557,7 @@ impl Default for HelpOptions {
             guild_only_text: "Only in guilds".to_string(),
             checks_label: "Checks".to_string(),
             sub_commands_label: "Sub Commands".to_string(),
+            default_sub_command_label: "Default".to_string(),
             dm_only_text: "Only in DM".to_string(),
             dm_and_guild_text: "In DM and guilds".to_string(),
             available_text: "Available".to_string(),
@@ -572,6 +577,7 @@ impl Default for HelpOptions {
             embed_success_colour: Colour::from_str("ROSEWATER").unwrap(),
             max_levenshtein_distance: 0,
             indention_prefix: "-".to_string(),
+            page_timeout: 120,
         }
     }
 }
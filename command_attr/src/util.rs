@@ -10,7 +10,7 @@ use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
     token::{Comma, Mut},
-    Ident, Lifetime, Lit, Type,
+    GenericArgument, Ident, Lifetime, Lit, PathArguments, Type,
 };
 
 pub trait LitExt {
@@ -233,6 +233,135 @@ pub fn create_declaration_validations(fun: &mut CommandFun, dec_for: DeclarFor)
     Ok(())
 }
 
+/// How the argument of a typed command parameter is parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterKind {
+    /// `T`
+    Required,
+    /// `Option<T>`
+    Optional,
+    /// `Rest<T>`
+    Rest,
+    /// `Option<Rest<T>>`
+    OptionalRest,
+}
+
+/// Returns the type argument of `ty` if it is `name<T>`, e.g. `Option<T>`.
+fn generic_argument<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let segment = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+
+    if segment.ident != name {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_args(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.path.segments.last().map_or(false, |s| s.ident == "Args"),
+        _ => false,
+    }
+}
+
+/// Takes the typed parameters following the context and the message off a
+/// command's arguments, replacing them with the `Args` they are parsed from.
+///
+/// Returns no parameters if the command takes `Args` itself.
+pub fn create_parameters(fun: &mut CommandFun) -> SynResult<Vec<(Argument, ParameterKind)>> {
+    if fun.args.len() < 3 || (fun.args.len() == 3 && is_args(&fun.args[2].kind)) {
+        return Ok(Vec::new());
+    }
+
+    let mut parameters: Vec<(Argument, ParameterKind)> = Vec::new();
+
+    // The parameters are converted with the context and the message.
+    for (arg, name) in fun.args.iter_mut().zip(&["__ctx", "__msg"]) {
+        if arg.name == "_" {
+            arg.name = Ident::new(name, arg.name.span());
+        }
+    }
+
+    for arg in fun.args.split_off(2) {
+        if let Some((last, ParameterKind::Rest)) | Some((last, ParameterKind::OptionalRest)) = parameters.last() {
+            return Err(Error::new(
+                arg.name.span(),
+                format_args!("no parameter may follow `{}`, as it takes the rest of the arguments", last.name),
+            ));
+        }
+
+        let kind = match generic_argument(&arg.kind, "Option") {
+            Some(inner) if generic_argument(inner, "Rest").is_some() => ParameterKind::OptionalRest,
+            Some(_) => ParameterKind::Optional,
+            None if generic_argument(&arg.kind, "Rest").is_some() => ParameterKind::Rest,
+            None => ParameterKind::Required,
+        };
+
+        parameters.push((arg, kind));
+    }
+
+    fun.args.push(Argument {
+        mutable: Some(Mut::default()),
+        name: Ident::new("__args", Span::call_site()),
+        kind: parse_quote!(serenity::framework::standard::Args),
+    });
+
+    Ok(parameters)
+}
+
+/// The usage of a command with typed parameters, e.g. `<user> [amount] <reason...>`.
+pub fn generate_usage(parameters: &[(Argument, ParameterKind)]) -> String {
+    parameters
+        .iter()
+        .map(|(arg, kind)| {
+            let name = arg.name.to_string();
+            let name = name.trim_start_matches('_');
+
+            match kind {
+                ParameterKind::Required => format!("<{}>", name),
+                ParameterKind::Optional => format!("[{}]", name),
+                ParameterKind::Rest => format!("<{}...>", name),
+                ParameterKind::OptionalRest => format!("[{}...]", name),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Statements converting each typed parameter from `__args`, returning early
+/// with an error the framework reports as `DispatchError::ArgumentParse`.
+pub fn generate_parameter_parsing(fun: &CommandFun, parameters: &[(Argument, ParameterKind)], usage: &str) -> Vec<syn::Stmt> {
+    let ctx = &fun.args[0].name;
+    let msg = &fun.args[1].name;
+
+    parameters
+        .iter()
+        .map(|(arg, kind)| {
+            let Argument { mutable, name, kind: ty } = arg;
+            let parameter = name.to_string();
+            let parse = match kind {
+                ParameterKind::Required => quote!(__parameter),
+                ParameterKind::Optional => quote!(__optional_parameter),
+                ParameterKind::Rest => quote!(__rest_parameter),
+                ParameterKind::OptionalRest => quote!(__optional_rest_parameter),
+            };
+
+            parse_quote! {
+                let #mutable #name: #ty = __args.#parse(#ctx, #msg, #parameter, #usage).await?;
+            }
+        })
+        .collect()
+}
+
 #[inline]
 pub fn create_return_type_validation(r#fn: &mut CommandFun, expect: Type) {
     let stmt = generate_type_validation(r#fn.ret.clone(), expect);
@@ -138,6 +138,18 @@ impl CreateAllowedMentions {
         }
         self
     }
+
+    /// Sets whether the author of the message replied to, set via
+    /// [`CreateMessage::reference_message`], is mentioned.
+    ///
+    /// Defaults to `false` on Discord's side.
+    ///
+    /// [`CreateMessage::reference_message`]: struct.CreateMessage.html#method.reference_message
+    #[inline]
+    pub fn replied_user(&mut self, mention_user: bool) -> &mut Self {
+        self.0.insert("replied_user", Value::Bool(mention_user));
+        self
+    }
 }
 
 impl Default for CreateAllowedMentions {
@@ -13,7 +13,7 @@ use std::collections::HashMap;
 /// [`Guild`]: ../model/guild/struct.Guild.html
 /// [`name`]: #method.name
 #[derive(Debug, Clone)]
-pub struct CreateChannel(pub HashMap<&'static str, Value>);
+pub struct CreateChannel(pub HashMap<&'static str, Value>, pub(crate) Option<String>);
 
 impl CreateChannel {
     /// Specify how to call this new channel.
@@ -142,6 +142,12 @@ impl CreateChannel {
 
         self
     }
+
+    /// The reason shown in the guild's audit log for creating the channel.
+    pub fn audit_log_reason<S: ToString>(&mut self, reason: S) -> &mut Self {
+        self.1 = Some(reason.to_string());
+        self
+    }
 }
 
 impl Default for CreateChannel {
@@ -157,7 +163,7 @@ impl Default for CreateChannel {
     /// let channel_builder = CreateChannel::default();
     /// ```
     fn default() -> Self {
-        let mut builder = CreateChannel(HashMap::new());
+        let mut builder = CreateChannel(HashMap::new(), None);
         builder.kind(ChannelType::Text);
 
         builder
@@ -68,7 +68,7 @@ use serde_json::Value;
 /// [`GuildChannel::create_invite`]: ../model/channel/struct.GuildChannel.html#method.create_invite
 /// [`RichInvite`]: ../model/invite/struct.RichInvite.html
 #[derive(Clone, Debug)]
-pub struct CreateInvite(pub HashMap<&'static str, Value>);
+pub struct CreateInvite(pub HashMap<&'static str, Value>, pub(crate) Option<String>);
 
 impl CreateInvite {
     /// The duration that the invite will be valid for.
@@ -206,6 +206,12 @@ impl CreateInvite {
         self.0.insert("unique", Value::Bool(unique));
         self
     }
+
+    /// The reason shown in the guild's audit log for creating the invite.
+    pub fn audit_log_reason<S: ToString>(&mut self, reason: S) -> &mut Self {
+        self.1 = Some(reason.to_string());
+        self
+    }
 }
 
 impl Default for CreateInvite {
@@ -224,6 +230,6 @@ impl Default for CreateInvite {
         let mut map = HashMap::new();
         map.insert("validate", Value::Null);
 
-        CreateInvite(map)
+        CreateInvite(map, None)
     }
 }
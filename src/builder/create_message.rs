@@ -1,6 +1,6 @@
 use crate::internal::prelude::*;
 use crate::http::AttachmentType;
-use crate::model::channel::ReactionType;
+use crate::model::channel::{MessageReference, ReactionType};
 use super::CreateEmbed;
 use super::CreateAllowedMentions;
 use crate::utils;
@@ -135,6 +135,55 @@ impl<'a> CreateMessage<'a> {
         self.0.insert("allowed_mentions", allowed_mentions);
         self
     }
+
+    /// Set the reference message this message is a reply to.
+    ///
+    /// Whether the author of the referenced message is mentioned is controlled
+    /// by [`CreateAllowedMentions::replied_user`].
+    ///
+    /// [`CreateAllowedMentions::replied_user`]: struct.CreateAllowedMentions.html#method.replied_user
+    #[inline]
+    pub fn reference_message(&mut self, reference: impl Into<MessageReference>) -> &mut Self {
+        self._reference_message(reference.into());
+        self
+    }
+
+    fn _reference_message(&mut self, reference: MessageReference) {
+        let entry = self.0
+            .entry("message_reference")
+            .or_insert_with(|| Value::Object(JsonMap::new()));
+
+        if let Value::Object(ref mut map) = *entry {
+            map.insert("channel_id".to_string(), Value::Number(Number::from(reference.channel_id.0)));
+
+            if let Some(message_id) = reference.message_id {
+                map.insert("message_id".to_string(), Value::Number(Number::from(message_id.0)));
+            }
+
+            if let Some(guild_id) = reference.guild_id {
+                map.insert("guild_id".to_string(), Value::Number(Number::from(guild_id.0)));
+            }
+        }
+    }
+
+    /// Set whether sending the message should fail if the message set via
+    /// [`reference_message`] does not exist.
+    ///
+    /// Defaults to `true` on Discord's side. If set to `false`, the message is
+    /// sent as a regular message instead.
+    ///
+    /// [`reference_message`]: #method.reference_message
+    pub fn fail_if_not_exists(&mut self, fail_if_not_exists: bool) -> &mut Self {
+        let reference = self.0
+            .entry("message_reference")
+            .or_insert_with(|| Value::Object(JsonMap::new()));
+
+        if let Value::Object(ref mut reference) = *reference {
+            reference.insert("fail_if_not_exists".to_string(), Value::Bool(fail_if_not_exists));
+        }
+
+        self
+    }
 }
 
 impl<'a> Default for CreateMessage<'a> {
@@ -27,7 +27,7 @@ use std::collections::HashMap;
 /// [`GuildChannel`]: ../model/channel/struct.GuildChannel.html
 /// [`GuildChannel::edit`]: ../model/channel/struct.GuildChannel.html#method.edit
 #[derive(Clone, Debug, Default)]
-pub struct EditChannel(pub HashMap<&'static str, Value>);
+pub struct EditChannel(pub HashMap<&'static str, Value>, pub(crate) Option<String>);
 
 impl EditChannel {
     /// The bitrate of the channel in bits.
@@ -115,4 +115,10 @@ impl EditChannel {
 
         self
     }
+
+    /// The reason shown in the guild's audit log for editing the channel.
+    pub fn audit_log_reason<S: ToString>(&mut self, reason: S) -> &mut Self {
+        self.1 = Some(reason.to_string());
+        self
+    }
 }
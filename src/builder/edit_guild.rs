@@ -12,7 +12,7 @@ use std::collections::HashMap;
 /// [`Guild`]: ../model/guild/struct.Guild.html
 /// [Manage Guild]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
 #[derive(Clone, Debug, Default)]
-pub struct EditGuild(pub HashMap<&'static str, Value>);
+pub struct EditGuild(pub HashMap<&'static str, Value>, pub(crate) Option<String>);
 
 impl EditGuild {
     /// Set the "AFK voice channel" that users are to move to if they have been
@@ -196,4 +196,10 @@ impl EditGuild {
         let num = Value::Number(Number::from(verification_level.num()));
         self.0.insert("verification_level", num);
     }
+
+    /// The reason shown in the audit log for editing the guild.
+    pub fn audit_log_reason<S: ToString>(&mut self, reason: S) -> &mut Self {
+        self.1 = Some(reason.to_string());
+        self
+    }
 }
@@ -0,0 +1,129 @@
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+use crate::utils;
+use std::collections::HashMap;
+
+/// A builder to specify the fields to edit in a [`GuildWelcomeScreen`], to be
+/// used in conjunction with [`GuildId::edit_welcome_screen`].
+///
+/// **Note**: Editing the welcome screen requires that the current user have
+/// the [Manage Guild] permission.
+///
+/// # Examples
+///
+/// Enable the welcome screen and point new members to the rules channel:
+///
+/// ```rust,ignore
+/// guild_id.edit_welcome_screen(&http, |w| {
+///     w.enabled(true)
+///         .description("A place to talk about crabs.")
+///         .add_welcome_channel(|c| {
+///             c.id(rules_channel_id)
+///                 .description("Read the rules first")
+///                 .emoji(ReactionType::Unicode("📜".to_string()))
+///         })
+/// }).await?;
+/// ```
+///
+/// [`GuildId::edit_welcome_screen`]: ../model/id/struct.GuildId.html#method.edit_welcome_screen
+/// [`GuildWelcomeScreen`]: ../model/guild/struct.GuildWelcomeScreen.html
+/// [Manage Guild]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+#[derive(Clone, Debug, Default)]
+pub struct EditGuildWelcomeScreen(pub HashMap<&'static str, Value>, pub(crate) Option<String>);
+
+impl EditGuildWelcomeScreen {
+    /// Whether the welcome screen is shown to new members.
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        self.0.insert("enabled", Value::Bool(enabled));
+        self
+    }
+
+    /// The server description shown in the welcome screen.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// Adds a channel to the welcome screen. Up to 5 channels may be shown.
+    ///
+    /// Refer to the documentation for [`CreateGuildWelcomeChannel`] for more
+    /// information.
+    ///
+    /// [`CreateGuildWelcomeChannel`]: struct.CreateGuildWelcomeChannel.html
+    pub fn add_welcome_channel<F>(&mut self, f: F) -> &mut Self
+        where F: FnOnce(&mut CreateGuildWelcomeChannel) -> &mut CreateGuildWelcomeChannel {
+        let mut channel = CreateGuildWelcomeChannel::default();
+        f(&mut channel);
+
+        let map = utils::hashmap_to_json_map(channel.0);
+
+        let entry = self.0
+            .entry("welcome_channels")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut inner) = *entry {
+            inner.push(Value::Object(map));
+        }
+
+        self
+    }
+
+    /// Removes all channels from the welcome screen.
+    pub fn clear_welcome_channels(&mut self) -> &mut Self {
+        self.0.insert("welcome_channels", Value::Array(vec![]));
+        self
+    }
+
+    /// The reason shown in the audit log for editing the welcome screen.
+    pub fn audit_log_reason<S: ToString>(&mut self, reason: S) -> &mut Self {
+        self.1 = Some(reason.to_string());
+        self
+    }
+}
+
+/// A builder to create a channel entry of a [`GuildWelcomeScreen`], used by
+/// [`EditGuildWelcomeScreen::add_welcome_channel`].
+///
+/// [`EditGuildWelcomeScreen::add_welcome_channel`]: struct.EditGuildWelcomeScreen.html#method.add_welcome_channel
+/// [`GuildWelcomeScreen`]: ../model/guild/struct.GuildWelcomeScreen.html
+#[derive(Clone, Debug, Default)]
+pub struct CreateGuildWelcomeChannel(pub HashMap<&'static str, Value>);
+
+impl CreateGuildWelcomeChannel {
+    /// The Id of the channel to show.
+    ///
+    /// **Note**: This field is required.
+    #[inline]
+    pub fn id<C: Into<ChannelId>>(&mut self, channel_id: C) -> &mut Self {
+        self.0.insert("channel_id", Value::String(channel_id.into().0.to_string()));
+        self
+    }
+
+    /// The description shown for the channel.
+    ///
+    /// **Note**: This field is required.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// The emoji shown next to the channel.
+    pub fn emoji<R: Into<ReactionType>>(&mut self, emoji: R) -> &mut Self {
+        self._emoji(emoji.into());
+        self
+    }
+
+    fn _emoji(&mut self, emoji: ReactionType) {
+        match emoji {
+            ReactionType::Custom { id, name, .. } => {
+                self.0.insert("emoji_id", Value::String(id.0.to_string()));
+                self.0.insert("emoji_name", name.map_or(Value::Null, Value::String));
+            },
+            ReactionType::Unicode(name) => {
+                self.0.insert("emoji_id", Value::Null);
+                self.0.insert("emoji_name", Value::String(name));
+            },
+            ReactionType::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
@@ -8,7 +8,7 @@ use std::collections::HashMap;
 /// [`Member`]: ../model/guild/struct.Member.html
 /// [`Member::edit`]: ../model/guild/struct.Member.html#method.edit
 #[derive(Clone, Debug, Default)]
-pub struct EditMember(pub HashMap<&'static str, Value>);
+pub struct EditMember(pub HashMap<&'static str, Value>, pub(crate) Option<String>);
 
 impl EditMember {
     /// Whether to deafen the member.
@@ -88,4 +88,10 @@ impl EditMember {
 
         self
     }
+
+    /// The reason shown in the guild's audit log for editing the member.
+    pub fn audit_log_reason<S: ToString>(&mut self, reason: S) -> &mut Self {
+        self.1 = Some(reason.to_string());
+        self
+    }
 }
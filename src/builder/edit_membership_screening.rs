@@ -0,0 +1,144 @@
+use crate::internal::prelude::*;
+use crate::model::guild::MembershipScreeningFieldType;
+use crate::utils;
+use std::collections::HashMap;
+
+/// A builder to specify the fields to edit in a guild's
+/// [`MembershipScreening`] form, to be used in conjunction with
+/// [`GuildId::edit_membership_screening`].
+///
+/// **Note**: Editing the membership screening form requires that the current
+/// user have the [Manage Guild] permission.
+///
+/// # Examples
+///
+/// Require new members to agree to the server rules:
+///
+/// ```rust,ignore
+/// use serenity::model::guild::MembershipScreeningFieldType;
+///
+/// guild_id.edit_membership_screening(&http, |s| {
+///     s.enabled(true)
+///         .add_form_field(|f| {
+///             f.kind(MembershipScreeningFieldType::Terms)
+///                 .label("Read and agree to the server rules")
+///                 .values(vec!["Be nice", "No spam"])
+///                 .required(true)
+///         })
+/// }).await?;
+/// ```
+///
+/// [`GuildId::edit_membership_screening`]: ../model/id/struct.GuildId.html#method.edit_membership_screening
+/// [`MembershipScreening`]: ../model/guild/struct.MembershipScreening.html
+/// [Manage Guild]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+#[derive(Clone, Debug, Default)]
+pub struct EditMembershipScreening(pub HashMap<&'static str, Value>, pub(crate) Option<String>);
+
+impl EditMembershipScreening {
+    /// Whether membership screening is enabled for the guild.
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        self.0.insert("enabled", Value::Bool(enabled));
+        self
+    }
+
+    /// The guild description shown in the form.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// Adds a question to the form.
+    ///
+    /// Refer to the documentation for [`CreateMembershipScreeningField`] for
+    /// more information.
+    ///
+    /// [`CreateMembershipScreeningField`]: struct.CreateMembershipScreeningField.html
+    pub fn add_form_field<F>(&mut self, f: F) -> &mut Self
+        where F: FnOnce(&mut CreateMembershipScreeningField) -> &mut CreateMembershipScreeningField {
+        let mut field = CreateMembershipScreeningField::default();
+        f(&mut field);
+
+        let map = utils::hashmap_to_json_map(field.0);
+
+        let entry = self.0
+            .entry("form_fields")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut inner) = *entry {
+            inner.push(Value::Object(map));
+        }
+
+        self
+    }
+
+    /// Removes all questions from the form.
+    pub fn clear_form_fields(&mut self) -> &mut Self {
+        self.0.insert("form_fields", Value::Array(vec![]));
+        self
+    }
+
+    /// The reason shown in the audit log for editing the screening form.
+    pub fn audit_log_reason<S: ToString>(&mut self, reason: S) -> &mut Self {
+        self.1 = Some(reason.to_string());
+        self
+    }
+}
+
+/// A builder to create a question of a [`MembershipScreening`] form, used by
+/// [`EditMembershipScreening::add_form_field`].
+///
+/// [`EditMembershipScreening::add_form_field`]: struct.EditMembershipScreening.html#method.add_form_field
+/// [`MembershipScreening`]: ../model/guild/struct.MembershipScreening.html
+#[derive(Clone, Debug)]
+pub struct CreateMembershipScreeningField(pub HashMap<&'static str, Value>);
+
+impl CreateMembershipScreeningField {
+    /// The kind of the field.
+    ///
+    /// Defaults to [`MembershipScreeningFieldType::Terms`].
+    ///
+    /// [`MembershipScreeningFieldType::Terms`]: ../model/guild/enum.MembershipScreeningFieldType.html#variant.Terms
+    pub fn kind(&mut self, kind: MembershipScreeningFieldType) -> &mut Self {
+        self.0.insert("field_type", Value::String(kind.name().to_string()));
+        self
+    }
+
+    /// The title of the field.
+    pub fn label<D: ToString>(&mut self, label: D) -> &mut Self {
+        self.0.insert("label", Value::String(label.to_string()));
+        self
+    }
+
+    /// The values of the field, such as the list of rules to agree to.
+    pub fn values<T: ToString, It: IntoIterator<Item=T>>(&mut self, values: It) -> &mut Self {
+        let values = values
+            .into_iter()
+            .map(|value| Value::String(value.to_string()))
+            .collect();
+
+        self.0.insert("values", Value::Array(values));
+        self
+    }
+
+    /// Whether the member has to fill in this field.
+    ///
+    /// Defaults to `true`.
+    pub fn required(&mut self, required: bool) -> &mut Self {
+        self.0.insert("required", Value::Bool(required));
+        self
+    }
+}
+
+impl Default for CreateMembershipScreeningField {
+    /// Creates a builder with default values, setting the `field_type` to
+    /// [`MembershipScreeningFieldType::Terms`] and `required` to `true`.
+    ///
+    /// [`MembershipScreeningFieldType::Terms`]: ../model/guild/enum.MembershipScreeningFieldType.html#variant.Terms
+    fn default() -> Self {
+        let mut map = HashMap::new();
+        map.insert("field_type", Value::String(MembershipScreeningFieldType::Terms.name().to_string()));
+        map.insert("required", Value::Bool(true));
+
+        CreateMembershipScreeningField(map)
+    }
+}
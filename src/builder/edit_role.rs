@@ -44,7 +44,7 @@ use std::collections::HashMap;
 /// [`Role`]: ../model/guild/struct.Role.html
 /// [`Role::edit`]: ../model/guild/struct.Role.html#method.edit
 #[derive(Clone, Debug, Default)]
-pub struct EditRole(pub HashMap<&'static str, Value>);
+pub struct EditRole(pub HashMap<&'static str, Value>, pub(crate) Option<String>);
 
 impl EditRole {
     /// Creates a new builder with the values of the given [`Role`].
@@ -70,7 +70,7 @@ impl EditRole {
         map.insert("permissions",Value::Number(Number::from(role.permissions.bits())));
         map.insert("position", Value::Number(Number::from(role.position)));
 
-        EditRole(map)
+        EditRole(map, None)
     }
 
     /// Sets the colour of the role.
@@ -113,4 +113,11 @@ impl EditRole {
         self.0.insert("position", Value::Number(Number::from(position)));
         self
     }
+
+    /// The reason shown in the guild's audit log for creating or editing the
+    /// role.
+    pub fn audit_log_reason<S: ToString>(&mut self, reason: S) -> &mut Self {
+        self.1 = Some(reason.to_string());
+        self
+    }
 }
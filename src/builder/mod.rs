@@ -12,7 +12,9 @@ mod create_message;
 mod create_allowed_mentions;
 mod edit_channel;
 mod edit_guild;
+mod edit_guild_welcome_screen;
 mod edit_member;
+mod edit_membership_screening;
 mod edit_message;
 mod edit_profile;
 mod edit_role;
@@ -28,7 +30,9 @@ pub use self::{
     create_allowed_mentions::ParseValue,
     edit_channel::EditChannel,
     edit_guild::EditGuild,
+    edit_guild_welcome_screen::{CreateGuildWelcomeChannel, EditGuildWelcomeScreen},
     edit_member::EditMember,
+    edit_membership_screening::{CreateMembershipScreeningField, EditMembershipScreening},
     edit_message::EditMessage,
     edit_profile::EditProfile,
     edit_role::EditRole,
@@ -0,0 +1,507 @@
+use async_trait::async_trait;
+use crate::model::prelude::*;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+use tokio::sync::RwLock;
+
+/// Storage for the guilds, guild channels, users and messages held by a
+/// [`Cache`].
+///
+/// Every [`CacheUpdate`] implementation writes through and every getter, such
+/// as [`Cache::guild`], [`Cache::member`] or [`Cache::guild_channel`], reads
+/// from the backend of the cache it is applied to. This allows e.g. several
+/// shard processes to share one external store, or tests to substitute a
+/// double.
+///
+/// By default, a cache uses the [`InMemoryBackend`]. Use
+/// [`Cache::new_with_backend`] to supply another one.
+///
+/// Members, roles, emojis and voice states are stored on their guild. The
+/// [`member`] and [`role`] lookups default to reading them from the guild, and
+/// may be overridden by backends able to fetch them on their own.
+///
+/// Methods taking a closure pass it a reference to the stored value instead
+/// of a clone, returning whether the value existed. As the closures are
+/// called from within the backend's futures, they must be `Send`.
+///
+/// [`Cache`]: struct.Cache.html
+/// [`Cache::guild`]: struct.Cache.html#method.guild
+/// [`Cache::guild_channel`]: struct.Cache.html#method.guild_channel
+/// [`Cache::member`]: struct.Cache.html#method.member
+/// [`Cache::new_with_backend`]: struct.Cache.html#method.new_with_backend
+/// [`CacheUpdate`]: trait.CacheUpdate.html
+/// [`InMemoryBackend`]: struct.InMemoryBackend.html
+/// [`member`]: #method.member
+/// [`role`]: #method.role
+#[async_trait]
+pub trait CacheBackend: Debug + Send + Sync {
+    /// Clones the guild with the given Id.
+    async fn guild(&self, id: GuildId) -> Option<Guild> {
+        let mut guild = None;
+        self.with_guild(id, &mut |g| guild = Some(g.clone())).await;
+
+        guild
+    }
+
+    /// Calls `f` with the guild with the given Id, if it is stored.
+    async fn with_guild(&self, id: GuildId, f: &mut (dyn for<'a> FnMut(&'a Guild) + Send)) -> bool;
+
+    /// Calls `f` with a mutable reference to the guild with the given Id, if
+    /// it is stored.
+    async fn update_guild(&self, id: GuildId, f: &mut (dyn for<'a> FnMut(&'a mut Guild) + Send)) -> bool;
+
+    /// Calls `f` with every stored guild until it returns `false`.
+    async fn for_each_guild(&self, f: &mut (dyn for<'a> FnMut(&'a Guild) -> bool + Send));
+
+    /// Returns the Ids of all stored guilds.
+    async fn guild_ids(&self) -> Vec<GuildId>;
+
+    /// Returns the number of stored guilds.
+    async fn guild_count(&self) -> usize;
+
+    /// Stores a guild, returning the previously stored one with the same Id.
+    async fn insert_guild(&self, guild: Guild) -> Option<Guild>;
+
+    /// Removes and returns the guild with the given Id.
+    async fn remove_guild(&self, id: GuildId) -> Option<Guild>;
+
+    /// Clones the member of the given guild with the given user Id.
+    async fn member(&self, guild_id: GuildId, user_id: UserId) -> Option<Member> {
+        let mut member = None;
+        self.with_guild(guild_id, &mut |g| member = g.members.get(&user_id).cloned()).await;
+
+        member
+    }
+
+    /// Clones the role of the given guild with the given Id.
+    async fn role(&self, guild_id: GuildId, role_id: RoleId) -> Option<Role> {
+        let mut role = None;
+        self.with_guild(guild_id, &mut |g| role = g.roles.get(&role_id).cloned()).await;
+
+        role
+    }
+
+    /// Clones the guild channel with the given Id.
+    async fn guild_channel(&self, id: ChannelId) -> Option<GuildChannel> {
+        let mut channel = None;
+        self.with_guild_channel(id, &mut |c| channel = Some(c.clone())).await;
+
+        channel
+    }
+
+    /// Calls `f` with the guild channel with the given Id, if it is stored.
+    async fn with_guild_channel(&self, id: ChannelId, f: &mut (dyn for<'a> FnMut(&'a GuildChannel) + Send)) -> bool;
+
+    /// Calls `f` with a mutable reference to the guild channel with the given
+    /// Id, if it is stored.
+    async fn update_guild_channel(&self, id: ChannelId, f: &mut (dyn for<'a> FnMut(&'a mut GuildChannel) + Send)) -> bool;
+
+    /// Returns the number of stored guild channels.
+    async fn guild_channel_count(&self) -> usize;
+
+    /// Stores a guild channel, returning the previously stored one with the
+    /// same Id.
+    async fn insert_guild_channel(&self, channel: GuildChannel) -> Option<GuildChannel>;
+
+    /// Removes and returns the guild channel with the given Id.
+    async fn remove_guild_channel(&self, id: ChannelId) -> Option<GuildChannel>;
+
+    /// Clones the user with the given Id.
+    async fn user(&self, id: UserId) -> Option<User>;
+
+    /// Clones all stored users.
+    async fn users(&self) -> HashMap<UserId, User>;
+
+    /// Returns the Ids of all stored users.
+    async fn user_ids(&self) -> Vec<UserId>;
+
+    /// Returns the number of stored users.
+    async fn user_count(&self) -> usize;
+
+    /// Stores a user, returning the previously stored one with the same Id.
+    async fn insert_user(&self, user: User) -> Option<User>;
+
+    /// Removes and returns the user with the given Id.
+    async fn remove_user(&self, id: UserId) -> Option<User>;
+
+    /// Clones the message of the given channel with the given Id.
+    async fn message(&self, channel_id: ChannelId, message_id: MessageId) -> Option<Message>;
+
+    /// Calls `f` with a mutable reference to the message of the given channel
+    /// with the given Id, if it is stored.
+    async fn update_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        f: &mut (dyn for<'a> FnMut(&'a mut Message) + Send),
+    ) -> bool;
+
+    /// Calls `f` with every stored message until it returns `false`.
+    async fn for_each_message(&self, f: &mut (dyn for<'a> FnMut(&'a Message) -> bool + Send));
+
+    /// Stores a message, first removing the oldest message of its channel if
+    /// `max` messages of the channel are already stored. Returns the removed
+    /// message.
+    async fn insert_message(&self, message: Message, max: usize) -> Option<Message>;
+
+    /// Removes all stored messages of the given channel.
+    async fn remove_channel_messages(&self, channel_id: ChannelId);
+}
+
+/// The default [`CacheBackend`], keeping everything in memory.
+///
+/// [`CacheBackend`]: trait.CacheBackend.html
+#[derive(Debug, Default)]
+pub struct InMemoryBackend {
+    guilds: RwLock<HashMap<GuildId, Guild>>,
+    channels: RwLock<HashMap<ChannelId, GuildChannel>>,
+    users: RwLock<HashMap<UserId, User>>,
+    messages: RwLock<HashMap<ChannelId, HashMap<MessageId, Message>>>,
+    /// The Ids of each channel's messages, oldest first, so that the oldest
+    /// message can be removed once a channel is full.
+    message_queues: RwLock<HashMap<ChannelId, VecDeque<MessageId>>>,
+}
+
+impl InMemoryBackend {
+    /// Creates an empty backend.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CacheBackend for InMemoryBackend {
+    async fn with_guild(&self, id: GuildId, f: &mut (dyn for<'a> FnMut(&'a Guild) + Send)) -> bool {
+        match self.guilds.read().await.get(&id) {
+            Some(guild) => {
+                f(guild);
+
+                true
+            },
+            None => false,
+        }
+    }
+
+    async fn update_guild(&self, id: GuildId, f: &mut (dyn for<'a> FnMut(&'a mut Guild) + Send)) -> bool {
+        match self.guilds.write().await.get_mut(&id) {
+            Some(guild) => {
+                f(guild);
+
+                true
+            },
+            None => false,
+        }
+    }
+
+    async fn for_each_guild(&self, f: &mut (dyn for<'a> FnMut(&'a Guild) -> bool + Send)) {
+        for guild in self.guilds.read().await.values() {
+            if !f(guild) {
+                break;
+            }
+        }
+    }
+
+    async fn guild_ids(&self) -> Vec<GuildId> {
+        self.guilds.read().await.keys().copied().collect()
+    }
+
+    async fn guild_count(&self) -> usize {
+        self.guilds.read().await.len()
+    }
+
+    async fn insert_guild(&self, guild: Guild) -> Option<Guild> {
+        self.guilds.write().await.insert(guild.id, guild)
+    }
+
+    async fn remove_guild(&self, id: GuildId) -> Option<Guild> {
+        self.guilds.write().await.remove(&id)
+    }
+
+    async fn with_guild_channel(&self, id: ChannelId, f: &mut (dyn for<'a> FnMut(&'a GuildChannel) + Send)) -> bool {
+        match self.channels.read().await.get(&id) {
+            Some(channel) => {
+                f(channel);
+
+                true
+            },
+            None => false,
+        }
+    }
+
+    async fn update_guild_channel(&self, id: ChannelId, f: &mut (dyn for<'a> FnMut(&'a mut GuildChannel) + Send)) -> bool {
+        match self.channels.write().await.get_mut(&id) {
+            Some(channel) => {
+                f(channel);
+
+                true
+            },
+            None => false,
+        }
+    }
+
+    async fn guild_channel_count(&self) -> usize {
+        self.channels.read().await.len()
+    }
+
+    async fn insert_guild_channel(&self, channel: GuildChannel) -> Option<GuildChannel> {
+        self.channels.write().await.insert(channel.id, channel)
+    }
+
+    async fn remove_guild_channel(&self, id: ChannelId) -> Option<GuildChannel> {
+        self.channels.write().await.remove(&id)
+    }
+
+    async fn user(&self, id: UserId) -> Option<User> {
+        self.users.read().await.get(&id).cloned()
+    }
+
+    async fn users(&self) -> HashMap<UserId, User> {
+        self.users.read().await.clone()
+    }
+
+    async fn user_ids(&self) -> Vec<UserId> {
+        self.users.read().await.keys().copied().collect()
+    }
+
+    async fn user_count(&self) -> usize {
+        self.users.read().await.len()
+    }
+
+    async fn insert_user(&self, user: User) -> Option<User> {
+        self.users.write().await.insert(user.id, user)
+    }
+
+    async fn remove_user(&self, id: UserId) -> Option<User> {
+        self.users.write().await.remove(&id)
+    }
+
+    async fn message(&self, channel_id: ChannelId, message_id: MessageId) -> Option<Message> {
+        self.messages.read().await.get(&channel_id).and_then(|messages| {
+            messages.get(&message_id).cloned()
+        })
+    }
+
+    async fn update_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        f: &mut (dyn for<'a> FnMut(&'a mut Message) + Send),
+    ) -> bool {
+        let mut messages = self.messages.write().await;
+
+        match messages.get_mut(&channel_id).and_then(|messages| messages.get_mut(&message_id)) {
+            Some(message) => {
+                f(message);
+
+                true
+            },
+            None => false,
+        }
+    }
+
+    async fn for_each_message(&self, f: &mut (dyn for<'a> FnMut(&'a Message) -> bool + Send)) {
+        for message in self.messages.read().await.values().flat_map(HashMap::values) {
+            if !f(message) {
+                break;
+            }
+        }
+    }
+
+    async fn insert_message(&self, message: Message, max: usize) -> Option<Message> {
+        let mut messages_map = self.messages.write().await;
+        let messages = messages_map.entry(message.channel_id).or_insert_with(Default::default);
+        let mut message_queues = self.message_queues.write().await;
+        let queue = message_queues.entry(message.channel_id).or_insert_with(Default::default);
+
+        let mut removed_msg = None;
+
+        if !messages.contains_key(&message.id) {
+            if messages.len() >= max {
+                if let Some(id) = queue.pop_front() {
+                    removed_msg = messages.remove(&id);
+                }
+            }
+
+            queue.push_back(message.id);
+        }
+
+        messages.insert(message.id, message);
+
+        removed_msg
+    }
+
+    async fn remove_channel_messages(&self, channel_id: ChannelId) {
+        self.messages.write().await.remove(&channel_id);
+        self.message_queues.write().await.remove(&channel_id);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use async_trait::async_trait;
+    use crate::cache::{Cache, CacheBackend, InMemoryBackend, Settings};
+    use crate::model::prelude::*;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A backend counting the writes made through it, storing everything in
+    /// an inner `InMemoryBackend`.
+    #[derive(Debug, Default)]
+    struct CountingBackend {
+        inner: InMemoryBackend,
+        writes: Arc<AtomicUsize>,
+    }
+
+    impl CountingBackend {
+        fn write(&self) {
+            self.writes.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[async_trait]
+    impl CacheBackend for CountingBackend {
+        async fn with_guild(&self, id: GuildId, f: &mut (dyn for<'a> FnMut(&'a Guild) + Send)) -> bool {
+            self.inner.with_guild(id, f).await
+        }
+
+        async fn update_guild(&self, id: GuildId, f: &mut (dyn for<'a> FnMut(&'a mut Guild) + Send)) -> bool {
+            self.write();
+            self.inner.update_guild(id, f).await
+        }
+
+        async fn for_each_guild(&self, f: &mut (dyn for<'a> FnMut(&'a Guild) -> bool + Send)) {
+            self.inner.for_each_guild(f).await
+        }
+
+        async fn guild_ids(&self) -> Vec<GuildId> {
+            self.inner.guild_ids().await
+        }
+
+        async fn guild_count(&self) -> usize {
+            self.inner.guild_count().await
+        }
+
+        async fn insert_guild(&self, guild: Guild) -> Option<Guild> {
+            self.write();
+            self.inner.insert_guild(guild).await
+        }
+
+        async fn remove_guild(&self, id: GuildId) -> Option<Guild> {
+            self.write();
+            self.inner.remove_guild(id).await
+        }
+
+        async fn with_guild_channel(&self, id: ChannelId, f: &mut (dyn for<'a> FnMut(&'a GuildChannel) + Send)) -> bool {
+            self.inner.with_guild_channel(id, f).await
+        }
+
+        async fn update_guild_channel(&self, id: ChannelId, f: &mut (dyn for<'a> FnMut(&'a mut GuildChannel) + Send)) -> bool {
+            self.write();
+            self.inner.update_guild_channel(id, f).await
+        }
+
+        async fn guild_channel_count(&self) -> usize {
+            self.inner.guild_channel_count().await
+        }
+
+        async fn insert_guild_channel(&self, channel: GuildChannel) -> Option<GuildChannel> {
+            self.write();
+            self.inner.insert_guild_channel(channel).await
+        }
+
+        async fn remove_guild_channel(&self, id: ChannelId) -> Option<GuildChannel> {
+            self.write();
+            self.inner.remove_guild_channel(id).await
+        }
+
+        async fn user(&self, id: UserId) -> Option<User> {
+            self.inner.user(id).await
+        }
+
+        async fn users(&self) -> HashMap<UserId, User> {
+            self.inner.users().await
+        }
+
+        async fn user_ids(&self) -> Vec<UserId> {
+            self.inner.user_ids().await
+        }
+
+        async fn user_count(&self) -> usize {
+            self.inner.user_count().await
+        }
+
+        async fn insert_user(&self, user: User) -> Option<User> {
+            self.write();
+            self.inner.insert_user(user).await
+        }
+
+        async fn remove_user(&self, id: UserId) -> Option<User> {
+            self.write();
+            self.inner.remove_user(id).await
+        }
+
+        async fn message(&self, channel_id: ChannelId, message_id: MessageId) -> Option<Message> {
+            self.inner.message(channel_id, message_id).await
+        }
+
+        async fn update_message(
+            &self,
+            channel_id: ChannelId,
+            message_id: MessageId,
+            f: &mut (dyn for<'a> FnMut(&'a mut Message) + Send),
+        ) -> bool {
+            self.write();
+            self.inner.update_message(channel_id, message_id, f).await
+        }
+
+        async fn for_each_message(&self, f: &mut (dyn for<'a> FnMut(&'a Message) -> bool + Send)) {
+            self.inner.for_each_message(f).await
+        }
+
+        async fn insert_message(&self, message: Message, max: usize) -> Option<Message> {
+            self.write();
+            self.inner.insert_message(message, max).await
+        }
+
+        async fn remove_channel_messages(&self, channel_id: ChannelId) {
+            self.write();
+            self.inner.remove_channel_messages(channel_id).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_backend() {
+        let backend = CountingBackend::default();
+        let writes = Arc::clone(&backend.writes);
+
+        let mut settings = Settings::new();
+        settings.max_messages(1);
+        let cache = Cache::new_with_backend(settings, Box::new(backend));
+
+        let mut guild_create: GuildCreateEvent = serde_json::from_str(
+            include_str!("../../tests/resources/guild_create_1.json"),
+        ).unwrap();
+        let mut message_create: MessageCreateEvent = serde_json::from_str(
+            include_str!("../../tests/resources/message_create_1.json"),
+        ).unwrap();
+
+        cache.update(&mut guild_create).await;
+        cache.update(&mut message_create).await;
+
+        let guild_id = guild_create.guild.id;
+        let (role_id, role) = guild_create.guild.roles.iter().next().unwrap();
+        let message = &message_create.message;
+
+        assert!(writes.load(Ordering::Relaxed) > 0);
+        assert_eq!(cache.guild(guild_id).await.map(|g| g.name), Some(guild_create.guild.name.clone()));
+        assert_eq!(cache.role(guild_id, *role_id).await.map(|r| r.name), Some(role.name.clone()));
+        assert!(cache.user(message.author.id).await.is_some());
+        assert_eq!(cache.message(message.channel_id, message.id).await.map(|m| m.content), Some("a".to_string()));
+
+        // Deleting the channel removes its messages from the backend.
+        cache.backend.remove_channel_messages(message.channel_id).await;
+        assert!(cache.message(message.channel_id, message.id).await.is_none());
+    }
+}
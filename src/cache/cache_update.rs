@@ -113,6 +113,14 @@ pub trait CacheUpdate {
     /// If there is nothing to return, specify this type as an unit (`()`).
     type Output;
 
+    /// The name updates of this type are counted under in
+    /// [`CacheStats::updates`], e.g. `"GuildCreateEvent"`.
+    ///
+    /// Defaults to `"custom"`.
+    ///
+    /// [`CacheStats::updates`]: struct.CacheStats.html#structfield.updates
+    const KIND: &'static str = "custom";
+
     /// Updates the cache with the implementation.
     async fn update(&mut self, _: &Cache) -> Option<Self::Output>;
 }
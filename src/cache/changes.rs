@@ -0,0 +1,80 @@
+use crate::model::prelude::*;
+use tokio::sync::broadcast::{self, Sender};
+
+/// The number of changes a lagging subscriber may fall behind by before it
+/// starts missing them.
+const CHANGE_CAPACITY: usize = 256;
+
+/// A cached entity as it was before and after being mutated by a
+/// [`CacheUpdate`].
+///
+/// Subscribe to changes via e.g. [`Cache::subscribe_guilds`] or
+/// [`Cache::subscribe_members`].
+///
+/// [`Cache::subscribe_guilds`]: struct.Cache.html#method.subscribe_guilds
+/// [`Cache::subscribe_members`]: struct.Cache.html#method.subscribe_members
+/// [`CacheUpdate`]: trait.CacheUpdate.html
+#[derive(Clone, Debug)]
+pub struct CacheChange<T> {
+    /// The guild the entity belongs to, if any.
+    pub guild_id: Option<GuildId>,
+    /// The entity before the change, `None` if it was newly cached.
+    pub old: Option<T>,
+    /// The entity after the change, `None` if it was removed.
+    pub new: Option<T>,
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The broadcast channels the cache emits [`CacheChange`]s on, one per kind of
+/// entity.
+///
+/// [`CacheChange`]: struct.CacheChange.html
+#[derive(Debug)]
+pub(crate) struct ChangeSenders {
+    pub(crate) guilds: Sender<CacheChange<Guild>>,
+    pub(crate) channels: Sender<CacheChange<Channel>>,
+    pub(crate) roles: Sender<CacheChange<Role>>,
+    pub(crate) members: Sender<CacheChange<Member>>,
+    pub(crate) users: Sender<CacheChange<User>>,
+    pub(crate) presences: Sender<CacheChange<Presence>>,
+}
+
+impl Default for ChangeSenders {
+    fn default() -> Self {
+        Self {
+            guilds: broadcast::channel(CHANGE_CAPACITY).0,
+            channels: broadcast::channel(CHANGE_CAPACITY).0,
+            roles: broadcast::channel(CHANGE_CAPACITY).0,
+            members: broadcast::channel(CHANGE_CAPACITY).0,
+            users: broadcast::channel(CHANGE_CAPACITY).0,
+            presences: broadcast::channel(CHANGE_CAPACITY).0,
+        }
+    }
+}
+
+/// Whether anyone is subscribed to `sender`, used to avoid cloning entities
+/// for changes nobody receives.
+#[inline]
+pub(crate) fn is_watched<T>(sender: &Sender<CacheChange<T>>) -> bool {
+    sender.receiver_count() > 0
+}
+
+/// Emits a change on `sender`, unless both sides are `None`.
+pub(crate) fn notify<T>(
+    sender: &Sender<CacheChange<T>>,
+    guild_id: Option<GuildId>,
+    old: Option<T>,
+    new: Option<T>,
+) {
+    if old.is_none() && new.is_none() {
+        return;
+    }
+
+    // Sending only fails if there are no subscribers, which is fine.
+    let _ = sender.send(CacheChange {
+        guild_id,
+        old,
+        new,
+        _nonexhaustive: (),
+    });
+}
@@ -40,21 +40,36 @@ use std::str::FromStr;
 use crate::model::prelude::*;
 use tokio::sync::RwLock;
 use std::collections::{
-    hash_map::Entry,
     HashMap,
     HashSet,
-    VecDeque,
 };
 use std::default::Default;
+use std::hash::Hash;
+use std::iter;
+use std::mem;
+use std::sync::atomic::{AtomicU64, Ordering};
 use async_trait::async_trait;
+use tokio::sync::broadcast::Receiver;
 
+mod backend;
 mod cache_update;
+mod changes;
 mod settings;
+mod snapshot;
+mod stats;
+mod user_refs;
 
+pub use self::backend::{CacheBackend, InMemoryBackend};
 pub use self::cache_update::CacheUpdate;
-pub use self::settings::Settings;
+pub use self::changes::CacheChange;
+pub use self::snapshot::CacheSnapshot;
+pub use self::stats::{CacheStats, GetterStats};
 
-type MessageCache = HashMap<ChannelId, HashMap<MessageId, Message>>;
+pub(crate) use self::changes::{is_watched, notify, ChangeSenders};
+pub use self::settings::{CacheResources, Settings};
+
+use self::stats::StatCounters;
+use self::user_refs::UserRefs;
 
 #[async_trait]
 pub trait FromStrAndCache: Sized {
@@ -101,26 +116,21 @@ impl<F: FromStr> FromStrAndCache for F {
 /// [`http`]: ../http/index.html
 #[derive(Debug)]
 pub struct Cache {
-    /// A map of channels in [`Guild`]s that the current user has received data
-    /// for.
-    ///
-    /// When a [`Event::GuildDelete`] or [`Event::GuildUnavailable`] is
-    /// received and processed by the cache, the relevant channels are also
-    /// removed from this map.
-    ///
-    /// [`Event::GuildDelete`]: ../model/event/struct.GuildDeleteEvent.html
-    /// [`Event::GuildUnavailable`]: ../model/event/struct.GuildUnavailableEvent.html
-    /// [`Guild`]: ../model/guild/struct.Guild.html
-    pub(crate) channels: RwLock<HashMap<ChannelId, GuildChannel>>,
+    /// The storage of guilds, guild channels and users.
+    ///
+    /// Guild channels are removed along with their guild when a
+    /// [`Event::GuildDelete`] is processed. Users, however, are _not_ removed
+    /// on removal events such as [`Event::GuildMemberRemove`], as other structs
+    /// such as members or recipients may still exist. If
+    /// [`Settings::max_users`] is set, users no longer referenced by either are
+    /// evicted once the limit is exceeded, least recently used first.
+    ///
+    /// [`Event::GuildDelete`]: ../model/event/enum.Event.html#variant.GuildDelete
+    /// [`Event::GuildMemberRemove`]: ../model/event/enum.Event.html#variant.GuildMemberRemove
+    /// [`Settings::max_users`]: struct.Settings.html#structfield.max_users
+    pub(crate) backend: Box<dyn CacheBackend>,
     /// A map of channel categories.
     pub(crate) categories: RwLock<HashMap<ChannelId, ChannelCategory>>,
-    /// A map of guilds with full data available. This includes data like
-    /// [`Role`]s and [`Emoji`]s that are not available through the REST API.
-    ///
-    /// [`Emoji`]: ../model/guild/struct.Emoji.html
-    /// [`Role`]: ../model/guild/struct.Role.html
-    pub(crate) guilds: RwLock<HashMap<GuildId, Guild>>,
-    pub(crate) messages: RwLock<MessageCache>,
     /// A map of users' presences. This is updated in real-time. Note that
     /// status updates are often "eaten" by the gateway, and this should not
     /// be treated as being entirely 100% accurate.
@@ -151,34 +161,28 @@ pub struct Cache {
     /// [`CurrentUser`]: ../model/user/struct.CurrentUser.html
     /// [`User`]: ../model/user/struct.User.html
     pub(crate) user: RwLock<CurrentUser>,
-    /// A map of users that the current user sees.
-    ///
-    /// Users are added to - and updated from - this map via the following
-    /// received events:
-    ///
-    /// - [`GuildMemberAdd`][`GuildMemberAddEvent`]
-    /// - [`GuildMemberRemove`][`GuildMemberRemoveEvent`]
-    /// - [`GuildMembersChunk`][`GuildMembersChunkEvent`]
-    /// - [`PresenceUpdate`][`PresenceUpdateEvent`]
-    /// - [`Ready`][`ReadyEvent`]
-    ///
-    /// Note, however, that users are _not_ removed from the map on removal
-    /// events such as [`GuildMemberRemove`][`GuildMemberRemoveEvent`], as other
-    /// structs such as members or recipients may still exist.
-    ///
-    /// [`GuildMemberAddEvent`]: ../model/event/struct.GuildMemberAddEvent.html
-    /// [`GuildMemberRemoveEvent`]: ../model/event/struct.GuildMemberRemoveEvent.html
-    /// [`GuildMemberUpdateEvent`]: ../model/event/struct.GuildMemberUpdateEvent.html
-    /// [`GuildMembersChunkEvent`]: ../model/event/struct.GuildMembersChunkEvent.html
-    /// [`PresenceUpdateEvent`]: ../model/event/struct.PresenceUpdateEvent.html
-    /// [`ReadyEvent`]: ../model/event/struct.ReadyEvent.html
-    pub(crate) users: RwLock<HashMap<UserId, User>>,
-    /// Queue of message IDs for each channel.
-    ///
-    /// This is simply a vecdeque so we can keep track of the order of messages
-    /// inserted into the cache. When a maximum number of messages are in a
-    /// channel's cache, we can pop the front and remove that ID from the cache.
-    pub(crate) message_queue: RwLock<HashMap<ChannelId, VecDeque<MessageId>>>,
+    /// The last time each cached user was inserted, updated or retrieved, used
+    /// to pick eviction candidates when [`Settings::max_users`] is set.
+    ///
+    /// The generations are atomics so that retrieving a user only needs to
+    /// read-lock the map; it is write-locked when users are inserted or
+    /// evicted.
+    ///
+    /// [`Settings::max_users`]: struct.Settings.html#structfield.max_users
+    user_access: RwLock<HashMap<UserId, AtomicU64>>,
+    /// Monotonic counter backing the values of `user_access`.
+    user_access_tick: AtomicU64,
+    /// The references to cached users by guild members and private channels,
+    /// maintained when [`Settings::max_users`] is set.
+    ///
+    /// [`Settings::max_users`]: struct.Settings.html#structfield.max_users
+    user_refs: RwLock<UserRefs>,
+    /// The channels changes to cached entities are broadcast on.
+    pub(crate) changes: ChangeSenders,
+    /// The usage counters reported by [`stats`].
+    ///
+    /// [`stats`]: #method.stats
+    counters: StatCounters,
     /// The settings for the cache.
     settings: RwLock<Settings>,
     __nonexhaustive: (),
@@ -210,6 +214,28 @@ impl Cache {
         }
     }
 
+    /// Creates a new cache instance with settings applied, storing guilds,
+    /// guild channels and users in the given `backend`.
+    ///
+    /// Refer to [`CacheBackend`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::cache::{Cache, InMemoryBackend, Settings};
+    ///
+    /// let cache = Cache::new_with_backend(Settings::new(), Box::new(InMemoryBackend::new()));
+    /// ```
+    ///
+    /// [`CacheBackend`]: trait.CacheBackend.html
+    pub fn new_with_backend(settings: Settings, backend: Box<dyn CacheBackend>) -> Self {
+        Self {
+            backend,
+            settings: RwLock::new(settings),
+            ..Default::default()
+        }
+    }
+
     /// Fetches the number of [`Member`]s that have not had data received.
     ///
     /// The important detail to note here is that this is the number of
@@ -261,13 +287,15 @@ impl Cache {
     pub async fn unknown_members(&self) -> u64 {
         let mut total = 0;
 
-        for guild in self.guilds.read().await.values() {
+        self.backend.for_each_guild(&mut |guild| {
             let members = guild.members.len() as u64;
 
             if guild.member_count > members {
                 total += guild.member_count - members;
             }
-        }
+
+            true
+        }).await;
 
         total
     }
@@ -330,11 +358,10 @@ impl Cache {
     /// [`Guild`]: ../model/guild/struct.Guild.html
     /// [`Shard`]: ../gateway/struct.Shard.html
     pub async fn guilds(&self) -> Vec<GuildId> {
-        self.guilds
-            .read()
+        self.backend
+            .guild_ids()
             .await
-            .keys()
-            .cloned()
+            .into_iter()
             .chain(self.unavailable_guilds.read().await.clone().into_iter())
             .collect()
     }
@@ -358,12 +385,11 @@ impl Cache {
     /// [`private_channels`]: #structfield.private_channels
     #[inline]
     pub async fn channel<C: Into<ChannelId>>(&self, id: C) -> Option<Channel> {
-        self._channel(id.into()).await
+        self.counters.channel.record(self._channel(id.into()).await)
     }
 
     async fn _channel(&self, id: ChannelId) -> Option<Channel> {
-        if let Some(channel) = self.channels.read().await.get(&id) {
-            let channel = channel.clone();
+        if let Some(channel) = self.backend.guild_channel(id).await {
             return Some(Channel::Guild(channel));
         }
 
@@ -400,11 +426,11 @@ impl Cache {
     /// ```
     #[inline]
     pub async fn guild<G: Into<GuildId>>(&self, id: G) -> Option<Guild> {
-        self._guild(id.into()).await
+        self.counters.guild.record(self._guild(id.into()).await)
     }
 
     async fn _guild(&self, id: GuildId) -> Option<Guild> {
-        self.guilds.read().await.get(&id).cloned()
+        self.backend.guild(id).await
     }
 
     /// This method allows to select a field of the guild instead of
@@ -425,21 +451,25 @@ impl Cache {
     /// ```
     #[inline]
     pub async fn guild_field<Ret, Fun>(&self, id: impl Into<GuildId>, field_selector: Fun) -> Option<Ret>
-    where Fun: FnOnce(&Guild) -> Ret {
+    where Ret: Send, Fun: FnOnce(&Guild) -> Ret + Send {
         self._guild_field(id.into(), field_selector).await
     }
 
     async fn _guild_field<Ret, Fun>(&self, id: GuildId, field_accessor: Fun) -> Option<Ret>
-    where Fun: FnOnce(&Guild) -> Ret {
-        let guilds = self.guilds.read().await;
-        let guild = guilds.get(&id.into())?;
+    where Ret: Send, Fun: FnOnce(&Guild) -> Ret + Send {
+        let mut field_accessor = Some(field_accessor);
+        let mut ret = None;
 
-        Some(field_accessor(guild))
+        self.backend.with_guild(id, &mut |guild| {
+            ret = field_accessor.take().map(|f| f(guild));
+        }).await;
+
+        ret
     }
 
     /// Returns the number of cached guilds.
     pub async fn guild_count(&self) -> usize {
-        self.guilds.read().await.len()
+        self.backend.guild_count().await
     }
 
     /// Retrieves a reference to a [`Guild`]'s channel. Unlike [`channel`],
@@ -492,11 +522,11 @@ impl Cache {
     /// [`channel`]: #method.channel
     #[inline]
     pub async fn guild_channel<C: Into<ChannelId>>(&self, id: C) -> Option<GuildChannel> {
-        self._guild_channel(id.into()).await
+        self.counters.guild_channel.record(self._guild_channel(id.into()).await)
     }
 
     async fn _guild_channel(&self, id: ChannelId) -> Option<GuildChannel> {
-        self.channels.read().await.get(&id).cloned()
+        self.backend.guild_channel(id).await
     }
 
     /// This method allows to only clone a field of the guild channel instead of
@@ -519,18 +549,22 @@ impl Cache {
     pub async fn guild_channel_field<Ret, Fun>(&self,
         id: impl Into<ChannelId>,
         field_selector: Fun) -> Option<Ret>
-    where Fun: FnOnce(&GuildChannel) -> Ret {
+    where Ret: Send, Fun: FnOnce(&GuildChannel) -> Ret + Send {
         self._guild_channel_field(id.into(), field_selector).await
     }
 
     async fn _guild_channel_field<Ret, Fun>(&self,
         id: ChannelId,
         field_selector: Fun) -> Option<Ret>
-    where Fun: FnOnce(&GuildChannel) -> Ret {
-        let guild_channels = &self.channels.read().await;
-        let channel = guild_channels.get(&id.into())?;
+    where Ret: Send, Fun: FnOnce(&GuildChannel) -> Ret + Send {
+        let mut field_selector = Some(field_selector);
+        let mut ret = None;
+
+        self.backend.with_guild_channel(id, &mut |channel| {
+            ret = field_selector.take().map(|f| f(channel));
+        }).await;
 
-        Some(field_selector(channel))
+        ret
     }
 
     /// Retrieves a [`Guild`]'s member from the cache based on the guild's and
@@ -591,19 +625,11 @@ impl Cache {
     #[inline]
     pub async fn member<G, U>(&self, guild_id: G, user_id: U) -> Option<Member>
         where G: Into<GuildId>, U: Into<UserId> {
-        self._member(guild_id.into(), user_id.into()).await
+        self.counters.member.record(self._member(guild_id.into(), user_id.into()).await)
     }
 
     async fn _member(&self, guild_id: GuildId, user_id: UserId) -> Option<Member> {
-        match self.guilds.read().await.get(&guild_id) {
-            Some(guild) => {
-                guild
-                    .members
-                    .get(&user_id)
-                    .cloned()
-            }
-            None => None,
-        }
+        self.backend.member(guild_id, user_id).await
     }
 
     /// This method allows to only clone a field of a member instead of
@@ -627,7 +653,7 @@ impl Cache {
         guild_id: impl Into<GuildId>,
         user_id: impl Into<UserId>,
         field_selector: Fun) -> Option<Ret>
-    where Fun: FnOnce(&Member) -> Ret {
+    where Ret: Send, Fun: FnOnce(&Member) -> Ret + Send {
         self._member_field(guild_id.into(), user_id.into(), field_selector).await
     }
 
@@ -635,12 +661,113 @@ impl Cache {
         guild_id: GuildId,
         user_id: UserId,
         field_selector: Fun) -> Option<Ret>
-    where Fun: FnOnce(&Member) -> Ret {
-        let guilds = &self.guilds.read().await;
-        let guild = guilds.get(&guild_id)?;
-        let member = guild.members.get(&user_id)?;
+    where Ret: Send, Fun: FnOnce(&Member) -> Ret + Send {
+        let mut field_selector = Some(field_selector);
+        let mut ret = None;
+
+        self.backend.with_guild(guild_id, &mut |guild| {
+            if let Some(member) = guild.members.get(&user_id) {
+                ret = field_selector.take().map(|f| f(member));
+            }
+        }).await;
+
+        ret
+    }
+
+    /// Calculates the permissions of a user in a channel of a guild from the
+    /// cached roles and permission overwrites, without cloning the guild.
+    ///
+    /// Returns `None` if the guild, the member, any of the member's roles or
+    /// the @everyone role is not cached, as the permissions would be
+    /// incomplete otherwise.
+    ///
+    /// Refer to [`Guild::user_permissions_in`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # async fn run() {
+    /// # let cache = Cache::default();
+    /// if let Some(permissions) = cache.member_permissions(7, 8, 9).await {
+    ///     println!("Can send messages: {}", permissions.send_messages());
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`Guild::user_permissions_in`]: ../model/guild/struct.Guild.html#method.user_permissions_in
+    #[cfg(feature = "model")]
+    #[inline]
+    pub async fn member_permissions(&self,
+        guild_id: impl Into<GuildId>,
+        channel_id: impl Into<ChannelId>,
+        user_id: impl Into<UserId>) -> Option<Permissions> {
+        self._member_permissions(guild_id.into(), channel_id.into(), user_id.into()).await
+    }
+
+    #[cfg(feature = "model")]
+    async fn _member_permissions(&self,
+        guild_id: GuildId,
+        channel_id: ChannelId,
+        user_id: UserId) -> Option<Permissions> {
+        self._guild_field(guild_id, |guild| {
+            // The owner has all permissions, whatever else is cached.
+            if user_id == guild.owner_id {
+                return Some(Permissions::all());
+            }
+
+            // Without the member, any of their roles or @everyone the result
+            // would silently lack permissions, so report it as unknown.
+            let member = guild.members.get(&user_id)?;
+
+            if !guild.roles.contains_key(&RoleId(guild.id.0))
+                || member.roles.iter().any(|role| !guild.roles.contains_key(role)) {
+                return None;
+            }
+
+            Some(guild.user_permissions_in(channel_id, user_id))
+        }).await.and_then(|permissions| permissions)
+    }
+
+    /// Checks whether the current user has all of the given `permissions` in
+    /// a guild channel, to avoid sending requests that would be rejected.
+    ///
+    /// Returns `None` if the channel is not cached, including for private
+    /// channels, or if the current user's permissions are unknown as
+    /// described in [`member_permissions`].
+    ///
+    /// # Examples
+    ///
+    /// Only reply if the message may be sent:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// # use serenity::model::permissions::Permissions;
+    /// #
+    /// # async fn run() {
+    /// # let cache = Cache::default();
+    /// if cache.current_user_can(7, Permissions::SEND_MESSAGES).await != Some(false) {
+    ///     // send the message
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`member_permissions`]: #method.member_permissions
+    #[cfg(feature = "model")]
+    #[inline]
+    pub async fn current_user_can(&self, channel_id: impl Into<ChannelId>, permissions: Permissions) -> Option<bool> {
+        self._current_user_can(channel_id.into(), permissions).await
+    }
+
+    #[cfg(feature = "model")]
+    async fn _current_user_can(&self, channel_id: ChannelId, permissions: Permissions) -> Option<bool> {
+        let guild_id = self._guild_channel_field(channel_id, |channel| channel.guild_id).await?;
+        let user_id = self.current_user_id().await;
 
-        Some(field_selector(member))
+        self._member_permissions(guild_id, channel_id, user_id)
+            .await
+            .map(|current| current.contains(permissions))
     }
 
     #[inline]
@@ -649,7 +776,7 @@ impl Cache {
     }
 
     async fn _guild_roles(&self, guild_id: GuildId) -> Option<HashMap<RoleId, Role>> {
-        self.guilds.read().await.get(&guild_id).map(|g| g.roles.clone())
+        self._guild_field(guild_id, |g| g.roles.clone()).await
     }
 
     /// This method clones and returns all unavailable guilds.
@@ -665,12 +792,12 @@ impl Cache {
     }
 
     async fn _guild_channels(&self, guild_id: GuildId) -> Option<HashMap<ChannelId, GuildChannel>> {
-        self.guilds.read().await.get(&guild_id).map(|g| g.channels.clone())
+        self._guild_field(guild_id, |g| g.channels.clone()).await
     }
 
     /// Returns the number of guild channels in the cache.
     pub async fn guild_channel_count(&self) -> usize {
-        self.channels.read().await.len()
+        self.backend.guild_channel_count().await
     }
 
     /// Returns the number of shards.
@@ -714,13 +841,11 @@ impl Cache {
     #[inline]
     pub async fn message<C, M>(&self, channel_id: C, message_id: M) -> Option<Message>
         where C: Into<ChannelId>, M: Into<MessageId> {
-        self._message(channel_id.into(), message_id.into()).await
+        self.counters.message.record(self._message(channel_id.into(), message_id.into()).await)
     }
 
     async fn _message(&self, channel_id: ChannelId, message_id: MessageId) -> Option<Message> {
-        self.messages.read().await.get(&channel_id).and_then(|messages| {
-            messages.get(&message_id).cloned()
-        })
+        self.backend.message(channel_id, message_id).await
     }
 
     /// Retrieves a [`PrivateChannel`] from the cache's [`private_channels`]
@@ -753,7 +878,7 @@ impl Cache {
     /// [`private_channels`]: #structfield.private_channels
     #[inline]
     pub async fn private_channel(&self, channel_id: impl Into<ChannelId>) -> Option<PrivateChannel> {
-        self._private_channel(channel_id.into()).await
+        self.counters.private_channel.record(self._private_channel(channel_id.into()).await)
     }
 
     async fn _private_channel(&self, channel_id: ChannelId) -> Option<PrivateChannel> {
@@ -789,11 +914,11 @@ impl Cache {
     #[inline]
     pub async fn role<G, R>(&self, guild_id: G, role_id: R) -> Option<Role>
         where G: Into<GuildId>, R: Into<RoleId> {
-        self._role(guild_id.into(), role_id.into()).await
+        self.counters.role.record(self._role(guild_id.into(), role_id.into()).await)
     }
 
     async fn _role(&self, guild_id: GuildId, role_id: RoleId) -> Option<Role> {
-        self.guilds.read().await.get(&guild_id).and_then(|g| g.roles.get(&role_id)).cloned()
+        self.backend.role(guild_id, role_id).await
     }
 
     /// Returns the settings.
@@ -821,13 +946,20 @@ impl Cache {
         self.settings.write().await.max_messages = max;
     }
 
-    /// Retrieves a `User` from the cache's [`users`] map, if it exists.
+    /// Retrieves a `User` from the cache, if it exists.
+    ///
+    /// Users are added to - and updated from - the cache via events such as
+    /// [`GuildMemberAdd`], [`GuildMembersChunk`], [`PresenceUpdate`] and
+    /// [`Ready`].
     ///
     /// The only advantage of this method is that you can pass in anything that
     /// is indirectly a [`UserId`].
     ///
+    /// [`GuildMemberAdd`]: ../model/event/struct.GuildMemberAddEvent.html
+    /// [`GuildMembersChunk`]: ../model/event/struct.GuildMembersChunkEvent.html
+    /// [`PresenceUpdate`]: ../model/event/struct.PresenceUpdateEvent.html
+    /// [`Ready`]: ../model/event/struct.ReadyEvent.html
     /// [`UserId`]: ../model/id/struct.UserId.html
-    /// [`users`]: #structfield.users
     ///
     /// # Examples
     ///
@@ -847,29 +979,35 @@ impl Cache {
     /// ```
     #[inline]
     pub async fn user<U: Into<UserId>>(&self, user_id: U) -> Option<User> {
-        self._user(user_id.into()).await
+        self.counters.user.record(self._user(user_id.into()).await)
     }
 
-    async fn _user(&self, user_id: UserId) -> Option<User> {
-        self.users.read().await.get(&user_id).cloned()
+    pub(crate) async fn _user(&self, user_id: UserId) -> Option<User> {
+        let user = self.backend.user(user_id).await;
+
+        if user.is_some() {
+            self.touch_user(user_id).await;
+        }
+
+        user
     }
 
     /// Clones all users and returns them.
     #[inline]
     pub async fn users(&self) -> HashMap<UserId, User> {
-        self.users.read().await.clone()
+        self.backend.users().await
     }
 
     /// Returns the amount of cached users.
     #[inline]
     pub async fn user_count(&self) -> usize {
-        self.users.read().await.len()
+        self.backend.user_count().await
     }
 
     /// Clones a category matching the `channel_id` and returns it.
     #[inline]
     pub async fn category<C: Into<ChannelId>>(&self, channel_id: C) -> Option<ChannelCategory> {
-        self._category(channel_id.into()).await
+        self.counters.category.record(self._category(channel_id.into()).await)
     }
 
     async fn _category(&self, channel_id: ChannelId) -> Option<ChannelCategory> {
@@ -924,6 +1062,223 @@ impl Cache {
         field_selector(&user).clone()
     }
 
+    /// Subscribes to changes of cached guilds.
+    ///
+    /// A change is emitted whenever a guild is added, removed, or updated by
+    /// events such as [`GuildUpdate`] or [`GuildEmojisUpdate`]. Changes of a
+    /// guild's channels, roles and members are emitted on their own channels
+    /// instead.
+    ///
+    /// If the receiver falls too far behind, it will miss the oldest changes.
+    ///
+    /// # Examples
+    ///
+    /// Log every renamed guild:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # async fn run() {
+    /// # let cache = Cache::default();
+    /// let mut guilds = cache.subscribe_guilds();
+    ///
+    /// while let Ok(change) = guilds.recv().await {
+    ///     if let (Some(old), Some(new)) = (change.old, change.new) {
+    ///         if old.name != new.name {
+    ///             println!("{} was renamed to {}", old.name, new.name);
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`GuildEmojisUpdate`]: ../model/event/struct.GuildEmojisUpdateEvent.html
+    /// [`GuildUpdate`]: ../model/event/struct.GuildUpdateEvent.html
+    pub fn subscribe_guilds(&self) -> Receiver<CacheChange<Guild>> {
+        self.changes.guilds.subscribe()
+    }
+
+    /// Subscribes to changes of cached guild channels, channel categories and
+    /// private channels.
+    pub fn subscribe_channels(&self) -> Receiver<CacheChange<Channel>> {
+        self.changes.channels.subscribe()
+    }
+
+    /// Subscribes to changes of cached roles.
+    pub fn subscribe_roles(&self) -> Receiver<CacheChange<Role>> {
+        self.changes.roles.subscribe()
+    }
+
+    /// Subscribes to changes of cached members, including those received via
+    /// [`GuildMembersChunk`].
+    ///
+    /// [`GuildMembersChunk`]: ../model/event/struct.GuildMembersChunkEvent.html
+    pub fn subscribe_members(&self) -> Receiver<CacheChange<Member>> {
+        self.changes.members.subscribe()
+    }
+
+    /// Subscribes to changes of cached users.
+    ///
+    /// A change is only emitted if a user is newly cached, evicted, or one of
+    /// their fields differs.
+    pub fn subscribe_users(&self) -> Receiver<CacheChange<User>> {
+        self.changes.users.subscribe()
+    }
+
+    /// Subscribes to changes of cached presences, including those received
+    /// via [`PresencesReplace`].
+    ///
+    /// [`PresencesReplace`]: ../model/event/struct.PresencesReplaceEvent.html
+    pub fn subscribe_presences(&self) -> Receiver<CacheChange<Presence>> {
+        self.changes.presences.subscribe()
+    }
+
+    /// Creates a serializable copy of the cached guilds, including their
+    /// channels, roles and members, as well as of channel categories, private
+    /// channels, users and the current user.
+    ///
+    /// Refer to [`CacheSnapshot`] for an example.
+    ///
+    /// [`CacheSnapshot`]: struct.CacheSnapshot.html
+    pub async fn snapshot(&self) -> CacheSnapshot {
+        let mut guilds = Vec::with_capacity(self.backend.guild_count().await);
+
+        self.backend.for_each_guild(&mut |guild| {
+            guilds.push(guild.clone());
+
+            true
+        }).await;
+
+        CacheSnapshot {
+            guilds,
+            categories: self.categories.read().await.values().cloned().collect(),
+            private_channels: self.private_channels.read().await.values().cloned().collect(),
+            users: self.backend.users().await.into_iter().map(|(_, u)| u).collect(),
+            current_user: self.current_user().await,
+            _nonexhaustive: (),
+        }
+    }
+
+    /// Loads a [`CacheSnapshot`] into the cache, replacing cached entries with
+    /// the same Ids.
+    ///
+    /// This is meant to be used before starting the client, so that combined
+    /// with resuming a session, the cache holds data right away instead of
+    /// after every guild has been received again.
+    ///
+    /// [`CacheSnapshot`]: struct.CacheSnapshot.html
+    pub async fn restore(&self, snapshot: CacheSnapshot) {
+        for guild in snapshot.guilds {
+            self.unavailable_guilds.write().await.remove(&guild.id);
+
+            for channel in guild.channels.values() {
+                self.backend.insert_guild_channel(channel.clone()).await;
+            }
+
+            self.reference_users(guild.members.keys().copied()).await;
+
+            if let Some(old_guild) = self.backend.insert_guild(guild).await {
+                self.release_users(old_guild.members.keys().copied()).await;
+            }
+        }
+
+        self.categories.write().await.extend(snapshot.categories.into_iter().map(|c| (c.id, c)));
+
+        for channel in snapshot.private_channels {
+            self.reference_users(iter::once(channel.recipient.id)).await;
+
+            if let Some(old_channel) = self.private_channels.write().await.insert(channel.id, channel) {
+                self.release_users(iter::once(old_channel.recipient.id)).await;
+            }
+        }
+
+        for user in &snapshot.users {
+            self.update_user_entry(user).await;
+        }
+
+        *self.user.write().await = snapshot.current_user;
+
+        self.evict_users().await;
+    }
+
+    /// Counts the cached entries, estimates their memory usage and collects
+    /// the usage counters of the cache.
+    ///
+    /// Refer to [`CacheStats`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Log how often members are found in the cache:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # async fn run() {
+    /// # let cache = Cache::new();
+    /// let stats = cache.stats().await;
+    /// let member = stats.getters["member"];
+    ///
+    /// println!("{} members cached, {} hits, {} misses", stats.members, member.hits, member.misses);
+    /// # }
+    /// ```
+    ///
+    /// [`CacheStats`]: struct.CacheStats.html
+    pub async fn stats(&self) -> CacheStats {
+        let mut stats = CacheStats::default();
+        let mut memory = 0;
+
+        self.backend.for_each_guild(&mut |guild| {
+            stats.guilds += 1;
+            stats.members += guild.members.len();
+            stats.roles += guild.roles.len();
+            stats.emojis += guild.emojis.len();
+            stats.presences += guild.presences.len();
+
+            memory += mem::size_of::<Guild>()
+                + guild.members.len() * mem::size_of::<(UserId, Member)>()
+                + guild.roles.len() * mem::size_of::<(RoleId, Role)>()
+                + guild.emojis.len() * mem::size_of::<(EmojiId, Emoji)>()
+                + guild.presences.len() * mem::size_of::<(UserId, Presence)>()
+                + guild.channels.len() * mem::size_of::<(ChannelId, GuildChannel)>()
+                + guild.voice_states.len() * mem::size_of::<(UserId, VoiceState)>();
+
+            true
+        }).await;
+
+        stats.unavailable_guilds = self.unavailable_guilds.read().await.len();
+        stats.guild_channels = self.backend.guild_channel_count().await;
+        stats.categories = self.categories.read().await.len();
+        stats.private_channels = self.private_channels.read().await.len();
+        stats.presences += self.presences.read().await.len();
+        stats.users = self.backend.user_count().await;
+
+        let message_queues = &mut stats.message_queues;
+
+        self.backend.for_each_message(&mut |message| {
+            *message_queues.entry(message.channel_id).or_insert(0) += 1;
+
+            memory += mem::size_of::<(MessageId, Message)>()
+                + mem::size_of::<MessageId>()
+                + message.content.len();
+
+            true
+        }).await;
+
+        stats.messages = stats.message_queues.values().sum();
+
+        memory += stats.guild_channels * mem::size_of::<(ChannelId, GuildChannel)>()
+            + stats.categories * mem::size_of::<(ChannelId, ChannelCategory)>()
+            + stats.private_channels * mem::size_of::<(ChannelId, PrivateChannel)>()
+            + self.presences.read().await.len() * mem::size_of::<(UserId, Presence)>()
+            + stats.users * mem::size_of::<(UserId, User)>();
+
+        stats.approximate_memory = memory;
+        stats.getters = self.counters.getters();
+        stats.updates = self.counters.updates();
+
+        stats
+    }
+
     /// Updates the cache with the update implementation for an event or other
     /// custom update implementation.
     ///
@@ -936,36 +1291,206 @@ impl Cache {
     /// [`CacheUpdate`]: trait.CacheUpdate.html
     /// [`CacheUpdate` examples]: trait.CacheUpdate.html#examples
     pub async fn update<E: CacheUpdate>(&self, e: &mut E) -> Option<E::Output> {
+        self.counters.record_update(E::KIND);
+
         e.update(self).await
     }
 
     pub(crate) async fn update_user_entry(&self, user: &User) {
-        match self.users.write().await.entry(user.id) {
-            Entry::Vacant(e) => {
-                e.insert(user.clone());
-            },
-            Entry::Occupied(mut e) => {
-                e.get_mut().clone_from(user);
+        let settings = self.settings.read().await;
+
+        if !settings.caches(CacheResources::USERS) || settings.max_users == Some(0) {
+            return;
+        }
+
+        drop(settings);
+
+        let old = self.backend.insert_user(user.clone()).await;
+
+        if !self.touch_user(user.id).await {
+            let tick = self.user_access_tick.fetch_add(1, Ordering::Relaxed);
+            self.user_access.write().await.insert(user.id, AtomicU64::new(tick));
+            self.user_refs.write().await.queue(user.id, tick);
+        }
+
+        let changed = old.as_ref().map_or(true, |old| {
+            old.name != user.name
+                || old.discriminator != user.discriminator
+                || old.avatar != user.avatar
+                || old.bot != user.bot
+        });
+
+        if changed && is_watched(&self.changes.users) {
+            notify(&self.changes.users, None, old, Some(user.clone()));
+        }
+    }
+
+    /// Marks a user as recently used, if user eviction is enabled.
+    ///
+    /// Returns whether the user is tracked, so that inserting new users may
+    /// start tracking them.
+    async fn touch_user(&self, user_id: UserId) -> bool {
+        if self.settings.read().await.max_users.is_none() {
+            return true;
+        }
+
+        match self.user_access.read().await.get(&user_id) {
+            Some(generation) => {
+                let tick = self.user_access_tick.fetch_add(1, Ordering::Relaxed);
+                generation.store(tick, Ordering::Relaxed);
+
+                true
             },
+            None => false,
+        }
+    }
+
+    /// Whether users are evicted, and thus references to them are counted.
+    async fn evicts_users(&self) -> bool {
+        let settings = self.settings.read().await;
+
+        match settings.max_users {
+            Some(max) => max > 0 && settings.caches(CacheResources::USERS),
+            None => false,
+        }
+    }
+
+    /// Records that a guild member or private channel recipient was cached for
+    /// each of the users, sparing them from eviction.
+    pub(crate) async fn reference_users(&self, user_ids: impl IntoIterator<Item = UserId>) {
+        if !self.evicts_users().await {
+            return;
+        }
+
+        let mut refs = self.user_refs.write().await;
+
+        for user_id in user_ids {
+            refs.reference(user_id);
+        }
+    }
+
+    /// Records that a guild member or private channel recipient was removed
+    /// for each of the users, making them eligible for eviction once nothing
+    /// references them anymore.
+    pub(crate) async fn release_users(&self, user_ids: impl IntoIterator<Item = UserId>) {
+        if !self.evicts_users().await {
+            return;
+        }
+
+        let mut refs = self.user_refs.write().await;
+        let user_access = self.user_access.read().await;
+
+        for user_id in user_ids {
+            let generation = user_access.get(&user_id).map(|g| g.load(Ordering::Relaxed));
+            refs.release(user_id, generation);
+        }
+    }
+
+    /// Evicts the least recently used users once the user map exceeds
+    /// [`Settings::max_users`].
+    ///
+    /// Users are evicted in a batch down to a tenth below the limit. Only
+    /// users that are not referenced by a guild member or a private channel's
+    /// recipient are considered, so the map may remain above the limit if
+    /// every user is still in use.
+    ///
+    /// [`Settings::max_users`]: struct.Settings.html#structfield.max_users
+    pub(crate) async fn evict_users(&self) {
+        let max = match self.settings.read().await.max_users {
+            Some(max) => max,
+            None => return,
+        };
+
+        let mut count = self.backend.user_count().await;
+
+        if count <= max {
+            return;
+        }
+
+        let low_water = max - max / 10;
+        let current_user_id = self.user.read().await.id;
+        let watched = is_watched(&self.changes.users);
+
+        let mut refs = self.user_refs.write().await;
+        let mut user_access = self.user_access.write().await;
+
+        while count > low_water {
+            let (queued_at, user_id) = match refs.pop() {
+                Some(entry) => entry,
+                None => break,
+            };
+
+            // Users retrieved since being queued go to the back of the queue.
+            match user_access.get(&user_id).map(|g| g.load(Ordering::Relaxed)) {
+                Some(generation) if generation > queued_at => {
+                    refs.queue(user_id, generation);
+
+                    continue;
+                },
+                Some(_) if user_id != current_user_id => {},
+                _ => continue,
+            }
+
+            let old = self.backend.remove_user(user_id).await;
+            user_access.remove(&user_id);
+            count -= 1;
+
+            if watched {
+                notify(&self.changes.users, None, old, None);
+            }
         }
     }
 }
 
+/// Whether `key` may be inserted into `map` without exceeding `max` entries.
+///
+/// Existing keys may always be updated.
+pub(crate) fn has_capacity<K: Eq + Hash, V>(map: &HashMap<K, V>, key: &K, max: Option<usize>) -> bool {
+    match max {
+        Some(max) => map.len() < max || map.contains_key(key),
+        None => true,
+    }
+}
+
+/// Drops entries from `map` until at most `max` remain, never dropping the
+/// entry for `keep`.
+pub(crate) fn truncate_map<K: Eq + Hash, V>(map: &mut HashMap<K, V>, max: Option<usize>, keep: Option<&K>) {
+    let max = match max {
+        Some(max) if map.len() > max => max,
+        _ => return,
+    };
+
+    let mut retained = keep.map_or(0, |k| map.contains_key(k) as usize);
+
+    map.retain(|k, _| {
+        if Some(k) == keep {
+            true
+        } else if retained < max {
+            retained += 1;
+
+            true
+        } else {
+            false
+        }
+    });
+}
+
 impl Default for Cache {
     fn default() -> Cache {
         Cache {
-            channels: RwLock::new(HashMap::default()),
+            backend: Box::new(InMemoryBackend::default()),
             categories: RwLock::new(HashMap::default()),
-            guilds: RwLock::new(HashMap::default()),
-            messages: RwLock::new(HashMap::default()),
             presences: RwLock::new(HashMap::default()),
             private_channels: RwLock::new(HashMap::with_capacity(128)),
             settings: RwLock::new(Settings::default()),
             shard_count: RwLock::new(1),
             unavailable_guilds: RwLock::new(HashSet::default()),
             user: RwLock::new(CurrentUser::default()),
-            users: RwLock::new(HashMap::default()),
-            message_queue: RwLock::new(HashMap::default()),
+            user_access: RwLock::new(HashMap::default()),
+            user_access_tick: AtomicU64::new(0),
+            user_refs: RwLock::new(UserRefs::default()),
+            changes: ChangeSenders::default(),
+            counters: StatCounters::default(),
             __nonexhaustive: (),
         }
     }
@@ -979,11 +1504,72 @@ mod test {
         collections::HashMap,
     };
     use crate::{
-        cache::{Cache, CacheUpdate, Settings},
+        cache::{Cache, CacheResources, CacheUpdate, Settings},
         model::prelude::*,
     };
     use crate::model::guild::PremiumTier::Tier2;
 
+    fn user(id: u64) -> User {
+        User {
+            id: UserId(id),
+            avatar: None,
+            bot: false,
+            discriminator: 1,
+            name: format!("user {}", id),
+            _nonexhaustive: (),
+        }
+    }
+
+    fn member(guild_id: u64, id: u64) -> Member {
+        Member {
+            deaf: false,
+            guild_id: GuildId(guild_id),
+            joined_at: None,
+            mute: false,
+            nick: None,
+            pending: false,
+            roles: vec![],
+            user: user(id),
+            _nonexhaustive: (),
+        }
+    }
+
+    fn guild(id: u64, members: &[u64]) -> Guild {
+        Guild {
+            id: GuildId(id),
+            afk_channel_id: None,
+            afk_timeout: 0,
+            application_id: None,
+            default_message_notifications: DefaultMessageNotificationLevel::All,
+            emojis: HashMap::new(),
+            explicit_content_filter: ExplicitContentFilter::None,
+            features: vec![],
+            icon: None,
+            joined_at: Utc::now(),
+            large: false,
+            member_count: members.len() as u64,
+            members: members.iter().map(|&user_id| (UserId(user_id), member(id, user_id))).collect(),
+            mfa_level: MfaLevel::None,
+            name: String::new(),
+            owner_id: UserId(3),
+            presences: HashMap::new(),
+            region: String::new(),
+            roles: HashMap::new(),
+            splash: None,
+            system_channel_id: None,
+            verification_level: VerificationLevel::Low,
+            voice_states: HashMap::new(),
+            description: None,
+            premium_tier: PremiumTier::Tier0,
+            channels: HashMap::new(),
+            premium_subscription_count: 0,
+            banner: None,
+            vanity_url_code: None,
+            preferred_locale: "en-US".to_string(),
+            _nonexhaustive: (),
+        }
+    }
+
     #[tokio::test]
     async fn test_cache_messages() {
         let mut settings = Settings::new();
@@ -1028,37 +1614,33 @@ mod test {
                 activity: None,
                 application: None,
                 message_reference: None,
+                referenced_message: None,
                 flags: None,
                 _nonexhaustive: (),
             },
             _nonexhaustive: (),
         };
         // Check that the channel cache doesn't exist.
-        assert!(!cache.messages.read().await.contains_key(&event.message.channel_id));
+        assert_eq!(cache.stats().await.message_queues.get(&event.message.channel_id), None);
         // Add first message, none because message ID 2 doesn't already exist.
         assert!(event.update(&mut cache).await.is_none());
         // None, it only returns the oldest message if the cache was already full.
         assert!(event.update(&mut cache).await.is_none());
         // Assert there's only 1 message in the channel's message cache.
-        assert_eq!(cache.messages.read().await.get(&event.message.channel_id).unwrap().len(), 1);
+        assert_eq!(cache.stats().await.message_queues[&event.message.channel_id], 1);
 
         // Add a second message, assert that channel message cache length is 2.
         event.message.id = MessageId(4);
         assert!(event.update(&mut cache).await.is_none());
-        assert_eq!(cache.messages.read().await.get(&event.message.channel_id).unwrap().len(), 2);
+        assert_eq!(cache.stats().await.message_queues[&event.message.channel_id], 2);
 
         // Add a third message, the first should now be removed.
         event.message.id = MessageId(5);
         assert!(event.update(&mut cache).await.is_some());
 
-        {
-            let messages = cache.messages.read().await;
-            let channel = messages.get(&event.message.channel_id).unwrap();
-
-            assert_eq!(channel.len(), 2);
-            // Check that the first message is now removed.
-            assert!(!channel.contains_key(&MessageId(3)));
-        }
+        assert_eq!(cache.stats().await.message_queues[&event.message.channel_id], 2);
+        // Check that the first message is now removed.
+        assert!(cache.message(event.message.channel_id, 3).await.is_none());
 
         let guild_channel = GuildChannel {
             id: event.message.channel_id,
@@ -1085,7 +1667,7 @@ mod test {
             _nonexhaustive: (),
         };
         assert!(cache.update(&mut delete).await.is_none());
-        assert!(!cache.messages.read().await.contains_key(&delete.channel.id()));
+        assert!(cache.message(delete.channel.id(), 5).await.is_none());
 
         // Test deletion of a guild channel's message cache when a GuildDeleteEvent
         // is received.
@@ -1166,6 +1748,393 @@ mod test {
         assert!(cache.update(&mut guild_delete).await.is_some());
 
         // Assert that the channel's message cache no longer exists.
-        assert!(!cache.messages.read().await.contains_key(&ChannelId(2)));
+        assert!(cache.message(ChannelId(2), 5).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cache_user_eviction() {
+        let mut settings = Settings::new();
+        settings.max_users(2);
+        let cache = Cache::new_with_settings(settings);
+
+        let mut event = GuildMemberAddEvent {
+            guild_id: GuildId(1),
+            member: member(1, 10),
+            _nonexhaustive: (),
+        };
+
+        // The guild isn't cached, so none of these users are referenced by a
+        // member and all of them are eligible for eviction.
+        for id in 10..=12 {
+            event.member.user.id = UserId(id);
+            cache.update(&mut event).await;
+
+            // Retrieving the first user keeps it the most recently used.
+            assert!(cache.user(10).await.is_some());
+        }
+
+        assert_eq!(cache.user_count().await, 2);
+        assert!(cache.user(10).await.is_some());
+        assert!(cache.user(11).await.is_none());
+        assert!(cache.user(12).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cache_user_eviction_batch() {
+        let mut settings = Settings::new();
+        settings.max_users(10);
+        let cache = Cache::new_with_settings(settings);
+
+        let mut event = GuildMemberAddEvent {
+            guild_id: GuildId(1),
+            member: member(1, 1),
+            _nonexhaustive: (),
+        };
+
+        for id in 1..=10 {
+            event.member.user.id = UserId(id);
+            cache.update(&mut event).await;
+        }
+
+        assert_eq!(cache.user_count().await, 10);
+
+        // Retrieving the oldest user spares it from the eviction.
+        assert!(cache.user(1).await.is_some());
+
+        event.member.user.id = UserId(11);
+        cache.update(&mut event).await;
+
+        // Exceeding the limit evicts down to a tenth below it at once.
+        assert_eq!(cache.user_count().await, 9);
+        assert!(cache.user(1).await.is_some());
+        assert!(cache.user(2).await.is_none());
+        assert!(cache.user(3).await.is_none());
+        assert!(cache.user(4).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cache_user_eviction_references() {
+        let mut settings = Settings::new();
+        settings.max_users(2);
+        let cache = Cache::new_with_settings(settings);
+
+        let mut guild_create = GuildCreateEvent {
+            guild: guild(1, &[10, 11, 12]),
+            _nonexhaustive: (),
+        };
+        cache.update(&mut guild_create).await;
+
+        // Members keep their users cached beyond the limit.
+        assert_eq!(cache.user_count().await, 3);
+
+        // A user that is a member of another guild as well stays referenced.
+        guild_create.guild = guild(2, &[10]);
+        cache.update(&mut guild_create).await;
+
+        for id in &[10, 11] {
+            let mut member_remove = GuildMemberRemoveEvent {
+                guild_id: GuildId(1),
+                user: user(*id),
+                _nonexhaustive: (),
+            };
+            cache.update(&mut member_remove).await;
+        }
+
+        assert_eq!(cache.user_count().await, 2);
+        assert!(cache.user(10).await.is_some());
+        assert!(cache.user(11).await.is_none());
+        assert!(cache.user(12).await.is_some());
+
+        // Removing a guild releases its members.
+        let mut guild_unavailable = GuildUnavailableEvent {
+            guild_id: GuildId(2),
+            _nonexhaustive: (),
+        };
+        cache.update(&mut guild_unavailable).await;
+
+        let mut member_add = GuildMemberAddEvent {
+            guild_id: GuildId(3),
+            member: member(3, 13),
+            _nonexhaustive: (),
+        };
+        cache.update(&mut member_add).await;
+
+        assert_eq!(cache.user_count().await, 2);
+        assert!(cache.user(10).await.is_none());
+        assert!(cache.user(12).await.is_some());
+        assert!(cache.user(13).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cache_snapshot() {
+        let cache = Cache::new();
+        let user = user(10);
+
+        cache.update_user_entry(&user).await;
+        cache.user.write().await.id = UserId(1);
+
+        let json = serde_json::to_string(&cache.snapshot().await).unwrap();
+        let snapshot = serde_json::from_str(&json).unwrap();
+
+        let restored = Cache::new();
+        restored.restore(snapshot).await;
+
+        assert_eq!(restored.user(10).await.map(|u| u.name), Some(user.name));
+        assert_eq!(restored.current_user_id().await, UserId(1));
+    }
+
+    #[tokio::test]
+    async fn test_cache_subscribe_users() {
+        let cache = Cache::new();
+        let mut changes = cache.subscribe_users();
+        let mut user = user(10);
+
+        cache.update_user_entry(&user).await;
+        // Unchanged users are not re-emitted.
+        cache.update_user_entry(&user).await;
+        user.name = "renamed".to_owned();
+        cache.update_user_entry(&user).await;
+
+        let change = changes.try_recv().unwrap();
+        assert!(change.old.is_none());
+        assert_eq!(change.new.map(|u| u.name), Some("user 10".to_owned()));
+
+        let change = changes.try_recv().unwrap();
+        assert_eq!(change.old.map(|u| u.name), Some("user 10".to_owned()));
+        assert_eq!(change.new.map(|u| u.name), Some("renamed".to_owned()));
+
+        assert!(changes.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cache_subscribe_members_chunk() {
+        let cache = Cache::new();
+        let mut guild_create: GuildCreateEvent = serde_json::from_str(
+            include_str!("../../tests/resources/guild_create_1.json"),
+        ).unwrap();
+        cache.update(&mut guild_create).await;
+
+        let guild_id = guild_create.guild.id;
+        let mut member = guild_create.guild.members.values().next().unwrap().clone();
+        let mut changes = cache.subscribe_members();
+
+        member.nick = Some("chunked".to_owned());
+        let mut members = HashMap::new();
+        members.insert(member.user.id, member.clone());
+
+        let mut chunk = GuildMembersChunkEvent {
+            guild_id,
+            members,
+            nonce: None,
+            _nonexhaustive: (),
+        };
+        cache.update(&mut chunk).await;
+
+        let change = changes.try_recv().unwrap();
+        assert_eq!(change.guild_id, Some(guild_id));
+        assert!(change.old.is_some());
+        assert_eq!(change.new.and_then(|m| m.nick), Some("chunked".to_owned()));
+        assert!(changes.try_recv().is_err());
+
+        assert_eq!(cache.member(guild_id, member.user.id).await.and_then(|m| m.nick), Some("chunked".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn test_cache_subscribe_presences_replace() {
+        let cache = Cache::new();
+        let presence: Presence = serde_json::from_value(serde_json::json!({
+            "user": {"id": "10"},
+            "status": "online",
+        })).unwrap();
+
+        // Nothing is sent while no one is subscribed.
+        let mut replace = PresencesReplaceEvent {
+            presences: vec![presence.clone()],
+            _nonexhaustive: (),
+        };
+        cache.update(&mut replace).await;
+
+        let mut changes = cache.subscribe_presences();
+        replace.presences[0].status = OnlineStatus::Idle;
+        cache.update(&mut replace).await;
+
+        let change = changes.try_recv().unwrap();
+        assert_eq!(change.guild_id, None);
+        assert_eq!(change.old.map(|p| p.status), Some(OnlineStatus::Online));
+        assert_eq!(change.new.map(|p| p.status), Some(OnlineStatus::Idle));
+        assert!(changes.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cache_stats() {
+        let cache = Cache::new();
+        let user = user(10);
+
+        cache.update_user_entry(&user).await;
+        assert!(cache.user(10).await.is_some());
+        assert!(cache.user(11).await.is_none());
+
+        let mut event = UserUpdateEvent {
+            current_user: CurrentUser::default(),
+            _nonexhaustive: (),
+        };
+        cache.update(&mut event).await;
+
+        let stats = cache.stats().await;
+        assert_eq!(stats.users, 1);
+        assert_eq!(stats.getters["user"].hits, 1);
+        assert_eq!(stats.getters["user"].misses, 1);
+        assert_eq!(stats.getters["member"].hits, 0);
+        assert_eq!(stats.updates.get("UserUpdateEvent"), Some(&1));
+        assert_eq!(stats.updates.get("ReadyEvent"), None);
+        assert!(stats.approximate_memory >= std::mem::size_of::<User>());
+    }
+
+    #[tokio::test]
+    async fn test_cache_stats_custom_update() {
+        struct Noop;
+        struct Named;
+
+        #[async_trait::async_trait]
+        impl CacheUpdate for Noop {
+            type Output = ();
+
+            async fn update(&mut self, _: &Cache) -> Option<()> {
+                None
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl CacheUpdate for Named {
+            type Output = ();
+            const KIND: &'static str = "Named";
+
+            async fn update(&mut self, _: &Cache) -> Option<()> {
+                None
+            }
+        }
+
+        let cache = Cache::new();
+        cache.update(&mut Noop).await;
+        cache.update(&mut Noop).await;
+        cache.update(&mut Named).await;
+
+        let stats = cache.stats().await;
+        assert_eq!(stats.updates.get("custom"), Some(&2));
+        assert_eq!(stats.updates.get("Named"), Some(&1));
+        assert_eq!(stats.updates.len(), 2);
+    }
+
+    #[cfg(feature = "model")]
+    #[tokio::test]
+    async fn test_cache_member_permissions() {
+        let cache = Cache::new();
+        cache.user.write().await.id = UserId(10);
+
+        let everyone: Role = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "guild_id": "1",
+            "color": 0,
+            "hoist": false,
+            "managed": false,
+            "name": "@everyone",
+            "permissions": (Permissions::READ_MESSAGES | Permissions::SEND_MESSAGES).bits(),
+            "position": 0,
+        })).unwrap();
+
+        let channel = GuildChannel {
+            id: ChannelId(2),
+            bitrate: None,
+            category_id: None,
+            guild_id: GuildId(1),
+            kind: ChannelType::Text,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            name: String::new(),
+            permission_overwrites: vec![PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Permissions::SEND_MESSAGES,
+                kind: PermissionOverwriteType::Member(UserId(10)),
+            }],
+            position: 0,
+            topic: None,
+            user_limit: None,
+            nsfw: false,
+            slow_mode_rate: Some(0),
+            _nonexhaustive: (),
+        };
+
+        let mut guild = guild(1, &[10]);
+        guild.channels.insert(channel.id, channel.clone());
+        guild.roles.insert(everyone.id, everyone);
+
+        cache.backend.insert_guild_channel(channel).await;
+        cache.backend.insert_guild(guild).await;
+
+        assert_eq!(cache.member_permissions(1, 2, 3).await, Some(Permissions::all()));
+        assert_eq!(cache.member_permissions(4, 2, 10).await, None);
+        assert_eq!(cache.current_user_can(2, Permissions::READ_MESSAGES).await, Some(true));
+        assert_eq!(cache.current_user_can(2, Permissions::SEND_MESSAGES).await, Some(false));
+        assert_eq!(cache.current_user_can(5, Permissions::SEND_MESSAGES).await, None);
+
+        // A member that is not cached.
+        assert_eq!(cache.member_permissions(1, 2, 11).await, None);
+
+        // Only the guild needs to be cached to compute a channel's
+        // permissions for a user.
+        let channel = cache.guild_channel(2).await.unwrap();
+        assert!(channel.permissions_for_user(&cache, 11).await.is_ok());
+
+        // A role of the member that is not cached.
+        cache.backend.update_guild(GuildId(1), &mut |guild| {
+            guild.members.get_mut(&UserId(10)).unwrap().roles.push(RoleId(6));
+        }).await;
+        assert_eq!(cache.member_permissions(1, 2, 10).await, None);
+        assert_eq!(cache.current_user_can(2, Permissions::READ_MESSAGES).await, None);
+
+        // The @everyone role that is not cached.
+        cache.backend.update_guild(GuildId(1), &mut |guild| {
+            guild.members.get_mut(&UserId(10)).unwrap().roles.clear();
+            guild.roles.remove(&RoleId(1));
+        }).await;
+        assert_eq!(cache.member_permissions(1, 2, 10).await, None);
+        assert_eq!(cache.current_user_can(2, Permissions::READ_MESSAGES).await, None);
+        assert_eq!(cache.member_permissions(1, 2, 3).await, Some(Permissions::all()));
+    }
+
+    #[tokio::test]
+    async fn test_cache_resources() {
+        let mut guild_create = GuildCreateEvent {
+            guild: guild(1, &[10, 11]),
+            _nonexhaustive: (),
+        };
+        let mut guild_unavailable = GuildUnavailableEvent {
+            guild_id: GuildId(1),
+            _nonexhaustive: (),
+        };
+
+        // Unavailable guilds are tracked even if guilds are not cached.
+        let mut settings = Settings::new();
+        settings.resources(CacheResources::all() - CacheResources::GUILDS);
+        let cache = Cache::new_with_settings(settings);
+
+        cache.update(&mut guild_unavailable).await;
+        assert!(cache.unavailable_guilds.read().await.contains(&GuildId(1)));
+
+        cache.update(&mut guild_create).await;
+        assert!(cache.unavailable_guilds.read().await.is_empty());
+        assert!(cache.guild(1).await.is_none());
+
+        // The current user's member is kept even if members are not cached.
+        let mut settings = Settings::new();
+        settings.resources(CacheResources::all() - CacheResources::MEMBERS);
+        let cache = Cache::new_with_settings(settings);
+        cache.user.write().await.id = UserId(10);
+
+        cache.update(&mut guild_create).await;
+
+        let guild = cache.guild(1).await.unwrap();
+        assert_eq!(guild.members.len(), 1);
+        assert!(guild.members.contains_key(&UserId(10)));
     }
 }
@@ -1,3 +1,47 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// The kinds of resources the cache stores.
+    ///
+    /// Removing a resource from [`Settings::resources`] skips every cache
+    /// write for it, so bots that don't need e.g. presences or messages can
+    /// run with a smaller cache.
+    ///
+    /// Members, roles, emojis and voice states are stored on their guild, so
+    /// they are only cached if [`GUILDS`] is cached as well.
+    ///
+    /// [`GUILDS`]: #associatedconstant.GUILDS
+    /// [`Settings::resources`]: struct.Settings.html#structfield.resources
+    pub struct CacheResources: u64 {
+        /// Guilds, along with their unavailability.
+        const GUILDS = 1;
+        /// Guild channels, channel categories and private channels.
+        const CHANNELS = 1 << 1;
+        /// Guild members.
+        const MEMBERS = 1 << 2;
+        /// Guild roles.
+        const ROLES = 1 << 3;
+        /// Guild emojis.
+        const EMOJIS = 1 << 4;
+        /// User presences, both globally and per guild.
+        const PRESENCES = 1 << 5;
+        /// Channel messages, additionally limited by [`Settings::max_messages`].
+        ///
+        /// [`Settings::max_messages`]: struct.Settings.html#structfield.max_messages
+        const MESSAGES = 1 << 6;
+        /// Guild voice states.
+        const VOICE_STATES = 1 << 7;
+        /// Users, as referenced by members, presences and private channels.
+        const USERS = 1 << 8;
+    }
+}
+
+impl Default for CacheResources {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
 /// Settings for the cache.
 ///
 /// # Examples
@@ -16,6 +60,36 @@ pub struct Settings {
     ///
     /// Defaults to 0.
     pub max_messages: usize,
+    /// The maximum number of presences to store, both globally and per guild.
+    ///
+    /// Presences of users already in the cache keep being updated once the
+    /// limit is reached, but new ones are dropped. `Some(0)` disables the
+    /// presence cache entirely.
+    ///
+    /// Defaults to `None`, meaning no limit.
+    pub max_presences: Option<usize>,
+    /// The maximum number of users to store in the cache's user map.
+    ///
+    /// When the limit is exceeded, the least recently used users that are no
+    /// longer referenced by any guild member or private channel are evicted,
+    /// in a batch down to a tenth below the limit.
+    /// `Some(0)` disables the user cache entirely.
+    ///
+    /// Defaults to `None`, meaning no limit.
+    pub max_users: Option<usize>,
+    /// The maximum number of members to store for each guild.
+    ///
+    /// Members beyond the limit are not cached, with the exception of the
+    /// current user's own member. `Some(0)` caches only the current user.
+    ///
+    /// Defaults to `None`, meaning no limit.
+    pub max_members_per_guild: Option<usize>,
+    /// The kinds of resources to store.
+    ///
+    /// Defaults to [`CacheResources::all`].
+    ///
+    /// [`CacheResources::all`]: struct.CacheResources.html#method.all
+    pub resources: CacheResources,
     __nonexhaustive: (),
 }
 
@@ -23,6 +97,10 @@ impl Default for Settings {
     fn default() -> Self {
         Settings {
             max_messages: usize::default(),
+            max_presences: None,
+            max_users: None,
+            max_members_per_guild: None,
+            resources: CacheResources::default(),
             __nonexhaustive: (),
         }
     }
@@ -56,4 +134,76 @@ impl Settings {
 
         self
     }
+
+    /// Sets the maximum number of presences to cache.
+    ///
+    /// Refer to [`max_presences`] for more information.
+    ///
+    /// [`max_presences`]: #structfield.max_presences
+    pub fn max_presences(&mut self, max: usize) -> &mut Self {
+        self.max_presences = Some(max);
+
+        self
+    }
+
+    /// Sets the maximum number of users to cache, evicting the least recently
+    /// used unreferenced users past that point.
+    ///
+    /// Refer to [`max_users`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Keep at most 10 000 users around:
+    ///
+    /// ```rust
+    /// use serenity::cache::Settings;
+    ///
+    /// let mut settings = Settings::new();
+    /// settings.max_users(10_000);
+    /// ```
+    ///
+    /// [`max_users`]: #structfield.max_users
+    pub fn max_users(&mut self, max: usize) -> &mut Self {
+        self.max_users = Some(max);
+
+        self
+    }
+
+    /// Sets the maximum number of members to cache per guild.
+    ///
+    /// Refer to [`max_members_per_guild`] for more information.
+    ///
+    /// [`max_members_per_guild`]: #structfield.max_members_per_guild
+    pub fn max_members_per_guild(&mut self, max: usize) -> &mut Self {
+        self.max_members_per_guild = Some(max);
+
+        self
+    }
+
+    /// Sets the kinds of resources to cache, replacing the current ones.
+    ///
+    /// Refer to [`resources`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Only cache guilds, channels and roles:
+    ///
+    /// ```rust
+    /// use serenity::cache::{CacheResources, Settings};
+    ///
+    /// let mut settings = Settings::new();
+    /// settings.resources(CacheResources::GUILDS | CacheResources::CHANNELS | CacheResources::ROLES);
+    /// ```
+    ///
+    /// [`resources`]: #structfield.resources
+    pub fn resources(&mut self, resources: CacheResources) -> &mut Self {
+        self.resources = resources;
+
+        self
+    }
+
+    /// Whether the given kind of resource is cached.
+    pub fn caches(&self, resource: CacheResources) -> bool {
+        self.resources.contains(resource)
+    }
 }
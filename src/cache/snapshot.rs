@@ -0,0 +1,50 @@
+use crate::model::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A serializable copy of the long-lived data of a [`Cache`], created via
+/// [`Cache::snapshot`] and loaded back via [`Cache::restore`].
+///
+/// Guild channels, roles and members are contained in their [`Guild`].
+/// Messages and presences are not part of a snapshot, as they are outdated
+/// quickly.
+///
+/// # Examples
+///
+/// Persist the cache as JSON on shutdown and load it back on start, so that a
+/// resumed session has cache data before any guild is received again:
+///
+/// ```rust,no_run
+/// use serenity::cache::{Cache, CacheSnapshot};
+/// use std::fs::File;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let cache = Cache::new();
+/// let snapshot = cache.snapshot().await;
+/// serde_json::to_writer(File::create("cache.json")?, &snapshot)?;
+///
+/// // ... after a restart:
+/// let snapshot: CacheSnapshot = serde_json::from_reader(File::open("cache.json")?)?;
+/// cache.restore(snapshot).await;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Cache`]: struct.Cache.html
+/// [`Cache::restore`]: struct.Cache.html#method.restore
+/// [`Cache::snapshot`]: struct.Cache.html#method.snapshot
+/// [`Guild`]: ../model/guild/struct.Guild.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CacheSnapshot {
+    /// The available guilds, including their channels, roles and members.
+    pub guilds: Vec<Guild>,
+    /// The channel categories.
+    pub categories: Vec<ChannelCategory>,
+    /// The direct message channels of the current user.
+    pub private_channels: Vec<PrivateChannel>,
+    /// The users seen by the current user.
+    pub users: Vec<User>,
+    /// The current user.
+    pub current_user: CurrentUser,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
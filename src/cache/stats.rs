@@ -0,0 +1,158 @@
+use crate::model::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    RwLock,
+};
+
+/// A point-in-time summary of what a [`Cache`] holds and how it has been
+/// used, created via [`Cache::stats`].
+///
+/// All counters are cumulative since the cache was created, so that they can
+/// be exported as-is to a metrics system.
+///
+/// [`Cache`]: struct.Cache.html
+/// [`Cache::stats`]: struct.Cache.html#method.stats
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CacheStats {
+    /// The number of available guilds.
+    pub guilds: usize,
+    /// The number of unavailable guilds.
+    pub unavailable_guilds: usize,
+    /// The number of guild channels.
+    pub guild_channels: usize,
+    /// The number of channel categories.
+    pub categories: usize,
+    /// The number of direct message channels.
+    pub private_channels: usize,
+    /// The number of members, summed over all guilds.
+    pub members: usize,
+    /// The number of roles, summed over all guilds.
+    pub roles: usize,
+    /// The number of emojis, summed over all guilds.
+    pub emojis: usize,
+    /// The number of presences, both global and summed over all guilds.
+    pub presences: usize,
+    /// The number of users.
+    pub users: usize,
+    /// The number of messages, summed over all channels.
+    pub messages: usize,
+    /// The number of cached messages of each channel, as tracked by the
+    /// message queue.
+    pub message_queues: HashMap<ChannelId, usize>,
+    /// An estimate of the memory used by the entries above, in bytes.
+    ///
+    /// This only accounts for the size of the entries themselves and the
+    /// content of messages, not for other heap allocations such as names or
+    /// map overhead, so it is a lower bound.
+    pub approximate_memory: usize,
+    /// The hits and misses of each getter, keyed by the getter's name, e.g.
+    /// `"member"` or `"guild_channel"`.
+    pub getters: HashMap<&'static str, GetterStats>,
+    /// The number of updates applied via [`Cache::update`], keyed by the
+    /// [`CacheUpdate::KIND`] of the update, e.g. `"GuildCreateEvent"`.
+    ///
+    /// [`Cache::update`]: struct.Cache.html#method.update
+    /// [`CacheUpdate::KIND`]: trait.CacheUpdate.html#associatedconstant.KIND
+    pub updates: HashMap<&'static str, u64>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// How often a getter of the [`Cache`] found what it was asked for.
+///
+/// [`Cache`]: struct.Cache.html
+#[derive(Clone, Copy, Debug, Default, Serialize)]
+pub struct GetterStats {
+    /// The number of lookups that found a value.
+    pub hits: u64,
+    /// The number of lookups that did not find a value.
+    pub misses: u64,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct GetterCounter {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl GetterCounter {
+    /// Counts a lookup, passing its result through.
+    pub(crate) fn record<T>(&self, value: Option<T>) -> Option<T> {
+        let counter = if value.is_some() { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        value
+    }
+
+    fn load(&self) -> GetterStats {
+        GetterStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// The counters behind the usage part of [`CacheStats`].
+///
+/// [`CacheStats`]: struct.CacheStats.html
+#[derive(Debug, Default)]
+pub(crate) struct StatCounters {
+    pub(crate) category: GetterCounter,
+    pub(crate) channel: GetterCounter,
+    pub(crate) guild: GetterCounter,
+    pub(crate) guild_channel: GetterCounter,
+    pub(crate) member: GetterCounter,
+    pub(crate) message: GetterCounter,
+    pub(crate) private_channel: GetterCounter,
+    pub(crate) role: GetterCounter,
+    pub(crate) user: GetterCounter,
+    /// The number of updates of each [`CacheUpdate::KIND`].
+    ///
+    /// The map is only write-locked the first time a kind is recorded.
+    ///
+    /// [`CacheUpdate::KIND`]: trait.CacheUpdate.html#associatedconstant.KIND
+    updates: RwLock<HashMap<&'static str, AtomicU64>>,
+}
+
+impl StatCounters {
+    pub(crate) fn record_update(&self, kind: &'static str) {
+        if let Some(counter) = self.updates.read().expect("poisoned update counters").get(kind) {
+            counter.fetch_add(1, Ordering::Relaxed);
+
+            return;
+        }
+
+        self.updates
+            .write()
+            .expect("poisoned update counters")
+            .entry(kind)
+            .or_default()
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn updates(&self) -> HashMap<&'static str, u64> {
+        self.updates
+            .read()
+            .expect("poisoned update counters")
+            .iter()
+            .map(|(kind, counter)| (*kind, counter.load(Ordering::Relaxed)))
+            .collect()
+    }
+
+    pub(crate) fn getters(&self) -> HashMap<&'static str, GetterStats> {
+        let mut getters = HashMap::new();
+        getters.insert("category", self.category.load());
+        getters.insert("channel", self.channel.load());
+        getters.insert("guild", self.guild.load());
+        getters.insert("guild_channel", self.guild_channel.load());
+        getters.insert("member", self.member.load());
+        getters.insert("message", self.message.load());
+        getters.insert("private_channel", self.private_channel.load());
+        getters.insert("role", self.role.load());
+        getters.insert("user", self.user.load());
+
+        getters
+    }
+}
@@ -0,0 +1,78 @@
+use crate::model::id::UserId;
+use std::collections::{BTreeMap, HashMap};
+
+/// The number of guild members and private channel recipients referencing
+/// each cached user, along with the unreferenced users in the order they were
+/// last used.
+///
+/// Only unreferenced users are eligible for eviction, so keeping them apart
+/// means evicting never needs to scan the guilds or the user map.
+#[derive(Debug, Default)]
+pub(crate) struct UserRefs {
+    /// The reference count of each referenced user.
+    counts: HashMap<UserId, usize>,
+    /// The unreferenced users, keyed by the generation they were last used at
+    /// when queued.
+    ///
+    /// Retrieving a user only bumps its generation, so entries may be stale;
+    /// they are requeued when popped instead.
+    unreferenced: BTreeMap<u64, UserId>,
+    /// The key each user in `unreferenced` is queued at.
+    queued: HashMap<UserId, u64>,
+}
+
+impl UserRefs {
+    /// Adds a reference to the user, taking it out of the eviction queue.
+    pub(crate) fn reference(&mut self, user_id: UserId) {
+        let count = self.counts.entry(user_id).or_insert(0);
+        *count += 1;
+
+        if *count == 1 {
+            if let Some(generation) = self.queued.remove(&user_id) {
+                self.unreferenced.remove(&generation);
+            }
+        }
+    }
+
+    /// Removes a reference to the user, queueing it for eviction at
+    /// `generation` once no references are left.
+    ///
+    /// `generation` is `None` if the user is not cached.
+    pub(crate) fn release(&mut self, user_id: UserId, generation: Option<u64>) {
+        match self.counts.get_mut(&user_id) {
+            Some(count) if *count > 1 => {
+                *count -= 1;
+
+                return;
+            },
+            Some(_) => {
+                self.counts.remove(&user_id);
+            },
+            None => return,
+        }
+
+        if let Some(generation) = generation {
+            self.queue(user_id, generation);
+        }
+    }
+
+    /// Queues the user for eviction at `generation`, unless it is referenced
+    /// or already queued.
+    pub(crate) fn queue(&mut self, user_id: UserId, generation: u64) {
+        if self.counts.contains_key(&user_id) || self.queued.contains_key(&user_id) {
+            return;
+        }
+
+        self.unreferenced.insert(generation, user_id);
+        self.queued.insert(user_id, generation);
+    }
+
+    /// Takes the least recently queued unreferenced user out of the queue.
+    pub(crate) fn pop(&mut self) -> Option<(u64, UserId)> {
+        let generation = *self.unreferenced.keys().next()?;
+        let user_id = self.unreferenced.remove(&generation)?;
+        self.queued.remove(&user_id);
+
+        Some((generation, user_id))
+    }
+}
@@ -23,8 +23,6 @@ use typemap_rev::TypeMap;
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
 #[cfg(feature = "cache")]
-use crate::model::id::GuildId;
-#[cfg(feature = "cache")]
 use crate::cache::{Cache, CacheUpdate};
 #[cfg(feature = "cache")]
 use std::fmt;
@@ -157,6 +155,25 @@ pub(crate) fn dispatch<'rec>(
     cache_and_http: Arc<CacheAndHttp>,
 ) -> BoxFuture<'rec, ()> {
     async move {
+        #[cfg(feature = "framework")]
+        {
+            if let DispatchEvent::Model(Event::MessageUpdate(ref event)) = event {
+                if framework.tracks_edits() {
+                    #[cfg(not(feature = "cache"))]
+                    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+                    #[cfg(feature = "cache")]
+                    let context = context(data, runner_tx, shard_id, &cache_and_http.http, &cache_and_http.cache);
+
+                    let framework = Arc::clone(&framework);
+                    let event = event.clone();
+
+                    tokio::spawn(async move {
+                        framework.dispatch_edit(context, event).await;
+                    });
+                }
+            }
+        }
+
         match (event_handler, raw_event_handler) {
             (None, None) => {
                 event.update(&cache_and_http).await;
@@ -433,14 +450,13 @@ async fn handle_event(
             {
                 let context = context.clone();
 
-                if cache_and_http.cache.unavailable_guilds.read().await.is_empty() {
+                // Only the arrival of the last unavailable guild makes the
+                // cache ready, rather than every later guild creation.
+                if !_is_new && cache_and_http.cache.unavailable_guilds.read().await.is_empty() {
                     let guild_amount = cache_and_http.cache
-                        .guilds
-                        .read()
-                        .await
-                        .iter()
-                        .map(|(&id, _)| id)
-                        .collect::<Vec<GuildId>>();
+                        .backend
+                        .guild_ids()
+                        .await;
                     let event_handler = Arc::clone(event_handler);
 
                     tokio::spawn(async move {
@@ -44,6 +44,8 @@ pub use crate::CacheAndHttp;
 
 #[cfg(feature = "cache")]
 pub use crate::cache::Cache;
+#[cfg(all(feature = "cache", feature = "gateway"))]
+use crate::cache::{CacheBackend, CacheResources, Settings as CacheSettings};
 
 use crate::internal::prelude::*;
 use tokio::sync::{Mutex, RwLock};
@@ -61,6 +63,8 @@ use std::{
 #[cfg(all(feature = "cache", feature = "gateway"))]
 use std::time::Duration;
 use log::{error, debug, info};
+#[cfg(all(feature = "cache", feature = "gateway"))]
+use log::warn;
 
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
@@ -85,6 +89,10 @@ pub struct ClientBuilder<'a> {
     intents: Option<GatewayIntents>,
     #[cfg(feature = "cache")]
     timeout: Option<Duration>,
+    #[cfg(feature = "cache")]
+    cache_settings: CacheSettings,
+    #[cfg(feature = "cache")]
+    cache_backend: Option<Box<dyn CacheBackend>>,
     #[cfg(feature = "framework")]
     framework: Option<Arc<Box<dyn Framework + Send + Sync + 'static>>>,
     event_handler: Option<Arc<dyn EventHandler>>,
@@ -112,6 +120,10 @@ impl<'a> ClientBuilder<'a> {
             intents: None,
             #[cfg(feature = "cache")]
             timeout: None,
+            #[cfg(feature = "cache")]
+            cache_settings: CacheSettings::default(),
+            #[cfg(feature = "cache")]
+            cache_backend: None,
             #[cfg(feature = "framework")]
             framework: None,
             event_handler: None,
@@ -180,6 +192,34 @@ impl<'a> ClientBuilder<'a> {
         self
     }
 
+    /// Sets the settings of the cache.
+    ///
+    /// If [`intents`] are set, a warning is logged for every kind of cached
+    /// resource the configured intents do not receive events for.
+    ///
+    /// [`intents`]: #method.intents
+    #[cfg(feature = "cache")]
+    pub fn cache_settings<F>(mut self, f: F) -> Self
+    where F: FnOnce(&mut CacheSettings) -> &mut CacheSettings
+    {
+        f(&mut self.cache_settings);
+
+        self
+    }
+
+    /// Sets the backend storing the cache's guilds, guild channels and users.
+    ///
+    /// By default, they are kept in memory. Refer to [`CacheBackend`] for more
+    /// information.
+    ///
+    /// [`CacheBackend`]: ../cache/trait.CacheBackend.html
+    #[cfg(feature = "cache")]
+    pub fn cache_backend<B: CacheBackend + 'static>(mut self, backend: B) -> Self {
+        self.cache_backend = Some(Box::new(backend));
+
+        self
+    }
+
     /// Whether presence or typing events shall be received over the gateway.
     ///
     /// **Info**:
@@ -295,9 +335,22 @@ impl<'a> Future for ClientBuilder<'a> {
                 UserId(0),
             )));
 
+            #[cfg(feature = "cache")]
+            {
+                if let Some(intents) = intents {
+                    warn_uncached_resources(&self.cache_settings, intents);
+                }
+            }
+
+            #[cfg(feature = "cache")]
+            let cache = match self.cache_backend.take() {
+                Some(backend) => Cache::new_with_backend(self.cache_settings.clone(), backend),
+                None => Cache::new_with_settings(self.cache_settings.clone()),
+            };
+
             let cache_and_http = Arc::new(CacheAndHttp {
                 #[cfg(feature = "cache")]
-                cache: Arc::new(Cache::default()),
+                cache: Arc::new(cache),
                 #[cfg(feature = "cache")]
                 update_cache_timeout: self.timeout.take(),
                 http: Arc::clone(&http),
@@ -342,6 +395,33 @@ impl<'a> Future for ClientBuilder<'a> {
     }
 }
 
+/// Logs a warning for every resource the cache is configured to store while
+/// none of the `intents` needed to populate it are set.
+#[cfg(all(feature = "cache", feature = "gateway"))]
+fn warn_uncached_resources(settings: &CacheSettings, intents: GatewayIntents) {
+    let requirements = [
+        (CacheResources::GUILDS, "guilds", GatewayIntents::GUILDS),
+        (CacheResources::CHANNELS, "channels", GatewayIntents::GUILDS | GatewayIntents::DIRECT_MESSAGES),
+        (CacheResources::MEMBERS, "members", GatewayIntents::GUILD_MEMBERS),
+        (CacheResources::ROLES, "roles", GatewayIntents::GUILDS),
+        (CacheResources::EMOJIS, "emojis", GatewayIntents::GUILD_EMOJIS),
+        (CacheResources::PRESENCES, "presences", GatewayIntents::GUILD_PRESENCES),
+        (CacheResources::MESSAGES, "messages", GatewayIntents::GUILD_MESSAGES | GatewayIntents::DIRECT_MESSAGES),
+        (CacheResources::VOICE_STATES, "voice states", GatewayIntents::GUILD_VOICE_STATES),
+        (CacheResources::USERS, "users", GatewayIntents::GUILDS | GatewayIntents::GUILD_MEMBERS | GatewayIntents::GUILD_PRESENCES | GatewayIntents::DIRECT_MESSAGES),
+    ];
+
+    for (resource, name, required) in requirements.iter() {
+        if settings.caches(*resource) && !intents.intersects(*required) {
+            warn!(
+                "The cache is configured to store {}, but none of the intents {:?} are set to receive them",
+                name,
+                required,
+            );
+        }
+    }
+}
+
 /// The Client is the way to be able to start sending authenticated requests
 /// over the REST API, as well as initializing a WebSocket connection through
 /// [`Shard`]s. Refer to the [documentation on using sharding][sharding docs]
@@ -85,6 +85,7 @@ pub use self::standard::StandardFramework;
 
 use crate::client::Context;
 use crate::model::channel::Message;
+use crate::model::event::MessageUpdateEvent;
 use async_trait::async_trait;
 
 /// A trait for defining your own framework for serenity to use.
@@ -96,6 +97,20 @@ use async_trait::async_trait;
 #[async_trait]
 pub trait Framework: Send + Sync {
     async fn dispatch(&self, _: Context, _: Message);
+
+    /// Called when a message is edited, e.g. to run its command again.
+    ///
+    /// Ignores the edit by default.
+    async fn dispatch_edit(&self, _: Context, _: MessageUpdateEvent) {}
+
+    /// Whether message edits should be passed to [`dispatch_edit`].
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`dispatch_edit`]: #method.dispatch_edit
+    fn tracks_edits(&self) -> bool {
+        false
+    }
 }
 
 #[async_trait]
@@ -106,6 +121,16 @@ where F: Framework + ?Sized
     async fn dispatch(&self, ctx: Context, msg: Message) {
         (**self).dispatch(ctx, msg).await;
     }
+
+    #[inline]
+    async fn dispatch_edit(&self, ctx: Context, event: MessageUpdateEvent) {
+        (**self).dispatch_edit(ctx, event).await;
+    }
+
+    #[inline]
+    fn tracks_edits(&self) -> bool {
+        (**self).tracks_edits()
+    }
 }
 
 #[async_trait]
@@ -116,4 +141,14 @@ where F: Framework + ?Sized
     async fn dispatch(&self, ctx: Context, msg: Message) {
         (**self).dispatch(ctx, msg).await;
     }
+
+    #[inline]
+    async fn dispatch_edit(&self, ctx: Context, event: MessageUpdateEvent) {
+        (**self).dispatch_edit(ctx, event).await;
+    }
+
+    #[inline]
+    fn tracks_edits(&self) -> bool {
+        (**self).tracks_edits()
+    }
 }
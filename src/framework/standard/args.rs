@@ -1,7 +1,12 @@
 use uwl::Stream;
 
+use super::{ArgumentConvert, CommandError};
+use crate::client::Context;
+use crate::model::channel::Message;
+
 use std::error::Error as StdError;
 use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
 use std::{fmt, str::FromStr};
 use std::borrow::Cow;
 
@@ -834,8 +839,174 @@ impl Args {
 
         self.len() - self.offset
     }
+
+    /// Convert the current argument via [`ArgumentConvert`], e.g. to a member
+    /// of the guild the message was sent in, and advance.
+    ///
+    /// Modifications of [`trimmed`] and [`quoted`] are also applied if they were called.
+    ///
+    /// [`ArgumentConvert`]: trait.ArgumentConvert.html
+    /// [`trimmed`]: #method.trimmed
+    /// [`quoted`]: #method.quoted
+    pub async fn single_convert<T: ArgumentConvert>(&mut self, ctx: &Context, msg: &Message) -> Result<T, T::Err> {
+        let arg = self.current().ok_or(Error::Eos)?;
+        let p = T::convert(ctx, msg.guild_id, Some(msg.channel_id), arg).await?;
+        self.advance();
+
+        Ok(p)
+    }
+
+    /// Converts the argument of the typed parameter `parameter`, for the
+    /// `#[command]` macro.
+    #[doc(hidden)]
+    pub async fn __parameter<T: ArgumentConvert>(
+        &mut self,
+        ctx: &Context,
+        msg: &Message,
+        parameter: &'static str,
+        usage: &'static str,
+    ) -> ::std::result::Result<T, CommandError>
+    where
+        T::Err: fmt::Display,
+    {
+        self.quoted()
+            .single_convert::<T>(ctx, msg)
+            .await
+            .map_err(|e| ParameterError::new(parameter, usage, e).into())
+    }
+
+    /// Converts the argument of the optional typed parameter `parameter`, for
+    /// the `#[command]` macro.
+    ///
+    /// An argument that fails to convert is left for the next parameter.
+    #[doc(hidden)]
+    pub async fn __optional_parameter<T: ArgumentConvert>(
+        &mut self,
+        ctx: &Context,
+        msg: &Message,
+        _parameter: &'static str,
+        _usage: &'static str,
+    ) -> ::std::result::Result<Option<T>, CommandError> {
+        Ok(self.quoted().single_convert::<T>(ctx, msg).await.ok())
+    }
+
+    /// Converts the remaining arguments as the typed parameter `parameter`,
+    /// for the `#[command]` macro.
+    #[doc(hidden)]
+    pub async fn __rest_parameter<T: ArgumentConvert>(
+        &mut self,
+        ctx: &Context,
+        msg: &Message,
+        parameter: &'static str,
+        usage: &'static str,
+    ) -> ::std::result::Result<Rest<T>, CommandError>
+    where
+        T::Err: fmt::Display,
+    {
+        let res = match self.remains() {
+            Some(rest) => T::convert(ctx, msg.guild_id, Some(msg.channel_id), rest).await.map_err(Error::Parse),
+            None => Err(Error::Eos),
+        };
+        let res = res.map_err(|e| ParameterError::new(parameter, usage, e))?;
+        self.offset = self.len();
+
+        Ok(Rest(res))
+    }
+
+    /// Converts the remaining arguments, if any, as the optional typed
+    /// parameter `parameter`, for the `#[command]` macro.
+    #[doc(hidden)]
+    pub async fn __optional_rest_parameter<T: ArgumentConvert>(
+        &mut self,
+        ctx: &Context,
+        msg: &Message,
+        _parameter: &'static str,
+        _usage: &'static str,
+    ) -> ::std::result::Result<Option<Rest<T>>, CommandError> {
+        let res = match self.remains() {
+            Some(rest) => T::convert(ctx, msg.guild_id, Some(msg.channel_id), rest).await.ok(),
+            None => None,
+        };
+
+        if res.is_some() {
+            self.offset = self.len();
+        }
+
+        Ok(res.map(Rest))
+    }
 }
 
+/// A typed parameter of a command that takes all of the remaining arguments,
+/// as they were written.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::prelude::*;
+/// # use serenity::model::prelude::*;
+/// use serenity::framework::standard::{macros::command, CommandResult, Rest};
+///
+/// #[command]
+/// async fn warn(ctx: &Context, msg: &Message, user: UserId, reason: Rest<String>) -> CommandResult {
+///     msg.channel_id.say(&ctx.http, format!("Warned {}: {}", user.mention(), *reason)).await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Rest<T>(pub T);
+
+impl<T> Deref for Rest<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Rest<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// A typed parameter of a command that could not be parsed, surfaced as
+/// [`DispatchError::ArgumentParse`] by the framework.
+///
+/// [`DispatchError::ArgumentParse`]: enum.DispatchError.html#variant.ArgumentParse
+#[derive(Debug)]
+pub(crate) struct ParameterError {
+    pub parameter: &'static str,
+    pub usage: &'static str,
+    pub error: Option<String>,
+}
+
+impl ParameterError {
+    fn new<E: fmt::Display>(parameter: &'static str, usage: &'static str, error: Error<E>) -> Self {
+        let error = match error {
+            Error::Parse(e) => Some(e.to_string()),
+            _ => None,
+        };
+
+        ParameterError {
+            parameter,
+            usage,
+            error,
+        }
+    }
+}
+
+impl fmt::Display for ParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.error {
+            Some(error) => write!(f, "invalid argument for `{}`: {}", self.parameter, error),
+            None => write!(f, "missing argument for `{}`", self.parameter),
+        }
+    }
+}
+
+impl StdError for ParameterError {}
+
 /// Parse each argument individually, as an iterator.
 pub struct Iter<'a, T: FromStr> {
     args: &'a mut Args,
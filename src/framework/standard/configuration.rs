@@ -2,9 +2,13 @@ use super::Delimiter;
 use crate::client::Context;
 use crate::model::{channel::Message, id::{UserId, GuildId, ChannelId}};
 use std::collections::HashSet;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::Duration;
 use futures::future::BoxFuture;
 
-type DynamicPrefixHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, Option<String>>;
+type DynamicPrefixHook = Arc<dyn for<'fut> Fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, Option<String>> + Send + Sync>;
+type GuildSettingsHook = Arc<dyn for<'fut> Fn(&'fut Context, GuildId) -> BoxFuture<'fut, Option<GuildSettings>> + Send + Sync>;
 
 /// A configuration struct for deciding whether the framework
 /// should allow optional whitespace between prefixes, group prefixes and command names.
@@ -59,6 +63,74 @@ impl From<(bool, bool, bool)> for WithWhiteSpace {
     }
 }
 
+/// Settings of a guild overriding those of the [`Configuration`], returned by
+/// the hook set via [`Configuration::guild_settings`].
+///
+/// Each setting that is set replaces the global one for commands invoked in
+/// the guild, while the others are inherited.
+///
+/// [`Configuration`]: struct.Configuration.html
+/// [`Configuration::guild_settings`]: struct.Configuration.html#method.guild_settings
+#[derive(Clone, Debug, Default)]
+pub struct GuildSettings {
+    #[doc(hidden)]
+    pub allowed_channels: Option<HashSet<ChannelId>>,
+    #[doc(hidden)]
+    pub blocked_users: Option<HashSet<UserId>>,
+    #[doc(hidden)]
+    pub case_insensitive: Option<bool>,
+    #[doc(hidden)]
+    pub disabled_commands: Option<HashSet<String>>,
+}
+
+impl GuildSettings {
+    /// HashSet of channel Ids of the guild where commands will be working.
+    ///
+    /// Refer to [`Configuration::allowed_channels`] for more information.
+    ///
+    /// [`Configuration::allowed_channels`]: struct.Configuration.html#method.allowed_channels
+    pub fn allowed_channels(&mut self, channels: HashSet<ChannelId>) -> &mut Self {
+        self.allowed_channels = Some(channels);
+
+        self
+    }
+
+    /// HashSet of user Ids whose commands will be ignored in the guild.
+    ///
+    /// Refer to [`Configuration::blocked_users`] for more information.
+    ///
+    /// [`Configuration::blocked_users`]: struct.Configuration.html#method.blocked_users
+    pub fn blocked_users(&mut self, users: HashSet<UserId>) -> &mut Self {
+        self.blocked_users = Some(users);
+
+        self
+    }
+
+    /// Whether command and group names are case insensitive in the guild.
+    ///
+    /// Unlike [`Configuration::case_insensitivity`], this does not apply to
+    /// prefixes, as the settings are only looked up once a message starts
+    /// with one.
+    ///
+    /// [`Configuration::case_insensitivity`]: struct.Configuration.html#method.case_insensitivity
+    pub fn case_insensitivity(&mut self, cs: bool) -> &mut Self {
+        self.case_insensitive = Some(cs);
+
+        self
+    }
+
+    /// HashSet of command names that won't be run in the guild.
+    ///
+    /// Refer to [`Configuration::disabled_commands`] for more information.
+    ///
+    /// [`Configuration::disabled_commands`]: struct.Configuration.html#method.disabled_commands
+    pub fn disabled_commands(&mut self, commands: HashSet<String>) -> &mut Self {
+        self.disabled_commands = Some(commands);
+
+        self
+    }
+}
+
 /// The configuration to use for a [`StandardFramework`] associated with a [`Client`]
 /// instance.
 ///
@@ -94,6 +166,7 @@ impl From<(bool, bool, bool)> for WithWhiteSpace {
 /// [`Client`]: ../../client/struct.Client.html
 /// [`StandardFramework`]: struct.StandardFramework.html
 /// [default implementation]: #impl-Default
+#[derive(Clone)]
 pub struct Configuration {
     #[doc(hidden)]
     pub allow_dm: bool,
@@ -112,6 +185,8 @@ pub struct Configuration {
     #[doc(hidden)]
     pub dynamic_prefixes: Vec<DynamicPrefixHook>,
     #[doc(hidden)]
+    pub guild_settings: Option<GuildSettingsHook>,
+    #[doc(hidden)]
     pub ignore_bots: bool,
     #[doc(hidden)]
     pub ignore_webhooks: bool,
@@ -127,6 +202,8 @@ pub struct Configuration {
     pub delimiters: Vec<Delimiter>,
     #[doc(hidden)]
     pub case_insensitive: bool,
+    #[doc(hidden)]
+    pub track_edits: Option<Duration>,
 }
 
 impl Configuration {
@@ -311,8 +388,54 @@ impl Configuration {
     ///     })));
     /// ```
     #[inline]
-    pub fn dynamic_prefix(&mut self, dynamic_prefix: DynamicPrefixHook) -> &mut Self {
-        self.dynamic_prefixes.push(dynamic_prefix);
+    pub fn dynamic_prefix<F>(&mut self, dynamic_prefix: F) -> &mut Self
+    where
+        F: for<'fut> Fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, Option<String>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.dynamic_prefixes.push(Arc::new(dynamic_prefix));
+
+        self
+    }
+
+    /// Sets the function looking up the settings of a guild, e.g. from a
+    /// database, which override the global ones for commands invoked in it.
+    ///
+    /// The function is called for messages in guilds starting with a prefix,
+    /// before the command is parsed. Return `None` to use the global settings.
+    ///
+    /// **Note**: Defaults to no guild settings.
+    ///
+    /// # Examples
+    ///
+    /// Disable the `ping` command in a single guild:
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::standard::{GuildSettings, StandardFramework};
+    ///
+    /// let framework = StandardFramework::new().configure(|c| c
+    ///     .guild_settings(|_, guild_id| Box::pin(async move {
+    ///         if guild_id.0 != 381880193251409931 {
+    ///             return None;
+    ///         }
+    ///
+    ///         let mut settings = GuildSettings::default();
+    ///         settings.disabled_commands(vec!["ping".to_string()].into_iter().collect());
+    ///
+    ///         Some(settings)
+    ///     })));
+    /// ```
+    #[inline]
+    pub fn guild_settings<F>(&mut self, guild_settings: F) -> &mut Self
+    where
+        F: for<'fut> Fn(&'fut Context, GuildId) -> BoxFuture<'fut, Option<GuildSettings>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.guild_settings = Some(Arc::new(guild_settings));
 
         self
     }
@@ -531,6 +654,85 @@ impl Configuration {
 
         self
     }
+
+    /// Runs commands again if their message is edited within `window` of
+    /// being sent, e.g. to fix a typo.
+    ///
+    /// Commands can edit their previous responses instead of sending new ones
+    /// via the framework's [`EditTracker`].
+    ///
+    /// Only messages starting with a prefix are tracked, and if the edited
+    /// message is not cached, it is fetched again.
+    ///
+    /// **Note**: Defaults to not tracking edits.
+    ///
+    /// # Examples
+    ///
+    /// Run commands again if edited within 5 minutes:
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::StandardFramework;
+    /// use std::time::Duration;
+    ///
+    /// let framework = StandardFramework::new().configure(|c| c
+    ///     .track_edits(Duration::from_secs(5 * 60)));
+    /// ```
+    ///
+    /// [`EditTracker`]: struct.EditTracker.html
+    pub fn track_edits(&mut self, window: Duration) -> &mut Self {
+        self.track_edits = Some(window);
+
+        self
+    }
+}
+
+/// The [`Configuration`] for a message, with the settings of its guild laid
+/// over it.
+///
+/// Dereferences to the global configuration, so the settings a guild may
+/// override must be read via the methods of the same names.
+///
+/// [`Configuration`]: struct.Configuration.html
+pub(crate) struct GuildConfig<'a> {
+    config: &'a Configuration,
+    settings: Option<GuildSettings>,
+}
+
+impl<'a> GuildConfig<'a> {
+    pub(crate) fn new(config: &'a Configuration, settings: Option<GuildSettings>) -> Self {
+        GuildConfig {
+            config,
+            settings,
+        }
+    }
+
+    fn setting<T>(&self, f: impl FnOnce(&GuildSettings) -> Option<&T>) -> Option<&T> {
+        self.settings.as_ref().and_then(f)
+    }
+
+    pub(crate) fn allowed_channels(&self) -> &HashSet<ChannelId> {
+        self.setting(|s| s.allowed_channels.as_ref()).unwrap_or(&self.config.allowed_channels)
+    }
+
+    pub(crate) fn blocked_users(&self) -> &HashSet<UserId> {
+        self.setting(|s| s.blocked_users.as_ref()).unwrap_or(&self.config.blocked_users)
+    }
+
+    pub(crate) fn case_insensitive(&self) -> bool {
+        *self.setting(|s| s.case_insensitive.as_ref()).unwrap_or(&self.config.case_insensitive)
+    }
+
+    pub(crate) fn disabled_commands(&self) -> &HashSet<String> {
+        self.setting(|s| s.disabled_commands.as_ref()).unwrap_or(&self.config.disabled_commands)
+    }
+}
+
+impl Deref for GuildConfig<'_> {
+    type Target = Configuration;
+
+    fn deref(&self) -> &Configuration {
+        self.config
+    }
 }
 
 impl Default for Configuration {
@@ -546,12 +748,14 @@ impl Default for Configuration {
     /// - **delimiters** to `vec![' ']`
     /// - **disabled_commands** to an empty HashSet
     /// - **dynamic_prefixes** to an empty vector
+    /// - **guild_settings** to `None`
     /// - **ignore_bots** to `true`
     /// - **ignore_webhooks** to `true`
     /// - **no_dm_prefix** to `false`
     /// - **on_mention** to `false`
     /// - **owners** to an empty HashSet
     /// - **prefix** to an empty vector
+    /// - **track_edits** to `None`
     fn default() -> Configuration {
         Configuration {
             allow_dm: true,
@@ -564,12 +768,14 @@ impl Default for Configuration {
             delimiters: vec![Delimiter::Single(' ')],
             disabled_commands: HashSet::default(),
             dynamic_prefixes: Vec::new(),
+            guild_settings: None,
             ignore_bots: true,
             ignore_webhooks: true,
             no_dm_prefix: false,
             on_mention: None,
             owners: HashSet::default(),
             prefixes: vec![],
+            track_edits: None,
         }
     }
 }
@@ -0,0 +1,477 @@
+use crate::client::Context;
+use crate::http::{HttpError, StatusCode};
+use crate::model::prelude::*;
+use crate::utils::{parse_channel, parse_emoji, parse_role, parse_username};
+use async_trait::async_trait;
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Parses a command argument that may need to be looked up, e.g. a member
+/// given by their mention, ID, or name.
+///
+/// Every type implementing `FromStr` converts via `from_str`, ignoring the
+/// context. The models are looked up in the cache first, if enabled, then via
+/// HTTP if the cache has no match.
+///
+/// Used by [`Args::single_convert`] and the typed parameters of commands.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::prelude::*;
+/// # use serenity::model::prelude::*;
+/// use serenity::framework::standard::{macros::command, Args, CommandResult};
+///
+/// #[command]
+/// async fn avatar(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+///     let member: Member = args.single_convert(ctx, msg).await?;
+///
+///     if let Some(url) = member.user.avatar_url() {
+///         msg.channel_id.say(&ctx.http, url).await?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Args::single_convert`]: struct.Args.html#method.single_convert
+#[async_trait]
+pub trait ArgumentConvert: Sized {
+    /// The error returned if the argument cannot be converted.
+    type Err;
+
+    /// Converts the argument `s`, given in the guild and channel the command
+    /// was invoked in.
+    async fn convert(
+        ctx: &Context,
+        guild_id: Option<GuildId>,
+        channel_id: Option<ChannelId>,
+        s: &str,
+    ) -> Result<Self, Self::Err>;
+}
+
+#[async_trait]
+impl<T: FromStr> ArgumentConvert for T {
+    type Err = T::Err;
+
+    async fn convert(
+        _: &Context,
+        _: Option<GuildId>,
+        _: Option<ChannelId>,
+        s: &str,
+    ) -> Result<Self, Self::Err> {
+        T::from_str(s)
+    }
+}
+
+/// Why an argument could not be converted to a model.
+#[derive(Debug)]
+pub enum ConvertError {
+    /// The model belongs to a guild, but the command was not invoked in one.
+    NotInGuild,
+    /// No model matches the argument.
+    NotFound,
+    /// The model could not be looked up, e.g. due to missing permissions.
+    Http(crate::Error),
+    #[doc(hidden)]
+    __Nonexhaustive,
+}
+
+impl From<crate::Error> for ConvertError {
+    fn from(e: crate::Error) -> Self {
+        if let crate::Error::Http(http) = &e {
+            if let HttpError::UnsuccessfulRequest(response) = &**http {
+                if response.status_code == StatusCode::NOT_FOUND {
+                    return ConvertError::NotFound;
+                }
+            }
+        }
+
+        ConvertError::Http(e)
+    }
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConvertError::NotInGuild => f.write_str("only available in guilds"),
+            ConvertError::NotFound => f.write_str("not found"),
+            ConvertError::Http(e) => fmt::Display::fmt(e, f),
+            ConvertError::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+
+impl StdError for ConvertError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            ConvertError::Http(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a mention via `parse`, or a raw ID.
+fn parse_id(s: &str, parse: fn(&str) -> Option<u64>) -> Option<u64> {
+    parse(s).or_else(|| s.parse().ok())
+}
+
+/// Whether `member` is named `s`, by their tag, username, or nickname.
+fn is_named(member: &Member, s: &str) -> bool {
+    member.user.tag() == s || member.user.name == s || member.nick.as_deref() == Some(s)
+}
+
+#[async_trait]
+impl ArgumentConvert for Member {
+    type Err = ConvertError;
+
+    /// Looks up a member of the guild by their mention, ID, tag, username,
+    /// or nickname.
+    async fn convert(
+        ctx: &Context,
+        guild_id: Option<GuildId>,
+        _: Option<ChannelId>,
+        s: &str,
+    ) -> Result<Self, Self::Err> {
+        let guild_id = guild_id.ok_or(ConvertError::NotInGuild)?;
+
+        if let Some(id) = parse_id(s, |s| parse_username(s)) {
+            return Ok(guild_id.member(ctx, id).await?);
+        }
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(Some(member)) = ctx.cache.guild_field(guild_id, |g| g.member_named(s).cloned()).await {
+                return Ok(member);
+            }
+        }
+
+        let query = s.rsplitn(2, '#').last().unwrap_or(s);
+
+        guild_id
+            .search_members(ctx, query, Some(100))
+            .await?
+            .into_iter()
+            .find(|m| is_named(m, s))
+            .ok_or(ConvertError::NotFound)
+    }
+}
+
+#[async_trait]
+impl ArgumentConvert for User {
+    type Err = ConvertError;
+
+    /// Looks up a user by their mention or ID, or by their tag, username, or
+    /// nickname in the guild.
+    async fn convert(
+        ctx: &Context,
+        guild_id: Option<GuildId>,
+        channel_id: Option<ChannelId>,
+        s: &str,
+    ) -> Result<Self, Self::Err> {
+        if let Some(id) = parse_id(s, |s| parse_username(s)) {
+            #[cfg(feature = "cache")]
+            {
+                if let Some(user) = ctx.cache.user(id).await {
+                    return Ok(user);
+                }
+            }
+
+            return Ok(ctx.http.get_user(id).await?);
+        }
+
+        Member::convert(ctx, guild_id, channel_id, s).await.map(|m| m.user)
+    }
+}
+
+#[async_trait]
+impl ArgumentConvert for Role {
+    type Err = ConvertError;
+
+    /// Looks up a role of the guild by its mention, ID, or name.
+    async fn convert(
+        ctx: &Context,
+        guild_id: Option<GuildId>,
+        _: Option<ChannelId>,
+        s: &str,
+    ) -> Result<Self, Self::Err> {
+        let guild_id = guild_id.ok_or(ConvertError::NotInGuild)?;
+        let id = parse_id(s, |s| parse_role(s)).map(RoleId);
+
+        #[cfg(feature = "cache")]
+        {
+            let role = ctx.cache.guild_field(guild_id, |g| {
+                g.roles.values().find(|role| Some(role.id) == id || role.name == s).cloned()
+            }).await;
+
+            if let Some(Some(role)) = role {
+                return Ok(role);
+            }
+        }
+
+        ctx.http
+            .get_guild_roles(guild_id.0)
+            .await?
+            .into_iter()
+            .find(|role| Some(role.id) == id || role.name == s)
+            .ok_or(ConvertError::NotFound)
+    }
+}
+
+#[async_trait]
+impl ArgumentConvert for GuildChannel {
+    type Err = ConvertError;
+
+    /// Looks up a channel of the guild by its mention, ID, or name, with or
+    /// without a leading `#`.
+    async fn convert(
+        ctx: &Context,
+        guild_id: Option<GuildId>,
+        _: Option<ChannelId>,
+        s: &str,
+    ) -> Result<Self, Self::Err> {
+        let guild_id = guild_id.ok_or(ConvertError::NotInGuild)?;
+        let id = parse_id(s, |s| parse_channel(s)).map(ChannelId);
+        let name = s.trim_start_matches('#');
+
+        #[cfg(feature = "cache")]
+        {
+            let channel = ctx.cache.guild_field(guild_id, |g| {
+                g.channels.values().find(|channel| Some(channel.id) == id || channel.name == name).cloned()
+            }).await;
+
+            if let Some(Some(channel)) = channel {
+                return Ok(channel);
+            }
+        }
+
+        guild_id
+            .channels(ctx)
+            .await?
+            .values()
+            .find(|channel| Some(channel.id) == id || channel.name == name)
+            .cloned()
+            .ok_or(ConvertError::NotFound)
+    }
+}
+
+#[async_trait]
+impl ArgumentConvert for Emoji {
+    type Err = ConvertError;
+
+    /// Looks up a custom emoji of the guild by itself, its ID, or its name,
+    /// with or without surrounding colons.
+    async fn convert(
+        ctx: &Context,
+        guild_id: Option<GuildId>,
+        _: Option<ChannelId>,
+        s: &str,
+    ) -> Result<Self, Self::Err> {
+        let guild_id = guild_id.ok_or(ConvertError::NotInGuild)?;
+        let id = parse_emoji(s).map(|e| e.id).or_else(|| s.parse().ok().map(EmojiId));
+        let name = s.trim_matches(':');
+
+        #[cfg(feature = "cache")]
+        {
+            let emoji = ctx.cache.guild_field(guild_id, |g| {
+                g.emojis.values().find(|emoji| Some(emoji.id) == id || emoji.name == name).cloned()
+            }).await;
+
+            if let Some(Some(emoji)) = emoji {
+                return Ok(emoji);
+            }
+        }
+
+        if let Some(id) = id {
+            return Ok(guild_id.emoji(ctx, id).await?);
+        }
+
+        guild_id
+            .emojis(ctx)
+            .await?
+            .into_iter()
+            .find(|emoji| emoji.name == name)
+            .ok_or(ConvertError::NotFound)
+    }
+}
+
+/// Parses a message link, `https://discord.com/channels/<guild>/<channel>/<message>`,
+/// where the guild is `@me` for direct messages.
+fn parse_message_link(s: &str) -> Option<(Option<GuildId>, ChannelId, MessageId)> {
+    let s = s.trim_start_matches('<').trim_end_matches('>');
+    let mut parts = s.split('/');
+
+    let scheme = parts.next()?;
+    if (scheme != "https:" && scheme != "http:") || !parts.next()?.is_empty() {
+        return None;
+    }
+
+    let host = parts.next()?;
+    if !(host.ends_with("discord.com") || host.ends_with("discordapp.com")) || parts.next()? != "channels" {
+        return None;
+    }
+
+    let guild_id = match parts.next()? {
+        "@me" => None,
+        guild_id => Some(GuildId(guild_id.parse().ok()?)),
+    };
+    let channel_id = parts.next()?.parse().ok()?;
+    let message_id = parts.next()?.parse().ok()?;
+
+    Some((guild_id, ChannelId(channel_id), MessageId(message_id)))
+}
+
+#[async_trait]
+impl ArgumentConvert for Message {
+    type Err = ConvertError;
+
+    /// Looks up a message by its link, by `<channel ID>-<message ID>` as
+    /// copied from the client, or by its ID in the channel.
+    ///
+    /// A link to a message of another guild, or of direct messages if the
+    /// command was invoked in a guild, is not found.
+    async fn convert(
+        ctx: &Context,
+        guild_id: Option<GuildId>,
+        channel_id: Option<ChannelId>,
+        s: &str,
+    ) -> Result<Self, Self::Err> {
+        let link = parse_message_link(s);
+
+        if let Some((link_guild_id, _, _)) = link {
+            if link_guild_id != guild_id {
+                return Err(ConvertError::NotFound);
+            }
+        }
+
+        let ids = link.map(|(_, channel_id, message_id)| (channel_id, message_id)).or_else(|| {
+            let mut parts = s.splitn(2, '-');
+            let channel_id = parts.next()?.parse().ok()?;
+            let message_id = parts.next()?.parse().ok()?;
+
+            Some((ChannelId(channel_id), MessageId(message_id)))
+        });
+
+        let (channel_id, message_id) = match ids {
+            Some(ids) => ids,
+            None => match (channel_id, s.parse().ok()) {
+                (Some(channel_id), Some(id)) => (channel_id, MessageId(id)),
+                _ => return Err(ConvertError::NotFound),
+            },
+        };
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(message) = ctx.cache.message(channel_id, message_id).await {
+                return Ok(message);
+            }
+        }
+
+        Ok(ctx.http.get_message(channel_id.0, message_id.0).await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn message_link() {
+        let ids = Some((Some(GuildId(1)), ChannelId(2), MessageId(3)));
+
+        assert_eq!(parse_message_link("https://discord.com/channels/1/2/3"), ids);
+        assert_eq!(
+            parse_message_link("<https://canary.discordapp.com/channels/@me/2/3>"),
+            Some((None, ChannelId(2), MessageId(3))),
+        );
+        assert_eq!(parse_message_link("https://example.com/channels/1/2/3"), None);
+        assert_eq!(parse_message_link("https://discord.com/channels/1/2"), None);
+    }
+
+    #[cfg(all(feature = "cache", feature = "gateway"))]
+    async fn context() -> (Context, GuildId) {
+        let ctx = super::super::test::context();
+        let mut guild_create: GuildCreateEvent = serde_json::from_str(
+            include_str!("../../../tests/resources/guild_create_1.json"),
+        ).unwrap();
+        ctx.cache.update(&mut guild_create).await;
+
+        (ctx, guild_create.guild.id)
+    }
+
+    /// Whether the conversion missed the cache and fell back to HTTP, which
+    /// fails in tests.
+    fn is_http<T>(res: Result<T, ConvertError>) -> bool {
+        match res {
+            Err(ConvertError::Http(_)) => true,
+            _ => false,
+        }
+    }
+
+    #[cfg(all(feature = "cache", feature = "gateway"))]
+    #[tokio::test]
+    async fn role() {
+        let (ctx, guild_id) = context().await;
+        let id = RoleId(159592059873787904);
+
+        for s in &["<@&159592059873787904>", "159592059873787904", "Contributor Bots"] {
+            assert_eq!(Role::convert(&ctx, Some(guild_id), None, s).await.unwrap().id, id);
+        }
+
+        assert!(is_http(Role::convert(&ctx, Some(guild_id), None, "Unknown").await));
+        assert!(is_http(Role::convert(&ctx, Some(GuildId(1)), None, "Contributor Bots").await));
+        match Role::convert(&ctx, None, None, "Contributor Bots").await {
+            Err(ConvertError::NotInGuild) => {},
+            res => panic!("converted to {:?}", res.map(|r| r.id)),
+        }
+    }
+
+    #[cfg(all(feature = "cache", feature = "gateway"))]
+    #[tokio::test]
+    async fn guild_channel() {
+        let (ctx, guild_id) = context().await;
+        let id = ChannelId(94831883505905664);
+
+        for s in &["<#94831883505905664>", "94831883505905664", "playground", "#playground"] {
+            assert_eq!(GuildChannel::convert(&ctx, Some(guild_id), None, s).await.unwrap().id, id);
+        }
+
+        assert!(is_http(GuildChannel::convert(&ctx, Some(guild_id), None, "unknown").await));
+    }
+
+    #[cfg(all(feature = "cache", feature = "gateway"))]
+    #[tokio::test]
+    async fn emoji() {
+        let (ctx, guild_id) = context().await;
+        let id = EmojiId(232720514114781185);
+
+        for s in &["<:python2:232720514114781185>", "232720514114781185", "python2", ":python2:"] {
+            assert_eq!(Emoji::convert(&ctx, Some(guild_id), None, s).await.unwrap().id, id);
+        }
+
+        assert!(is_http(Emoji::convert(&ctx, Some(guild_id), None, "unknown").await));
+    }
+
+    #[cfg(all(feature = "cache", feature = "gateway"))]
+    #[tokio::test]
+    async fn message() {
+        let (ctx, guild_id) = context().await;
+        let link = format!("https://discord.com/channels/{}/2/3", guild_id);
+
+        assert!(is_http(Message::convert(&ctx, Some(guild_id), None, &link).await));
+        assert!(is_http(Message::convert(&ctx, Some(guild_id), None, "2-3").await));
+
+        // Links to other guilds or to direct messages are not looked up.
+        let other = "https://discord.com/channels/1/2/3";
+        let private = "https://discord.com/channels/@me/2/3";
+
+        for (guild_id, link) in &[(Some(guild_id), other), (Some(guild_id), private), (None, other)] {
+            match Message::convert(&ctx, *guild_id, None, link).await {
+                Err(ConvertError::NotFound) => {},
+                res => panic!("{:?} converted to {:?}", link, res.map(|m| m.id)),
+            }
+        }
+    }
+}
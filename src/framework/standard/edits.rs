@@ -0,0 +1,297 @@
+use crate::http::Http;
+use crate::model::channel::Message;
+use crate::model::event::MessageUpdateEvent;
+use crate::model::id::{ChannelId, MessageId};
+use crate::Result;
+
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// The number of messages tracked at most, the oldest being forgotten first.
+const MAX_TRACKED: usize = 1000;
+
+struct Tracked {
+    edited_timestamp: Option<DateTime<Utc>>,
+    responses: Vec<(ChannelId, MessageId)>,
+    since: Instant,
+}
+
+#[derive(Default)]
+struct Inner {
+    tracked: HashMap<MessageId, Tracked>,
+    /// The tracked messages, oldest first.
+    order: VecDeque<MessageId>,
+}
+
+/// A handle to the messages invoking commands whose edits are tracked by the
+/// [`StandardFramework`], created via [`StandardFramework::edit_tracker`].
+///
+/// Messages are tracked if [`Configuration::track_edits`] is set and they
+/// invoke the framework with a prefix. Once one is edited within the
+/// configured window, its command is run again. By sending
+/// their responses through the tracker, commands edit their previous response
+/// when they are run again, instead of sending another one.
+///
+/// # Examples
+///
+/// Keep the tracker in the `Client`'s data for commands to reply through it:
+///
+/// ```rust,no_run
+/// # use serenity::prelude::*;
+/// # use serenity::model::prelude::*;
+/// use serenity::framework::standard::{macros::command, CommandResult, EditTracker};
+///
+/// struct EditTrackerKey;
+///
+/// impl TypeMapKey for EditTrackerKey {
+///     type Value = EditTracker;
+/// }
+///
+/// #[command]
+/// async fn echo(ctx: &Context, msg: &Message) -> CommandResult {
+///     let tracker = ctx.data.read().await.get::<EditTrackerKey>().cloned().unwrap();
+///
+///     tracker.say(ctx, msg, &msg.content).await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Configuration::track_edits`]: struct.Configuration.html#method.track_edits
+/// [`StandardFramework`]: struct.StandardFramework.html
+/// [`StandardFramework::edit_tracker`]: struct.StandardFramework.html#method.edit_tracker
+#[derive(Clone, Default)]
+pub struct EditTracker {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl EditTracker {
+    /// Sends `content` in reply to `msg`, or edits the first response to it
+    /// if the command is run again after an edit.
+    pub async fn say(&self, http: impl AsRef<Http>, msg: &Message, content: impl fmt::Display) -> Result<Message> {
+        let response = self.inner.lock().await
+            .tracked
+            .get(&msg.id)
+            .and_then(|t| t.responses.first().copied());
+
+        if let Some((channel_id, message_id)) = response {
+            return channel_id.edit_message(http, message_id, |m| m.content(content)).await;
+        }
+
+        let response = msg.channel_id.say(http, content).await?;
+        self.track_response(msg, &response).await;
+
+        Ok(response)
+    }
+
+    /// Records `response` as a response to `msg`, if its edits are tracked.
+    ///
+    /// Use this for responses not sent via [`say`], e.g. embeds.
+    ///
+    /// [`say`]: #method.say
+    pub async fn track_response(&self, msg: &Message, response: &Message) {
+        if let Some(tracked) = self.inner.lock().await.tracked.get_mut(&msg.id) {
+            tracked.responses.push((response.channel_id, response.id));
+        }
+    }
+
+    /// The recorded responses to `msg`, in the order they were sent.
+    pub async fn responses(&self, msg: &Message) -> Vec<(ChannelId, MessageId)> {
+        self.inner.lock().await
+            .tracked
+            .get(&msg.id)
+            .map_or_else(Vec::new, |t| t.responses.clone())
+    }
+
+    /// Deletes the recorded responses to `msg`, e.g. once its command is run
+    /// again with different responses.
+    pub async fn delete_responses(&self, http: impl AsRef<Http>, msg: &Message) -> Result<()> {
+        let responses = match self.inner.lock().await.tracked.get_mut(&msg.id) {
+            Some(tracked) => tracked.responses.drain(..).collect::<Vec<_>>(),
+            None => return Ok(()),
+        };
+
+        for (channel_id, message_id) in responses {
+            channel_id.delete_message(&http, message_id).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Starts tracking the edits of `msg`, unless it is already tracked.
+    ///
+    /// Messages tracked for longer than `window` are forgotten, as well as the
+    /// oldest ones once `MAX_TRACKED` messages are tracked.
+    pub(crate) async fn track(&self, msg: &Message, window: Duration) {
+        let mut inner = self.inner.lock().await;
+
+        if inner.tracked.contains_key(&msg.id) {
+            return;
+        }
+
+        while let Some(&id) = inner.order.front() {
+            let expired = match inner.tracked.get(&id) {
+                Some(tracked) => tracked.since.elapsed() >= window,
+                None => true,
+            };
+
+            if !expired && inner.order.len() < MAX_TRACKED {
+                break;
+            }
+
+            inner.order.pop_front();
+            inner.tracked.remove(&id);
+        }
+
+        inner.order.push_back(msg.id);
+        inner.tracked.insert(msg.id, Tracked {
+            edited_timestamp: msg.edited_timestamp,
+            responses: Vec::new(),
+            since: Instant::now(),
+        });
+    }
+
+    /// Records an edit of a tracked message, returning whether it was tracked
+    /// for less than `window` and this is a new edit of its content.
+    ///
+    /// Updates without an edit timestamp, e.g. for embeds being loaded, are
+    /// not edits.
+    pub(crate) async fn edit(&self, event: &MessageUpdateEvent, window: Duration) -> bool {
+        let mut inner = self.inner.lock().await;
+
+        let tracked = match inner.tracked.get_mut(&event.id) {
+            Some(tracked) => tracked,
+            None => return false,
+        };
+
+        if tracked.since.elapsed() >= window {
+            inner.tracked.remove(&event.id);
+
+            return false;
+        }
+
+        let edited_timestamp = match event.edited_timestamp {
+            Some(edited_timestamp) if event.content.is_some() => edited_timestamp,
+            _ => return false,
+        };
+
+        if let Some(previous) = tracked.edited_timestamp {
+            if previous >= edited_timestamp {
+                return false;
+            }
+        }
+
+        tracked.edited_timestamp = Some(edited_timestamp);
+
+        true
+    }
+}
+
+/// Applies the changes of an edit to `message`.
+#[cfg(feature = "cache")]
+pub(crate) fn apply(message: &mut Message, event: &MessageUpdateEvent) {
+    if let Some(content) = &event.content {
+        message.content = content.clone();
+    }
+
+    if let Some(edited_timestamp) = event.edited_timestamp {
+        message.edited_timestamp = Some(edited_timestamp);
+    }
+
+    if let Some(mentions) = &event.mentions {
+        message.mentions = mentions.clone();
+    }
+
+    if let Some(mention_everyone) = event.mention_everyone {
+        message.mention_everyone = mention_everyone;
+    }
+
+    if let Some(mention_roles) = &event.mention_roles {
+        message.mention_roles = mention_roles.clone();
+    }
+
+    if let Some(attachments) = &event.attachments {
+        message.attachments = attachments.clone();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::CustomMessage;
+    use chrono::TimeZone;
+
+    fn message(content: &str) -> Message {
+        let mut msg = CustomMessage::new();
+        msg.id(MessageId(1)).content(content);
+
+        msg.build()
+    }
+
+    fn update(content: Option<&str>, edited_secs: Option<i64>) -> MessageUpdateEvent {
+        MessageUpdateEvent {
+            id: MessageId(1),
+            guild_id: None,
+            channel_id: ChannelId(2),
+            kind: None,
+            content: content.map(ToString::to_string),
+            nonce: None,
+            tts: None,
+            pinned: None,
+            timestamp: None,
+            edited_timestamp: edited_secs.map(|secs| Utc.timestamp(secs, 0)),
+            author: None,
+            mention_everyone: None,
+            mentions: None,
+            mention_roles: None,
+            attachments: None,
+            embeds: None,
+            _nonexhaustive: (),
+        }
+    }
+
+    #[tokio::test]
+    async fn edits() {
+        let tracker = EditTracker::default();
+        let window = Duration::from_secs(60);
+
+        assert!(!tracker.edit(&update(Some("~ping"), Some(1)), window).await);
+
+        tracker.track(&message("~pnig"), window).await;
+        tracker.track_response(&message("~pnig"), &message("Pong!")).await;
+
+        // Embeds being loaded, or the message being pinned.
+        assert!(!tracker.edit(&update(None, None), window).await);
+        assert!(!tracker.edit(&update(Some("~pnig"), None), window).await);
+
+        assert!(tracker.edit(&update(Some("~ping"), Some(1)), window).await);
+        assert!(!tracker.edit(&update(Some("~ping"), Some(1)), window).await);
+
+        tracker.track(&message("~ping"), window).await;
+        assert_eq!(tracker.responses(&message("~ping")).await.len(), 1);
+
+        assert!(!tracker.edit(&update(Some("~pong"), Some(2)), Duration::from_secs(0)).await);
+        assert!(tracker.responses(&message("~ping")).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn max_tracked() {
+        let tracker = EditTracker::default();
+        let window = Duration::from_secs(60);
+
+        for id in 1..=MAX_TRACKED as u64 + 1 {
+            let mut msg = CustomMessage::new();
+            msg.id(MessageId(id));
+
+            tracker.track(&msg.build(), window).await;
+        }
+
+        let inner = tracker.inner.lock().await;
+        assert_eq!(inner.tracked.len(), MAX_TRACKED);
+        assert!(!inner.tracked.contains_key(&MessageId(1)));
+    }
+}
@@ -50,8 +50,18 @@
 //! The same can be accomplished with no embeds by substituting `with_embeds`
 //! with the [`plain`] function.
 //!
+//! Bots with more commands than fit into one embed can use the
+//! [`with_embeds_paginated`] function instead, which requires the `collector`
+//! feature.
+//!
+//! Each of these functions has a `_localized` variant translating the texts of
+//! the help into the locale of the invocation, as resolved by a
+//! [`Localization`].
+//!
+//! [`Localization`]: ../struct.Localization.html
 //! [`plain`]: fn.plain.html
 //! [`with_embeds`]: fn.with_embeds.html
+//! [`with_embeds_paginated`]: fn.with_embeds_paginated.html
 
 #[cfg(all(feature = "cache", feature = "http"))]
 use super::{
@@ -59,6 +69,7 @@ use super::{
     CheckResult, has_correct_roles, HelpBehaviour,
     HelpOptions, has_correct_permissions, OnlyIn,
     structures::Command as InternalCommand,
+    Translator,
 };
 #[cfg(all(feature = "cache", feature = "http"))]
 use crate::{
@@ -78,11 +89,34 @@ use std::{
     fmt::Write,
     ops::{Index, IndexMut},
 };
+#[cfg(all(feature = "cache", feature = "collector", feature = "http"))]
+use crate::{
+    builder::CreateEmbed,
+    constants::EMBED_MAX_LENGTH,
+    model::channel::ReactionType,
+};
+#[cfg(all(feature = "cache", feature = "collector", feature = "http"))]
+use std::time::Duration;
+#[cfg(all(feature = "cache", feature = "collector", feature = "http"))]
+use futures::stream::StreamExt;
 #[cfg(all(feature = "cache", feature = "http"))]
 use log::warn;
 #[cfg(all(feature = "cache", feature = "http"))]
 use futures::future::{BoxFuture, FutureExt};
 
+/// The maximum length of the value of an embed field.
+#[cfg(all(feature = "cache", feature = "collector", feature = "http"))]
+const EMBED_FIELD_VALUE_MAX_LENGTH: usize = 1024;
+/// The maximum number of fields of an embed.
+#[cfg(all(feature = "cache", feature = "collector", feature = "http"))]
+const EMBED_MAX_FIELDS: usize = 25;
+/// The reaction turning the paginated help to the previous page.
+#[cfg(all(feature = "cache", feature = "collector", feature = "http"))]
+const PREVIOUS_PAGE_EMOJI: &str = "\u{25c0}\u{fe0f}";
+/// The reaction turning the paginated help to the next page.
+#[cfg(all(feature = "cache", feature = "collector", feature = "http"))]
+const NEXT_PAGE_EMOJI: &str = "\u{25b6}\u{fe0f}";
+
 /// Macro to format a command according to a `HelpBehaviour` or
 /// continue to the next command-name upon hiding.
 #[cfg(all(feature = "cache", feature = "http"))]
@@ -106,6 +140,139 @@ macro_rules! warn_about_failed_send {
     }
 }
 
+/// Declares the texts of the help taken from `HelpOptions`, and their
+/// translation via the `help.<field>` keys.
+#[cfg(all(feature = "cache", feature = "http"))]
+macro_rules! help_texts {
+    ($($text:ident,)* ; $($optional_text:ident,)*) => {
+        /// The texts of the help, either as set in `HelpOptions` or translated.
+        #[derive(Clone, Copy)]
+        struct HelpTexts<'a> {
+            $($text: &'a str,)*
+            $($optional_text: Option<&'a str>,)*
+        }
+
+        impl<'a> HelpTexts<'a> {
+            fn new(help_options: &'a HelpOptions) -> Self {
+                HelpTexts {
+                    $($text: help_options.$text,)*
+                    $($optional_text: help_options.$optional_text,)*
+                }
+            }
+        }
+
+        /// The texts of the help translated into the locale of an invocation.
+        struct TranslatedHelpTexts {
+            $($text: String,)*
+            $($optional_text: Option<String>,)*
+        }
+
+        impl TranslatedHelpTexts {
+            async fn new(translator: &Translator<'_>, help_options: &HelpOptions) -> Self {
+                TranslatedHelpTexts {
+                    $($text: translator.text(
+                        concat!("help.", stringify!($text)),
+                        help_options.$text,
+                    ).await,)*
+                    $($optional_text: match help_options.$optional_text {
+                        Some(text) => Some(translator.text(
+                            concat!("help.", stringify!($optional_text)),
+                            text,
+                        ).await),
+                        None => None,
+                    },)*
+                }
+            }
+
+            fn texts(&self) -> HelpTexts<'_> {
+                HelpTexts {
+                    $($text: &self.$text,)*
+                    $($optional_text: self.$optional_text.as_deref(),)*
+                }
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "cache", feature = "http"))]
+help_texts! {
+    suggestion_text,
+    no_help_available_text,
+    usage_label,
+    usage_sample_label,
+    description_label,
+    grouped_label,
+    aliases_label,
+    guild_only_text,
+    checks_label,
+    sub_commands_label,
+    default_sub_command_label,
+    dm_only_text,
+    dm_and_guild_text,
+    available_text,
+    individual_command_tip,
+    group_prefix,
+    ;
+    strikethrough_commands_tip_in_dm,
+    strikethrough_commands_tip_in_guild,
+}
+
+/// The description and usage of a single command translated into the locale
+/// of an invocation, via the `commands.<name>.description` and
+/// `commands.<name>.usage` keys.
+#[cfg(all(feature = "cache", feature = "http"))]
+#[derive(Default)]
+struct TranslatedCommand {
+    description: Option<String>,
+    usage: Option<String>,
+}
+
+#[cfg(all(feature = "cache", feature = "http"))]
+impl TranslatedCommand {
+    async fn new(translator: &Translator<'_>, formatted_help: &CustomisedHelpData<'_>) -> Self {
+        let command = match formatted_help {
+            CustomisedHelpData::SingleCommand { command } if translator.is_translating() => command,
+            _ => return TranslatedCommand::default(),
+        };
+
+        let description = match command.description {
+            Some(description) => {
+                let key = format!("commands.{}.description", command.name);
+
+                Some(translator.text(&key, description).await)
+            },
+            None => None,
+        };
+
+        let usage = match command.usage {
+            Some(usage) => {
+                let key = format!("commands.{}.usage", command.name);
+
+                Some(translator.text(&key, usage).await)
+            },
+            None => None,
+        };
+
+        TranslatedCommand { description, usage }
+    }
+
+    /// Replaces the description and usage of the command with their
+    /// translations.
+    fn apply<'a>(&'a self, mut formatted_help: CustomisedHelpData<'a>) -> CustomisedHelpData<'a> {
+        if let CustomisedHelpData::SingleCommand { command } = &mut formatted_help {
+            if let Some(description) = &self.description {
+                command.description = Some(description);
+            }
+
+            if let Some(usage) = &self.usage {
+                command.usage = Some(usage);
+            }
+        }
+
+        formatted_help
+    }
+}
+
 /// A single group containing its name and all related commands that are eligible
 /// in relation of help-settings measured to the user.
 #[derive(Clone, Debug, Default)]
@@ -130,16 +297,31 @@ pub struct Command<'a> {
     pub name: &'static str,
     pub group_name: &'static str,
     pub group_prefixes: &'a [&'static str],
-    pub sub_commands: Vec<String>,
+    pub sub_commands: Vec<SubCommand<'a>>,
     pub aliases: Vec<&'static str>,
     pub availability: &'a str,
-    pub description: Option<&'static str>,
-    pub usage: Option<&'static str>,
+    pub description: Option<&'a str>,
+    pub usage: Option<&'a str>,
     pub usage_sample: Vec<&'static str>,
     pub checks: Vec<String>,
     pub(crate) _nonexhaustive: (),
 }
 
+/// A sub command of a command, along with its own sub commands.
+#[derive(Clone, Debug)]
+pub struct SubCommand<'a> {
+    pub name: &'static str,
+    pub availability: &'a str,
+    pub checks: Vec<String>,
+    /// Whether the sub command is run if none of the others is given.
+    pub is_default: bool,
+    /// Whether the sub command is struck through, as the user lacks the
+    /// conditions to run it.
+    pub is_struck: bool,
+    pub sub_commands: Vec<SubCommand<'a>>,
+    pub(crate) _nonexhaustive: (),
+}
+
 /// Contains possible suggestions in case a command could not be found
 /// but are similar enough.
 #[derive(Clone, Debug, Default)]
@@ -406,7 +588,7 @@ async fn check_command_behaviour(
 
                 let mut args = Args::new("", &[]);
 
-                if let CheckResult::Failure(_) = (check.function)(ctx, msg, &mut args, options).await {
+                if let CheckResult::Failure(_) = check.function.call(ctx, msg, &mut args, options).await {
                     return help_options.lacking_conditions;
                 }
             }
@@ -416,6 +598,89 @@ async fn check_command_behaviour(
     b
 }
 
+/// The text stating where a command can be used.
+#[cfg(all(feature = "cache", feature = "http"))]
+fn availability_text<'a>(options: &CommandOptions, texts: HelpTexts<'a>) -> &'a str {
+    if options.only_in == OnlyIn::Dm {
+        texts.dm_only_text
+    } else if options.only_in == OnlyIn::Guild {
+        texts.guild_only_text
+    } else {
+        texts.dm_and_guild_text
+    }
+}
+
+/// The names of the checks that are displayed in the help.
+#[cfg(all(feature = "cache", feature = "http"))]
+fn check_names<'a>(checks: impl Iterator<Item = &'a &'static Check>) -> Vec<String> {
+    checks
+        .filter(|check| check.display_in_help)
+        .map(|check| check.name.to_string())
+        .collect()
+}
+
+/// Builds the tree of the sub commands of a command available in the help,
+/// including its default sub command.
+///
+/// Like the commands of a group, each sub command is hidden or struck through
+/// if the user lacks the conditions to run it, and so are its own sub
+/// commands.
+#[cfg(all(feature = "cache", feature = "http"))]
+#[allow(clippy::too_many_arguments)]
+fn sub_command_tree<'rec, 'a: 'rec>(
+    ctx: &'rec Context,
+    msg: &'rec Message,
+    options: &'rec CommandOptions,
+    group_checks: &'rec [&'static Check],
+    owners: &'rec HashSet<UserId>,
+    help_options: &'rec HelpOptions,
+    texts: HelpTexts<'a>,
+    parent_behaviour: HelpBehaviour,
+) -> BoxFuture<'rec, Vec<SubCommand<'a>>> {
+    async move {
+        let is_default = |command: &InternalCommand| {
+            options.default_sub_command.map_or(false, |default| std::ptr::eq(default, command))
+        };
+        let unlisted_default = options
+            .default_sub_command
+            .filter(|default| !options.sub_commands.iter().any(|command| std::ptr::eq(*command, *default)));
+
+        let mut tree = Vec::new();
+
+        for command in options.sub_commands.iter().copied().chain(unlisted_default) {
+            let behaviour = std::cmp::max(
+                parent_behaviour,
+                check_command_behaviour(ctx, msg, command.options, group_checks, owners, help_options).await,
+            );
+
+            if behaviour == HelpBehaviour::Hide {
+                continue;
+            }
+
+            tree.push(SubCommand {
+                name: command.options.names[0],
+                availability: availability_text(command.options, texts),
+                checks: check_names(command.options.checks.iter()),
+                is_default: is_default(command),
+                is_struck: behaviour == HelpBehaviour::Strike,
+                sub_commands: sub_command_tree(
+                    ctx,
+                    msg,
+                    command.options,
+                    group_checks,
+                    owners,
+                    help_options,
+                    texts,
+                    behaviour,
+                ).await,
+                _nonexhaustive: (),
+            });
+        }
+
+        tree
+    }.boxed()
+}
+
 #[cfg(all(feature = "cache", feature = "http"))]
 #[allow(clippy::too_many_arguments)]
 async fn _nested_group_command_search<'rec, 'a: 'rec>(
@@ -424,6 +689,7 @@ async fn _nested_group_command_search<'rec, 'a: 'rec>(
     groups: &'rec [&'static CommandGroup],
     name: &'rec mut String,
     help_options: &'a HelpOptions,
+    texts: HelpTexts<'a>,
     similar_commands: &'rec mut Vec<SuggestedCommandName>,
     owners: &'rec HashSet<UserId>,
 ) -> Result<CustomisedHelpData<'a>, ()> {
@@ -516,45 +782,18 @@ async fn _nested_group_command_search<'rec, 'a: 'rec>(
 
             if !options.help_available {
                 return Ok(CustomisedHelpData::NoCommandFound {
-                    help_error_message: &help_options.no_help_available_text,
+                    help_error_message: texts.no_help_available_text,
                 });
             }
 
-            let available_text = if options.only_in == OnlyIn::Dm {
-                &help_options.dm_only_text
-            } else if options.only_in == OnlyIn::Guild {
-                &help_options.guild_only_text
-            } else {
-                &help_options.dm_and_guild_text
-            };
-
             similar_commands
                 .sort_unstable_by(|a, b| a.levenshtein_distance.cmp(&b.levenshtein_distance));
 
-            let check_names: Vec<String> = command
+            let checks = command
                 .options
                 .checks
                 .iter()
-                .chain(group.options.checks.iter())
-                .filter_map(|check| {
-                    if check.display_in_help {
-                        Some(check.name.to_string())
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            let sub_command_names: Vec<String> = options
-                .sub_commands
-                .iter()
-                .filter_map(|cmd| {
-                    if (*cmd).options.help_available {
-                        Some((*cmd).options.names[0].to_string())
-                    } else {
-                        None
-                    }
-                }).collect();
+                .chain(group.options.checks.iter());
 
             return Ok(CustomisedHelpData::SingleCommand {
                 command: Command {
@@ -562,12 +801,21 @@ async fn _nested_group_command_search<'rec, 'a: 'rec>(
                     description: options.desc,
                     group_name: group.name,
                     group_prefixes: &group.options.prefixes,
-                    checks: check_names,
+                    checks: check_names(checks),
                     aliases: options.names[1..].to_vec(),
-                    availability: available_text,
+                    availability: availability_text(options, texts),
                     usage: options.usage,
                     usage_sample: options.examples.to_vec(),
-                    sub_commands: sub_command_names,
+                    sub_commands: sub_command_tree(
+                        ctx,
+                        msg,
+                        options,
+                        group.options.checks,
+                        owners,
+                        help_options,
+                        texts,
+                        HelpBehaviour::Nothing,
+                    ).await,
                     _nonexhaustive: (),
                 },
             });
@@ -579,6 +827,7 @@ async fn _nested_group_command_search<'rec, 'a: 'rec>(
             &group.options.sub_groups,
             name,
             help_options,
+            texts,
             similar_commands,
             owners,
         ).await {
@@ -592,12 +841,14 @@ async fn _nested_group_command_search<'rec, 'a: 'rec>(
 }
 
 #[cfg(all(feature = "cache", feature = "http"))]
+#[allow(clippy::too_many_arguments)]
 fn nested_group_command_search<'rec, 'a: 'rec>(
     ctx: &'rec Context,
     msg: &'rec Message,
     groups: &'rec [&'static CommandGroup],
     name: &'rec mut String,
     help_options: &'a HelpOptions,
+    texts: HelpTexts<'a>,
     similar_commands: &'rec mut Vec<SuggestedCommandName>,
     owners: &'rec HashSet<UserId>,
 ) -> BoxFuture<'rec, Result<CustomisedHelpData<'a>, ()>> {
@@ -607,6 +858,7 @@ fn nested_group_command_search<'rec, 'a: 'rec>(
         groups,
         name,
         help_options,
+        texts,
         similar_commands,
         owners
     ).boxed()
@@ -621,6 +873,7 @@ async fn fetch_single_command<'a>(
     groups: &[&'static CommandGroup],
     name: &'a str,
     help_options: &'a HelpOptions,
+    texts: HelpTexts<'a>,
     owners: &HashSet<UserId>,
 ) -> Result<CustomisedHelpData<'a>, Vec<SuggestedCommandName>> {
     let mut similar_commands: Vec<SuggestedCommandName> = Vec::new();
@@ -632,6 +885,7 @@ async fn fetch_single_command<'a>(
         &groups,
         &mut name,
         &help_options,
+        texts,
         &mut similar_commands,
         &owners,
     ).await {
@@ -900,11 +1154,33 @@ pub async fn create_customised_help_data<'a>(
     groups: &[&'static CommandGroup],
     owners: &HashSet<UserId>,
     help_options: &'a HelpOptions,
+) -> CustomisedHelpData<'a> {
+    let texts = HelpTexts::new(help_options);
+
+    create_help_data_with_texts(ctx, msg, args, groups, owners, help_options, texts, &Translator::untranslated(ctx)).await
+}
+
+/// Creates the `CustomisedHelpData` like [`create_customised_help_data`],
+/// using the given `texts` and translating the description of a group
+/// searched by name.
+///
+/// [`create_customised_help_data`]: fn.create_customised_help_data.html
+#[cfg(feature = "cache")]
+#[allow(clippy::too_many_arguments)]
+async fn create_help_data_with_texts<'a>(
+    ctx: &Context,
+    msg: &Message,
+    args: &'a Args,
+    groups: &[&'static CommandGroup],
+    owners: &HashSet<UserId>,
+    help_options: &'a HelpOptions,
+    texts: HelpTexts<'a>,
+    translator: &Translator<'_>,
 ) -> CustomisedHelpData<'a> {
     if !args.is_empty() {
         let name = args.message();
 
-        return match fetch_single_command(ctx, msg, &groups, &name, &help_options, owners).await {
+        return match fetch_single_command(ctx, msg, &groups, &name, &help_options, texts, owners).await {
             Ok(single_command) => single_command,
             Err(suggestions) => {
                 let mut searched_named_lowercase = name.to_lowercase();
@@ -918,17 +1194,17 @@ pub async fn create_customised_help_data<'a>(
                         help_options,
                         &mut searched_named_lowercase,
                     ).await {
-                        return found_command;
+                        return translate_group_description(translator, found_command).await;
                     }
                 }
 
                 if suggestions.is_empty() {
                     CustomisedHelpData::NoCommandFound {
-                        help_error_message: &help_options.no_help_available_text,
+                        help_error_message: texts.no_help_available_text,
                     }
                 } else {
                     CustomisedHelpData::SuggestedCommands {
-                        help_description: help_options.suggestion_text.to_string(),
+                        help_description: texts.suggestion_text.to_string(),
                         suggestions: Suggestions(suggestions),
                     }
                 }
@@ -937,18 +1213,18 @@ pub async fn create_customised_help_data<'a>(
     }
 
     let strikethrough_command_tip = if msg.is_private() {
-        &help_options.strikethrough_commands_tip_in_dm
+        texts.strikethrough_commands_tip_in_dm
     } else {
-        &help_options.strikethrough_commands_tip_in_guild
+        texts.strikethrough_commands_tip_in_guild
     };
 
-    let description = if let Some(ref strikethrough_command_text) = strikethrough_command_tip {
+    let description = if let Some(strikethrough_command_text) = strikethrough_command_tip {
         format!(
             "{}\n{}",
-            &help_options.individual_command_tip, &strikethrough_command_text
+            texts.individual_command_tip, strikethrough_command_text
         )
     } else {
-        help_options.individual_command_tip.to_string()
+        texts.individual_command_tip.to_string()
     };
 
     let listed_groups = create_command_group_commands_pair_from_groups(
@@ -961,7 +1237,7 @@ pub async fn create_customised_help_data<'a>(
 
     if listed_groups.is_empty() {
         CustomisedHelpData::NoCommandFound {
-            help_error_message: &help_options.no_help_available_text,
+            help_error_message: texts.no_help_available_text,
         }
     } else {
         CustomisedHelpData::GroupedCommands {
@@ -971,6 +1247,26 @@ pub async fn create_customised_help_data<'a>(
     }
 }
 
+/// Translates the description of a group searched by name, shown as the
+/// description of its commands, via the `groups.<name>.description` key.
+#[cfg(feature = "cache")]
+async fn translate_group_description<'a>(
+    translator: &Translator<'_>,
+    mut help_data: CustomisedHelpData<'a>,
+) -> CustomisedHelpData<'a> {
+    if let CustomisedHelpData::GroupedCommands { help_description, groups } = &mut help_data {
+        if let [group] = &groups[..] {
+            if translator.is_translating() && !help_description.is_empty() {
+                let key = format!("groups.{}.description", group.name);
+
+                *help_description = translator.text(&key, help_description).await;
+            }
+        }
+    }
+
+    help_data
+}
+
 /// Flattens a group with all its nested sub-groups into the passed `group_text`
 /// buffer.
 /// If `nest_level` is `0`, this function will skip the group's name.
@@ -980,6 +1276,7 @@ fn flatten_group_to_string(
     group: &GroupCommandsPair,
     nest_level: usize,
     help_options: &HelpOptions,
+    texts: &HelpTexts<'_>,
 ) {
     let repeated_indent_str = help_options.indention_prefix.repeat(nest_level);
 
@@ -995,7 +1292,7 @@ fn flatten_group_to_string(
         let _ = writeln!(group_text,
             "{}{}: `{}`",
             &repeated_indent_str,
-            help_options.group_prefix,
+            texts.group_prefix,
             group.prefixes.join("`, `"),
         );
     };
@@ -1021,6 +1318,7 @@ fn flatten_group_to_string(
                 &sub_group,
                 nest_level + 1,
                 &help_options,
+                texts,
             );
 
             let _ = write!(group_text, "{}", sub_group_text);
@@ -1037,6 +1335,7 @@ fn flatten_group_to_plain_string(
     group: &GroupCommandsPair,
     nest_level: usize,
     help_options: &HelpOptions,
+    texts: &HelpTexts<'_>,
 ) {
     let repeated_indent_str = help_options.indention_prefix.repeat(nest_level);
 
@@ -1053,7 +1352,7 @@ fn flatten_group_to_plain_string(
     } else {
         let _ = write!(group_text,
             " ({}: `{}`): ",
-            help_options.group_prefix,
+            texts.group_prefix,
             group.prefixes.join("`, `"),
         );
     }
@@ -1070,6 +1369,7 @@ fn flatten_group_to_plain_string(
             &sub_group,
             nest_level + 1,
             &help_options,
+            texts,
         );
 
         let _ = write!(group_text, "{}", sub_group_text);
@@ -1077,11 +1377,58 @@ fn flatten_group_to_plain_string(
 }
 
 
+/// Writes the tree of sub commands into `text`, a line per sub command
+/// indented by its nesting.
+#[cfg(all(feature = "cache", feature = "http"))]
+fn sub_command_tree_to_string(
+    text: &mut String,
+    sub_commands: &[SubCommand<'_>],
+    nest_level: usize,
+    indention_prefix: &str,
+    texts: &HelpTexts<'_>,
+) {
+    let repeated_indent_str = indention_prefix.repeat(nest_level);
+
+    for sub_command in sub_commands {
+        if sub_command.is_struck {
+            let _ = write!(text, "{}~~`{}`~~", repeated_indent_str, sub_command.name);
+        } else {
+            let _ = write!(text, "{}`{}`", repeated_indent_str, sub_command.name);
+        }
+
+        if sub_command.is_default {
+            let _ = write!(text, " *({})*", texts.default_sub_command_label);
+        }
+
+        let _ = write!(text, ": {}", sub_command.availability);
+
+        if !sub_command.checks.is_empty() {
+            let _ = write!(
+                text,
+                "; {}: `{}`",
+                texts.checks_label,
+                sub_command.checks.join("`, `"),
+            );
+        }
+
+        let _ = writeln!(text);
+
+        sub_command_tree_to_string(
+            text,
+            &sub_command.sub_commands,
+            nest_level + 1,
+            indention_prefix,
+            texts,
+        );
+    }
+}
+
 /// Sends an embed listing all groups with their commands.
 #[cfg(all(feature = "cache", feature = "http"))]
 async fn send_grouped_commands_embed(
     http: impl AsRef<Http>,
     help_options: &HelpOptions,
+    texts: &HelpTexts<'_>,
     channel_id: ChannelId,
     help_description: &str,
     groups: &[GroupCommandsPair],
@@ -1100,6 +1447,7 @@ async fn send_grouped_commands_embed(
                     &group,
                     0,
                     &help_options,
+                    texts,
                 );
 
                 embed.field(group.name, &embed_text, true);
@@ -1116,6 +1464,7 @@ async fn send_grouped_commands_embed(
 async fn send_single_command_embed(
     http: impl AsRef<Http>,
     help_options: &HelpOptions,
+    texts: &HelpTexts<'_>,
     channel_id: ChannelId,
     command: &Command<'_>,
     colour: Colour,
@@ -1136,7 +1485,7 @@ async fn send_single_command_embed(
                     format!("`{} {}`", command.name, usage)
                 };
 
-                embed.field(&help_options.usage_label, full_usage_text, true);
+                embed.field(texts.usage_label, full_usage_text, true);
             }
 
             if !command.usage_sample.is_empty() {
@@ -1158,35 +1507,41 @@ async fn send_single_command_embed(
                            .map(format_example)
                            .collect::<String>()
                     };
-                embed.field(&help_options.usage_sample_label, full_example_text, true);
+                embed.field(texts.usage_sample_label, full_example_text, true);
             }
 
-            embed.field(&help_options.grouped_label, command.group_name, true);
+            embed.field(texts.grouped_label, command.group_name, true);
 
             if !command.aliases.is_empty() {
                 embed.field(
-                    &help_options.aliases_label,
+                    texts.aliases_label,
                     format!("`{}`", command.aliases.join("`, `")),
                     true,
                 );
             }
 
-            embed.field(&help_options.available_text, &command.availability, true);
+            embed.field(texts.available_text, &command.availability, true);
 
             if !command.checks.is_empty() {
                 embed.field(
-                    &help_options.checks_label,
+                    texts.checks_label,
                     format!("`{}`", command.checks.join("`, `")),
                     true,
                 );
             }
 
             if !command.sub_commands.is_empty() {
-                embed.field(
-                    &help_options.sub_commands_label,
-                    format!("`{}`", command.sub_commands.join("`, `")),
-                    true,
+                let mut sub_commands_text = String::default();
+
+                sub_command_tree_to_string(
+                    &mut sub_commands_text,
+                    &command.sub_commands,
+                    0,
+                    help_options.indention_prefix,
+                    texts,
                 );
+
+                embed.field(texts.sub_commands_label, sub_commands_text, false);
             }
 
             embed
@@ -1238,6 +1593,9 @@ async fn send_error_embed(
 
 /// Posts an embed showing each individual command group and its commands.
 ///
+/// Its texts are translated via the localization set with
+/// [`StandardFramework::localization`], if any.
+///
 /// # Examples
 ///
 /// Use the command with `exec_help`:
@@ -1266,6 +1624,8 @@ async fn send_error_embed(
 /// let framwork = StandardFramework::new()
 ///     .help(&MY_HELP);
 /// ```
+///
+/// [`StandardFramework::localization`]: ../struct.StandardFramework.html#method.localization
 #[cfg(all(feature = "cache", feature = "http"))]
 #[allow(clippy::implicit_hasher)]
 pub async fn with_embeds(
@@ -1276,10 +1636,53 @@ pub async fn with_embeds(
     groups: &[&'static CommandGroup],
     owners: HashSet<UserId>,
 ) -> Option<Message> {
+    with_embeds_localized(ctx, msg, args, help_options, groups, owners, &Translator::new(ctx, msg).await).await
+}
+
+/// Posts an embed like [`with_embeds`], with its texts translated by the
+/// `translator`.
+///
+/// Refer to [`Localization`] for the keys of the texts.
+///
+/// [`with_embeds`]: fn.with_embeds.html
+/// [`Localization`]: ../struct.Localization.html
+#[cfg(all(feature = "cache", feature = "http"))]
+#[allow(clippy::implicit_hasher)]
+pub async fn with_embeds_localized(
+    ctx: &Context,
+    msg: &Message,
+    args: Args,
+    help_options: &HelpOptions,
+    groups: &[&'static CommandGroup],
+    owners: HashSet<UserId>,
+    translator: &Translator<'_>,
+) -> Option<Message> {
+    let translated_texts;
+    let texts = if translator.is_translating() {
+        translated_texts = TranslatedHelpTexts::new(translator, help_options).await;
+        translated_texts.texts()
+    } else {
+        HelpTexts::new(help_options)
+    };
+
     let formatted_help =
-        create_customised_help_data(ctx, msg, &args, &groups, &owners, help_options).await;
+        create_help_data_with_texts(ctx, msg, &args, &groups, &owners, help_options, texts, translator).await;
+    let translated_command = TranslatedCommand::new(translator, &formatted_help).await;
+    let formatted_help = translated_command.apply(formatted_help);
 
-    let response_result = match formatted_help {
+    send_customised_help_embed(ctx, msg, help_options, &texts, &formatted_help).await
+}
+
+/// Sends the embed of the customised help data.
+#[cfg(all(feature = "cache", feature = "http"))]
+async fn send_customised_help_embed(
+    ctx: &Context,
+    msg: &Message,
+    help_options: &HelpOptions,
+    texts: &HelpTexts<'_>,
+    formatted_help: &CustomisedHelpData<'_>,
+) -> Option<Message> {
+    let response_result = match *formatted_help {
         CustomisedHelpData::SuggestedCommands {
             ref help_description,
             ref suggestions,
@@ -1304,6 +1707,7 @@ pub async fn with_embeds(
         } => send_grouped_commands_embed(
             &ctx.http,
             &help_options,
+            texts,
             msg.channel_id,
             &help_description,
             &groups,
@@ -1312,6 +1716,7 @@ pub async fn with_embeds(
         CustomisedHelpData::SingleCommand { ref command } => send_single_command_embed(
             &ctx.http,
             &help_options,
+            texts,
             msg.channel_id,
             &command,
             help_options.embed_success_colour,
@@ -1322,16 +1727,261 @@ pub async fn with_embeds(
     match response_result {
         Ok(response) => Some(response),
         Err(why) => {
-            warn_about_failed_send!(&formatted_help, why);
+            warn_about_failed_send!(formatted_help, why);
             None
         },
     }
 }
 
+/// Splits the texts of groups into embed fields, grouped into pages that
+/// each fit into an embed along with the `help_description`.
+///
+/// The text of a group is split across several fields by line if it exceeds
+/// the maximum length of a field, and so are lines exceeding it themselves.
+#[cfg(all(feature = "cache", feature = "collector", feature = "http"))]
+fn paginate_group_texts(
+    help_description: &str,
+    group_texts: Vec<(&'static str, String)>,
+) -> Vec<Vec<(&'static str, String)>> {
+    // Leaves room for the page number in the footer.
+    let max_page_length = (EMBED_MAX_LENGTH as usize).saturating_sub(help_description.chars().count() + 16);
+
+    let mut fields = Vec::new();
+
+    for (name, group_text) in group_texts {
+        let mut field_text = String::default();
+
+        for line in group_text.lines().flat_map(|line| split_line(line, EMBED_FIELD_VALUE_MAX_LENGTH - 1)) {
+            if !field_text.is_empty()
+                && field_text.chars().count() + line.chars().count() >= EMBED_FIELD_VALUE_MAX_LENGTH
+            {
+                fields.push((name, field_text));
+                field_text = String::default();
+            }
+
+            let _ = writeln!(field_text, "{}", line);
+        }
+
+        fields.push((name, field_text));
+    }
+
+    let mut pages = vec![Vec::new()];
+    let mut page_length = 0;
+
+    for (name, text) in fields {
+        let length = name.chars().count() + text.chars().count();
+        let page = pages.last_mut().unwrap();
+
+        if !page.is_empty() && (page_length + length > max_page_length || page.len() == EMBED_MAX_FIELDS) {
+            pages.push(vec![(name, text)]);
+            page_length = length;
+        } else {
+            page.push((name, text));
+            page_length += length;
+        }
+    }
+
+    pages
+}
+
+/// Splits `line` into parts of at most `max_length` characters.
+#[cfg(all(feature = "cache", feature = "collector", feature = "http"))]
+fn split_line(mut line: &str, max_length: usize) -> Vec<&str> {
+    let mut parts = Vec::new();
+
+    while let Some((index, _)) = line.char_indices().nth(max_length) {
+        parts.push(&line[..index]);
+        line = &line[index..];
+    }
+
+    parts.push(line);
+
+    parts
+}
+
+/// Builds the embed of a page of the paginated help.
+#[cfg(all(feature = "cache", feature = "collector", feature = "http"))]
+fn create_page_embed<'a>(
+    embed: &'a mut CreateEmbed,
+    help_options: &HelpOptions,
+    help_description: &str,
+    pages: &[Vec<(&'static str, String)>],
+    page: usize,
+) -> &'a mut CreateEmbed {
+    embed.colour(help_options.embed_success_colour);
+    embed.description(help_description);
+
+    for (name, text) in &pages[page] {
+        embed.field(name, text, true);
+    }
+
+    embed.footer(|f| f.text(format!("{}/{}", page + 1, pages.len())))
+}
+
+/// Posts an embed showing the command groups and their commands like
+/// [`with_embeds`], but split across pages if they do not fit into one embed.
+///
+/// The pages are turned by reacting with ◀️ or ▶️, until no reaction has been
+/// added or removed for [`HelpOptions::page_timeout`] seconds.
+///
+/// Its texts are translated via the localization set with
+/// [`StandardFramework::localization`], if any.
+///
+/// # Examples
+///
+/// Use the command with `exec_help`:
+///
+/// ```rust,no_run
+/// # use serenity::prelude::*;
+/// use std::{collections::HashSet, hash::BuildHasher};
+/// use serenity::{framework::standard::{Args, CommandGroup, CommandResult,
+///     StandardFramework, macros::help, HelpOptions,
+///     help_commands::*}, model::prelude::*,
+/// };
+///
+/// #[help]
+/// #[page_timeout(60)]
+/// async fn my_help(
+///     context: &Context,
+///     msg: &Message,
+///     args: Args,
+///     help_options: &'static HelpOptions,
+///     groups: &[&'static CommandGroup],
+///     owners: HashSet<UserId>
+/// ) -> CommandResult {
+///     let _ = with_embeds_paginated(context, msg, args, &help_options, groups, owners).await;
+///     Ok(())
+/// }
+///
+/// let framwork = StandardFramework::new()
+///     .help(&MY_HELP);
+/// ```
+///
+/// [`with_embeds`]: fn.with_embeds.html
+/// [`HelpOptions::page_timeout`]: ../struct.HelpOptions.html#structfield.page_timeout
+/// [`StandardFramework::localization`]: ../struct.StandardFramework.html#method.localization
+#[cfg(all(feature = "cache", feature = "collector", feature = "http"))]
+#[allow(clippy::implicit_hasher)]
+pub async fn with_embeds_paginated(
+    ctx: &Context,
+    msg: &Message,
+    args: Args,
+    help_options: &HelpOptions,
+    groups: &[&'static CommandGroup],
+    owners: HashSet<UserId>,
+) -> Option<Message> {
+    with_embeds_paginated_localized(ctx, msg, args, help_options, groups, owners, &Translator::new(ctx, msg).await).await
+}
+
+/// Posts a paginated embed like [`with_embeds_paginated`], with its texts
+/// translated by the `translator`.
+///
+/// Refer to [`Localization`] for the keys of the texts.
+///
+/// [`with_embeds_paginated`]: fn.with_embeds_paginated.html
+/// [`Localization`]: ../struct.Localization.html
+#[cfg(all(feature = "cache", feature = "collector", feature = "http"))]
+#[allow(clippy::implicit_hasher)]
+pub async fn with_embeds_paginated_localized(
+    ctx: &Context,
+    msg: &Message,
+    args: Args,
+    help_options: &HelpOptions,
+    groups: &[&'static CommandGroup],
+    owners: HashSet<UserId>,
+    translator: &Translator<'_>,
+) -> Option<Message> {
+    let translated_texts;
+    let texts = if translator.is_translating() {
+        translated_texts = TranslatedHelpTexts::new(translator, help_options).await;
+        translated_texts.texts()
+    } else {
+        HelpTexts::new(help_options)
+    };
+
+    let formatted_help =
+        create_help_data_with_texts(ctx, msg, &args, groups, &owners, help_options, texts, translator).await;
+    let translated_command = TranslatedCommand::new(translator, &formatted_help).await;
+    let formatted_help = translated_command.apply(formatted_help);
+
+    let (help_description, pages) = match &formatted_help {
+        CustomisedHelpData::GroupedCommands {
+            help_description,
+            groups,
+        } => {
+            let group_texts = groups.iter().map(|group| {
+                let mut group_text = String::default();
+
+                flatten_group_to_string(&mut group_text, group, 0, help_options, &texts);
+
+                (group.name, group_text)
+            }).collect();
+
+            (help_description, paginate_group_texts(help_description, group_texts))
+        },
+        _ => return send_customised_help_embed(ctx, msg, help_options, &texts, &formatted_help).await,
+    };
+
+    if pages.len() < 2 {
+        return send_customised_help_embed(ctx, msg, help_options, &texts, &formatted_help).await;
+    }
+
+    let mut response = match msg.channel_id.send_message(&ctx.http, |m| {
+        m.embed(|e| create_page_embed(e, help_options, help_description, &pages, 0))
+    }).await {
+        Ok(response) => response,
+        Err(why) => {
+            warn_about_failed_send!(&formatted_help, why);
+            return None;
+        },
+    };
+
+    for emoji in &[PREVIOUS_PAGE_EMOJI, NEXT_PAGE_EMOJI] {
+        if let Err(why) = response.react(ctx, ReactionType::Unicode(emoji.to_string())).await {
+            warn!("Failed to add the reactions for turning the help's pages: {:?}", why);
+
+            return Some(response);
+        }
+    }
+
+    // Removing a reaction turns the page as well, as the bot may lack the
+    // permission to remove the reactions of users.
+    let mut collector = response
+        .await_reactions(ctx)
+        .author_id(msg.author.id)
+        .added(true)
+        .removed(true)
+        .await;
+
+    let page_timeout = Duration::from_secs(help_options.page_timeout);
+    let mut page = 0;
+
+    // The collector's timeout is a deadline, so the time since the last
+    // reaction is measured here instead.
+    while let Ok(Some(action)) = tokio::time::timeout(page_timeout, collector.next()).await {
+        page = match &action.as_inner_ref().emoji {
+            ReactionType::Unicode(name) if name == PREVIOUS_PAGE_EMOJI => (page + pages.len() - 1) % pages.len(),
+            ReactionType::Unicode(name) if name == NEXT_PAGE_EMOJI => (page + 1) % pages.len(),
+            _ => continue,
+        };
+
+        if let Err(why) = response.edit(ctx, |m| {
+            m.embed(|e| create_page_embed(e, help_options, help_description, &pages, page))
+        }).await {
+            warn!("Failed to turn the help's page because: {:?}", why);
+        }
+    }
+
+    let _ = response.delete_reactions(ctx).await;
+
+    Some(response)
+}
+
 /// Turns grouped commands into a `String` taking plain help format into account.
 #[cfg(all(feature = "cache", feature = "http"))]
 fn grouped_commands_to_plain_string(
     help_options: &HelpOptions,
+    texts: &HelpTexts<'_>,
     help_description: &str,
     groups: &[GroupCommandsPair],
 ) -> String {
@@ -1346,6 +1996,7 @@ fn grouped_commands_to_plain_string(
             &group,
             0,
             &help_options,
+            texts,
         );
     }
 
@@ -1354,7 +2005,11 @@ fn grouped_commands_to_plain_string(
 
 /// Turns a single command into a `String` taking plain help format into account.
 #[cfg(all(feature = "cache", feature = "http"))]
-fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<'_>) -> String {
+fn single_command_to_plain_string(
+    help_options: &HelpOptions,
+    texts: &HelpTexts<'_>,
+    command: &Command<'_>,
+) -> String {
     let mut result = String::default();
     let _ = writeln!(result, "__**{}**__", command.name);
 
@@ -1362,7 +2017,7 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
         let _ = writeln!(
             result,
             "**{}**: `{}`",
-            help_options.aliases_label,
+            texts.aliases_label,
             command.aliases.join("`, `")
         );
     }
@@ -1371,7 +2026,7 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
         let _ = writeln!(
             result,
             "**{}**: {}",
-            help_options.description_label, description
+            texts.description_label, description
         );
     };
 
@@ -1380,13 +2035,13 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
             let _ = writeln!(
                 result,
                 "**{}**: `{} {} {}`",
-                help_options.usage_label, first_prefix, command.name, usage
+                texts.usage_label, first_prefix, command.name, usage
             );
         } else {
             let _ = writeln!(
                 result,
                 "**{}**: `{} {}`",
-                help_options.usage_label, command.name, usage
+                texts.usage_label, command.name, usage
             );
         }
     }
@@ -1397,7 +2052,7 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
                 let _ = writeln!(
                     result,
                     "**{}**: `{} {} {}`",
-                    help_options.usage_sample_label, first_prefix, command.name, example
+                    texts.usage_sample_label, first_prefix, command.name, example
                 );
             };
             command
@@ -1409,7 +2064,7 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
                 let _ = writeln!(
                     result,
                     "**{}**: `{} {}`",
-                    help_options.usage_sample_label, command.name, example
+                    texts.usage_sample_label, command.name, example
                 );
             };
             command
@@ -1422,19 +2077,43 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
     let _ = writeln!(
         result,
         "**{}**: {}",
-        help_options.grouped_label, command.group_name
+        texts.grouped_label, command.group_name
     );
     let _ = writeln!(
         result,
         "**{}**: {}",
-        help_options.available_text, command.availability
+        texts.available_text, command.availability
     );
 
+    if !command.checks.is_empty() {
+        let _ = writeln!(
+            result,
+            "**{}**: `{}`",
+            texts.checks_label,
+            command.checks.join("`, `")
+        );
+    }
+
+    if !command.sub_commands.is_empty() {
+        let _ = writeln!(result, "**{}**:", texts.sub_commands_label);
+
+        sub_command_tree_to_string(
+            &mut result,
+            &command.sub_commands,
+            0,
+            help_options.indention_prefix,
+            texts,
+        );
+    }
+
     result
 }
 
 /// Posts formatted text displaying each individual command group and its commands.
 ///
+/// Its texts are translated via the localization set with
+/// [`StandardFramework::localization`], if any.
+///
 /// # Examples
 ///
 /// Use the command with `exec_help`:
@@ -1463,6 +2142,8 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
 /// let framework = StandardFramework::new()
 ///     .help(&MY_HELP);
 /// ```
+///
+/// [`StandardFramework::localization`]: ../struct.StandardFramework.html#method.localization
 #[cfg(all(feature = "cache", feature = "http"))]
 #[allow(clippy::implicit_hasher)]
 pub async fn plain(
@@ -1473,8 +2154,39 @@ pub async fn plain(
     groups: &[&'static CommandGroup],
     owners: HashSet<UserId>,
 ) -> Option<Message> {
+    plain_localized(ctx, msg, args, help_options, groups, owners, &Translator::new(ctx, msg).await).await
+}
+
+/// Posts formatted text like [`plain`], with its texts translated by the
+/// `translator`.
+///
+/// Refer to [`Localization`] for the keys of the texts.
+///
+/// [`plain`]: fn.plain.html
+/// [`Localization`]: ../struct.Localization.html
+#[cfg(all(feature = "cache", feature = "http"))]
+#[allow(clippy::implicit_hasher)]
+pub async fn plain_localized(
+    ctx: &Context,
+    msg: &Message,
+    args: Args,
+    help_options: &HelpOptions,
+    groups: &[&'static CommandGroup],
+    owners: HashSet<UserId>,
+    translator: &Translator<'_>,
+) -> Option<Message> {
+    let translated_texts;
+    let texts = if translator.is_translating() {
+        translated_texts = TranslatedHelpTexts::new(translator, help_options).await;
+        translated_texts.texts()
+    } else {
+        HelpTexts::new(help_options)
+    };
+
     let formatted_help =
-        create_customised_help_data(ctx, msg, &args, &groups, &owners, help_options).await;
+        create_help_data_with_texts(ctx, msg, &args, &groups, &owners, help_options, texts, translator).await;
+    let translated_command = TranslatedCommand::new(translator, &formatted_help).await;
+    let formatted_help = translated_command.apply(formatted_help);
 
     let result = match formatted_help {
         CustomisedHelpData::SuggestedCommands {
@@ -1487,9 +2199,9 @@ pub async fn plain(
         CustomisedHelpData::GroupedCommands {
             ref help_description,
             ref groups,
-        } => grouped_commands_to_plain_string(&help_options, &help_description, &groups),
+        } => grouped_commands_to_plain_string(&help_options, &texts, &help_description, &groups),
         CustomisedHelpData::SingleCommand { ref command } => {
-            single_command_to_plain_string(&help_options, &command)
+            single_command_to_plain_string(&help_options, &texts, &command)
         },
         CustomisedHelpData::__Nonexhaustive => unreachable!(),
     };
@@ -1584,3 +2296,241 @@ mod matrix_tests {
         matrix[(0, 0)];
     }
 }
+
+#[cfg(test)]
+#[cfg(all(feature = "cache", feature = "collector", feature = "http"))]
+mod pagination_tests {
+    use super::*;
+
+    fn group_text(commands: usize) -> String {
+        (0..commands).map(|i| format!("`command{:03}`\n", i)).collect()
+    }
+
+    #[test]
+    fn single_page() {
+        let pages = paginate_group_texts("", vec![("A", group_text(3)), ("B", group_text(3))]);
+
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].len(), 2);
+    }
+
+    #[test]
+    fn split_fields() {
+        let pages = paginate_group_texts("", vec![("A", group_text(200))]);
+        let fields = pages.iter().flatten().collect::<Vec<_>>();
+
+        assert!(fields.len() > 1);
+        assert!(fields.iter().all(|(name, text)| *name == "A" && text.len() <= EMBED_FIELD_VALUE_MAX_LENGTH));
+        assert_eq!(fields.iter().map(|(_, text)| text.as_str()).collect::<String>(), group_text(200));
+    }
+
+    #[test]
+    fn split_pages() {
+        let groups = (0..30).map(|_| ("A", group_text(10))).collect();
+        let pages = paginate_group_texts("", groups);
+
+        assert!(pages.len() > 1);
+        assert!(pages.iter().all(|page| page.len() <= EMBED_MAX_FIELDS));
+        assert!(pages.iter().all(|page| {
+            page.iter().map(|(name, text)| name.len() + text.len()).sum::<usize>() <= EMBED_MAX_LENGTH as usize
+        }));
+        assert_eq!(pages.iter().map(Vec::len).sum::<usize>(), 30);
+    }
+
+    #[test]
+    fn split_long_lines() {
+        let line = "é".repeat(3000);
+        let pages = paginate_group_texts("", vec![("A", format!("{}\n`short`\n", line))]);
+        let fields = pages.iter().flatten().collect::<Vec<_>>();
+
+        assert_eq!(fields.len(), 3);
+        assert!(fields.iter().all(|(_, text)| text.chars().count() <= EMBED_FIELD_VALUE_MAX_LENGTH));
+        assert_eq!(fields.iter().map(|(_, text)| text.replace('\n', "")).collect::<String>(), format!("{}`short`", line));
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "cache", feature = "gateway", feature = "http"))]
+mod sub_command_tests {
+    use super::*;
+    use crate::framework::standard::{CheckFunction, CheckResult, CommandResult};
+    use crate::model::permissions::Permissions;
+    use crate::utils::{Colour, CustomMessage};
+    use futures::future::FutureExt;
+
+    fn run<'fut>(_: &'fut Context, _: &'fut Message, _: Args) -> BoxFuture<'fut, CommandResult> {
+        async { Ok(()) }.boxed()
+    }
+
+    fn admin<'fut>(_: &'fut Context, _: &'fut Message, _: &'fut mut Args, _: &'fut CommandOptions) -> BoxFuture<'fut, CheckResult> {
+        async { CheckResult::Success }.boxed()
+    }
+
+    static ADMIN_CHECK: Check = Check {
+        name: "admin",
+        function: CheckFunction::Static(admin),
+        display_in_help: true,
+        check_in_help: true,
+    };
+
+    fn locked<'fut>(_: &'fut Context, _: &'fut Message, _: &'fut mut Args, _: &'fut CommandOptions) -> BoxFuture<'fut, CheckResult> {
+        async { CheckResult::new_user("Locked.") }.boxed()
+    }
+
+    static LOCKED_CHECK: Check = Check {
+        name: "locked",
+        function: CheckFunction::Static(locked),
+        display_in_help: true,
+        check_in_help: true,
+    };
+
+    static HELP_OPTIONS: HelpOptions = HelpOptions {
+        names: &["help"],
+        suggestion_text: "",
+        no_help_available_text: "",
+        usage_label: "",
+        usage_sample_label: "",
+        ungrouped_label: "",
+        description_label: "",
+        grouped_label: "",
+        aliases_label: "",
+        guild_only_text: "",
+        checks_label: "",
+        sub_commands_label: "",
+        default_sub_command_label: "",
+        dm_only_text: "",
+        dm_and_guild_text: "",
+        available_text: "",
+        command_not_found_text: "",
+        individual_command_tip: "",
+        strikethrough_commands_tip_in_dm: None,
+        strikethrough_commands_tip_in_guild: None,
+        group_prefix: "",
+        lacking_role: HelpBehaviour::Strike,
+        lacking_permissions: HelpBehaviour::Strike,
+        lacking_ownership: HelpBehaviour::Hide,
+        lacking_conditions: HelpBehaviour::Hide,
+        wrong_channel: HelpBehaviour::Strike,
+        embed_error_colour: Colour(0),
+        embed_success_colour: Colour(0),
+        max_levenshtein_distance: 0,
+        indention_prefix: "-",
+        page_timeout: 120,
+    };
+
+    static KEY_OPTIONS: CommandOptions = CommandOptions {
+        checks: &[],
+        bucket: None,
+        max_concurrency: None,
+        names: &["key"],
+        desc: None,
+        delimiters: &[],
+        usage: None,
+        examples: &[],
+        min_args: None,
+        max_args: None,
+        allowed_roles: &[],
+        required_permissions: Permissions::empty(),
+        help_available: true,
+        only_in: OnlyIn::None,
+        owners_only: false,
+        owner_privilege: true,
+        sub_commands: &[],
+        default_sub_command: None,
+    };
+
+    static KEY_COMMAND: InternalCommand = InternalCommand { fun: run, prepare: None, options: &KEY_OPTIONS };
+
+    static SET_OPTIONS: CommandOptions = CommandOptions {
+        checks: &[&ADMIN_CHECK],
+        names: &["set"],
+        only_in: OnlyIn::Guild,
+        sub_commands: &[&KEY_COMMAND],
+        ..KEY_OPTIONS
+    };
+
+    static SET_COMMAND: InternalCommand = InternalCommand { fun: run, prepare: None, options: &SET_OPTIONS };
+
+    static SHOW_OPTIONS: CommandOptions = CommandOptions { names: &["show"], ..KEY_OPTIONS };
+
+    static SHOW_COMMAND: InternalCommand = InternalCommand { fun: run, prepare: None, options: &SHOW_OPTIONS };
+
+    static HIDDEN_OPTIONS: CommandOptions = CommandOptions {
+        names: &["hidden"],
+        help_available: false,
+        ..KEY_OPTIONS
+    };
+
+    static HIDDEN_COMMAND: InternalCommand = InternalCommand { fun: run, prepare: None, options: &HIDDEN_OPTIONS };
+
+    static LOCKED_OPTIONS: CommandOptions = CommandOptions {
+        checks: &[&LOCKED_CHECK],
+        names: &["locked"],
+        ..KEY_OPTIONS
+    };
+
+    static LOCKED_COMMAND: InternalCommand = InternalCommand { fun: run, prepare: None, options: &LOCKED_OPTIONS };
+
+    static CONFIG_OPTIONS: CommandOptions = CommandOptions {
+        names: &["config"],
+        sub_commands: &[&SET_COMMAND, &HIDDEN_COMMAND, &LOCKED_COMMAND],
+        default_sub_command: Some(&SHOW_COMMAND),
+        ..KEY_OPTIONS
+    };
+
+    #[tokio::test]
+    async fn tree() {
+        let texts = HelpTexts {
+            suggestion_text: "",
+            no_help_available_text: "",
+            usage_label: "",
+            usage_sample_label: "",
+            description_label: "",
+            grouped_label: "",
+            aliases_label: "",
+            guild_only_text: "Only in guilds",
+            checks_label: "Checks",
+            sub_commands_label: "",
+            default_sub_command_label: "Default",
+            dm_only_text: "Only in DM",
+            dm_and_guild_text: "In DM and guilds",
+            available_text: "",
+            individual_command_tip: "",
+            group_prefix: "",
+            strikethrough_commands_tip_in_dm: None,
+            strikethrough_commands_tip_in_guild: None,
+        };
+
+        let ctx = super::super::test::context();
+        // A direct message, in which the guild only `set` is struck through,
+        // while `locked` fails its check and is hidden.
+        let msg = CustomMessage::new().build();
+        let tree = sub_command_tree(
+            &ctx,
+            &msg,
+            &CONFIG_OPTIONS,
+            &[],
+            &HashSet::new(),
+            &HELP_OPTIONS,
+            texts,
+            HelpBehaviour::Nothing,
+        ).await;
+
+        assert_eq!(tree.iter().map(|c| c.name).collect::<Vec<_>>(), vec!["set", "show"]);
+        assert!(!tree[0].is_default && tree[1].is_default);
+        assert!(tree[0].is_struck && !tree[1].is_struck);
+        assert_eq!(tree[0].checks, vec!["admin".to_string()]);
+        assert_eq!(tree[0].sub_commands[0].name, "key");
+        assert!(tree[0].sub_commands[0].is_struck);
+
+        let mut text = String::default();
+        sub_command_tree_to_string(&mut text, &tree, 0, "-", &texts);
+
+        assert_eq!(
+            text,
+            "~~`set`~~: Only in guilds; Checks: `admin`\n\
+             -~~`key`~~: In DM and guilds\n\
+             `show` *(Default)*: In DM and guilds\n",
+        );
+    }
+}
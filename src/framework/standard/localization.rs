@@ -0,0 +1,427 @@
+use super::{DispatchError, Reason};
+use crate::client::Context;
+use crate::model::channel::Message;
+use futures::future::BoxFuture;
+use std::future::Future;
+use std::sync::Arc;
+
+type LocaleHook = Arc<dyn for<'fut> Fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, Option<String>> + Send + Sync>;
+type TranslateHook = Arc<
+    dyn for<'fut> Fn(&'fut Context, &'fut str, &'fut str) -> BoxFuture<'fut, Option<String>>
+        + Send
+        + Sync,
+>;
+
+tokio::task_local! {
+    /// The localization of the framework dispatching the current message.
+    static LOCALIZATION: Localization;
+}
+
+/// Hooks for translating the texts of the framework, such as the labels of
+/// the help and the descriptions of [`DispatchError`]s, into the locale of an
+/// invocation.
+///
+/// Texts are identified by keys:
+///
+/// - `help.<field>` for the texts of the [`HelpOptions`], e.g.
+///   `help.usage_label`;
+/// - `commands.<name>.description` and `commands.<name>.usage` for the
+///   description and usage of a command, by its actual name;
+/// - `groups.<name>.description` for the description of a group, shown when
+///   its commands are looked up by its name;
+/// - `dispatch.<error>` for the descriptions of [`DispatchError`]s, e.g.
+///   `dispatch.not_enough_arguments`. Refer to [`DispatchError::describe`] for
+///   the placeholders they may contain.
+///
+/// # Examples
+///
+/// Show the help in German to users of German guilds, given a function
+/// looking up the locale of a guild. The help functions pick up the
+/// localization set on the framework:
+///
+/// ```rust,no_run
+/// # use serenity::prelude::*;
+/// # use serenity::model::prelude::*;
+/// use serenity::framework::standard::{help_commands, macros::help};
+/// use serenity::framework::standard::{Args, CommandGroup, CommandResult, HelpOptions, Localization, StandardFramework};
+/// use std::collections::HashSet;
+///
+/// # async fn guild_locale(_: &Context, _: GuildId) -> Option<String> { None }
+/// #
+/// fn localization() -> Localization {
+///     let mut localization = Localization::default();
+///
+///     localization
+///         .locale(|ctx, msg| Box::pin(async move {
+///             guild_locale(ctx, msg.guild_id?).await
+///         }))
+///         .translate(|_, locale, key| Box::pin(async move {
+///             match (locale, key) {
+///                 ("de", "help.usage_label") => Some("Verwendung".to_string()),
+///                 ("de", "commands.ping.description") => Some("Antwortet mit Pong.".to_string()),
+///                 _ => None,
+///             }
+///         }));
+///
+///     localization
+/// }
+///
+/// #[help]
+/// async fn my_help(
+///     ctx: &Context,
+///     msg: &Message,
+///     args: Args,
+///     help_options: &'static HelpOptions,
+///     groups: &[&'static CommandGroup],
+///     owners: HashSet<UserId>
+/// ) -> CommandResult {
+///     let _ = help_commands::with_embeds(ctx, msg, args, help_options, groups, owners).await;
+///
+///     Ok(())
+/// }
+///
+/// let framework = StandardFramework::new()
+///     .localization(localization())
+///     .help(&MY_HELP);
+/// ```
+///
+/// [`DispatchError`]: enum.DispatchError.html
+/// [`DispatchError::describe`]: enum.DispatchError.html#method.describe
+/// [`HelpOptions`]: struct.HelpOptions.html
+#[derive(Clone, Default)]
+pub struct Localization {
+    locale: Option<LocaleHook>,
+    translate: Option<TranslateHook>,
+}
+
+impl Localization {
+    /// Sets the function resolving the locale of an invocation, e.g. from the
+    /// settings of its guild or user.
+    ///
+    /// Return `None` to leave the texts untranslated.
+    pub fn locale<F>(&mut self, locale: F) -> &mut Self
+    where
+        F: for<'fut> Fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, Option<String>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.locale = Some(Arc::new(locale));
+
+        self
+    }
+
+    /// Sets the function translating the text identified by a key into a
+    /// locale, given as `(context, locale, key)`.
+    ///
+    /// Return `None` to use the default text.
+    pub fn translate<F>(&mut self, translate: F) -> &mut Self
+    where
+        F: for<'fut> Fn(&'fut Context, &'fut str, &'fut str) -> BoxFuture<'fut, Option<String>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.translate = Some(Arc::new(translate));
+
+        self
+    }
+
+    /// Runs `future` with this as the localization of the current message.
+    pub(crate) async fn scope<F: Future>(self, future: F) -> F::Output {
+        LOCALIZATION.scope(self, future).await
+    }
+
+    /// Resolves the locale of the invocation by `msg`.
+    pub async fn translator<'a>(&self, ctx: &'a Context, msg: &Message) -> Translator<'a> {
+        let locale = match &self.locale {
+            Some(locale) => locale(ctx, msg).await,
+            None => None,
+        };
+
+        Translator {
+            ctx,
+            locale,
+            translate: self.translate.clone(),
+        }
+    }
+}
+
+/// Translates texts into the locale of an invocation, created via
+/// [`Translator::new`] or [`Localization::translator`].
+///
+/// [`Translator::new`]: #method.new
+/// [`Localization::translator`]: struct.Localization.html#method.translator
+pub struct Translator<'a> {
+    ctx: &'a Context,
+    locale: Option<String>,
+    translate: Option<TranslateHook>,
+}
+
+impl<'a> Translator<'a> {
+    /// Resolves the locale of the invocation by `msg` via the localization
+    /// set with [`StandardFramework::localization`].
+    ///
+    /// Texts are left untranslated if none is set, or outside of the
+    /// framework's dispatch of a message, e.g. in a spawned task.
+    ///
+    /// [`StandardFramework::localization`]: struct.StandardFramework.html#method.localization
+    pub async fn new(ctx: &'a Context, msg: &Message) -> Translator<'a> {
+        match LOCALIZATION.try_with(Localization::clone) {
+            Ok(localization) => localization.translator(ctx, msg).await,
+            Err(_) => Translator::untranslated(ctx),
+        }
+    }
+
+    /// A translator leaving all texts untranslated.
+    pub(crate) fn untranslated(ctx: &'a Context) -> Self {
+        Translator {
+            ctx,
+            locale: None,
+            translate: None,
+        }
+    }
+
+    /// The locale of the invocation, if resolved.
+    #[inline]
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// Whether texts may be translated, i.e. whether a locale has been
+    /// resolved and texts can be translated into it.
+    #[inline]
+    pub fn is_translating(&self) -> bool {
+        self.locale.is_some() && self.translate.is_some()
+    }
+
+    /// Translates the text identified by `key`, or returns `default`.
+    pub async fn text(&self, key: &str, default: &str) -> String {
+        if let (Some(locale), Some(translate)) = (&self.locale, &self.translate) {
+            if let Some(text) = translate(self.ctx, locale, key).await {
+                return text;
+            }
+        }
+
+        default.to_string()
+    }
+}
+
+/// Replaces the `{name}` placeholders in `text` with their values.
+fn fill_placeholders(mut text: String, values: &[(&str, String)]) -> String {
+    for (name, value) in values {
+        text = text.replace(&format!("{{{}}}", name), value);
+    }
+
+    text
+}
+
+impl DispatchError {
+    /// Describes the error to the user, translated into the locale of the
+    /// invocation.
+    ///
+    /// The descriptions are translated via the key `dispatch.<error>`, e.g.
+    /// `dispatch.not_enough_arguments`, and contain placeholders for the
+    /// details of the error:
+    ///
+    /// | Key                                | Placeholders          |
+    /// |------------------------------------|-----------------------|
+    /// | `dispatch.check_failed`            | `{check}`             |
+    /// | `dispatch.ratelimited`             | `{seconds}`           |
+    /// | `dispatch.concurrency_limited`     | `{max}`               |
+    /// | `dispatch.command_disabled`        | `{command}`           |
+    /// | `dispatch.blocked_user`            |                       |
+    /// | `dispatch.blocked_guild`           |                       |
+    /// | `dispatch.blocked_channel`         |                       |
+    /// | `dispatch.only_for_dm`             |                       |
+    /// | `dispatch.only_for_guilds`         |                       |
+    /// | `dispatch.only_for_owners`         |                       |
+    /// | `dispatch.lacking_role`            |                       |
+    /// | `dispatch.lacking_permissions`     | `{permissions}`       |
+    /// | `dispatch.not_enough_arguments`    | `{min}`, `{given}`    |
+    /// | `dispatch.too_many_arguments`      | `{max}`, `{given}`    |
+    /// | `dispatch.argument_parse`          | `{parameter}`, `{usage}`, `{error}` |
+    /// | `dispatch.missing_argument`        | `{parameter}`, `{usage}` |
+    ///
+    /// A failed check giving a reason for the user is described by that
+    /// reason instead.
+    ///
+    /// Returns `None` for errors that are not meant to be reported, such as
+    /// [`IgnoredBot`].
+    ///
+    /// # Examples
+    ///
+    /// Report dispatch errors in the locale set via the framework's
+    /// localization:
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::standard::{StandardFramework, Translator};
+    ///
+    /// let framework = StandardFramework::new()
+    ///     .on_dispatch_error(|ctx, msg, error| Box::pin(async move {
+    ///         let translator = Translator::new(ctx, msg).await;
+    ///
+    ///         if let Some(description) = error.describe(&translator).await {
+    ///             let _ = msg.channel_id.say(ctx, description).await;
+    ///         }
+    ///     }));
+    /// ```
+    ///
+    /// [`IgnoredBot`]: #variant.IgnoredBot
+    pub async fn describe(&self, translator: &Translator<'_>) -> Option<String> {
+        let (key, default, values) = match self {
+            DispatchError::CheckFailed(_, Reason::User(reason))
+            | DispatchError::CheckFailed(_, Reason::UserAndLog { user: reason, .. }) => {
+                return Some(reason.clone());
+            },
+            DispatchError::CheckFailed(check, _) => (
+                "dispatch.check_failed",
+                "The check `{check}` failed.",
+                vec![("check", check.to_string())],
+            ),
+            DispatchError::Ratelimited { remaining, .. } => (
+                "dispatch.ratelimited",
+                "Try this again in {seconds} seconds.",
+                vec![("seconds", remaining.as_secs().max(1).to_string())],
+            ),
+            DispatchError::ConcurrencyLimited { max, .. } => (
+                "dispatch.concurrency_limited",
+                "This command can only run {max} times at once.",
+                vec![("max", max.to_string())],
+            ),
+            DispatchError::CommandDisabled(command) => (
+                "dispatch.command_disabled",
+                "The command `{command}` is disabled.",
+                vec![("command", command.clone())],
+            ),
+            DispatchError::BlockedUser => (
+                "dispatch.blocked_user",
+                "You are blocked from using commands.",
+                vec![],
+            ),
+            DispatchError::BlockedGuild => (
+                "dispatch.blocked_guild",
+                "This guild is blocked from using commands.",
+                vec![],
+            ),
+            DispatchError::BlockedChannel => (
+                "dispatch.blocked_channel",
+                "Commands cannot be used in this channel.",
+                vec![],
+            ),
+            DispatchError::OnlyForDM => (
+                "dispatch.only_for_dm",
+                "This command can only be used in direct messages.",
+                vec![],
+            ),
+            DispatchError::OnlyForGuilds => (
+                "dispatch.only_for_guilds",
+                "This command can only be used in guilds.",
+                vec![],
+            ),
+            DispatchError::OnlyForOwners => (
+                "dispatch.only_for_owners",
+                "This command can only be used by the owners of the bot.",
+                vec![],
+            ),
+            DispatchError::LackingRole => (
+                "dispatch.lacking_role",
+                "You lack the roles required for this command.",
+                vec![],
+            ),
+            DispatchError::LackingPermissions(permissions) => (
+                "dispatch.lacking_permissions",
+                "You lack the permissions required for this command: {permissions}",
+                vec![("permissions", permissions.get_permission_names().join(", "))],
+            ),
+            DispatchError::NotEnoughArguments { min, given } => (
+                "dispatch.not_enough_arguments",
+                "This command takes at least {min} arguments, but {given} were given.",
+                vec![("min", min.to_string()), ("given", given.to_string())],
+            ),
+            DispatchError::TooManyArguments { max, given } => (
+                "dispatch.too_many_arguments",
+                "This command takes at most {max} arguments, but {given} were given.",
+                vec![("max", max.to_string()), ("given", given.to_string())],
+            ),
+            DispatchError::ArgumentParse { parameter, usage, error: Some(error) } => (
+                "dispatch.argument_parse",
+                "Invalid `{parameter}`: {error}\nUsage: `{usage}`",
+                vec![("parameter", parameter.to_string()), ("usage", usage.to_string()), ("error", error.clone())],
+            ),
+            DispatchError::ArgumentParse { parameter, usage, error: None } => (
+                "dispatch.missing_argument",
+                "Missing `{parameter}`.\nUsage: `{usage}`",
+                vec![("parameter", parameter.to_string()), ("usage", usage.to_string())],
+            ),
+            DispatchError::IgnoredBot | DispatchError::WebhookAuthor => return None,
+            DispatchError::__Nonexhaustive => unreachable!(),
+        };
+
+        Some(fill_placeholders(translator.text(key, default).await, &values))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn placeholders() {
+        let values = [("min", "2".to_string()), ("given", "1".to_string())];
+
+        assert_eq!(
+            fill_placeholders("Need {min}, got {given}; {min}!".to_string(), &values),
+            "Need 2, got 1; 2!",
+        );
+        assert_eq!(fill_placeholders("{unknown}".to_string(), &values), "{unknown}");
+    }
+
+    #[cfg(all(feature = "cache", feature = "gateway"))]
+    #[tokio::test]
+    async fn describe() {
+        use crate::utils::CustomMessage;
+        use futures::future::FutureExt;
+
+        let ctx = super::super::test::context();
+        let msg = CustomMessage::new().build();
+
+        let mut localization = Localization::default();
+        localization
+            .locale(|_, _| async { Some("de".to_string()) }.boxed())
+            .translate(|_, locale, key| async move {
+                match (locale, key) {
+                    ("de", "dispatch.not_enough_arguments") => {
+                        Some("Mindestens {min} Argumente, {given} angegeben.".to_string())
+                    },
+                    _ => None,
+                }
+            }.boxed());
+
+        let error = DispatchError::NotEnoughArguments { min: 2, given: 1 };
+
+        // Outside of the framework's dispatch.
+        let translator = Translator::new(&ctx, &msg).await;
+        assert_eq!(
+            error.describe(&translator).await.unwrap(),
+            "This command takes at least 2 arguments, but 1 were given.",
+        );
+
+        localization.scope(async {
+            let translator = Translator::new(&ctx, &msg).await;
+            assert_eq!(translator.locale(), Some("de"));
+            assert_eq!(error.describe(&translator).await.unwrap(), "Mindestens 2 Argumente, 1 angegeben.");
+
+            let error = DispatchError::TooManyArguments { max: 1, given: 2 };
+            assert_eq!(
+                error.describe(&translator).await.unwrap(),
+                "This command takes at most 1 arguments, but 2 were given.",
+            );
+
+            let error = DispatchError::CheckFailed("admin", Reason::User("Admins only.".to_string()));
+            assert_eq!(error.describe(&translator).await.unwrap(), "Admins only.");
+
+            assert!(DispatchError::IgnoredBot.describe(&translator).await.is_none());
+        }).await;
+    }
+}
@@ -0,0 +1,461 @@
+use super::DispatchError;
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// The upper bounds of the buckets of a [`Histogram`], in milliseconds.
+///
+/// [`Histogram`]: struct.Histogram.html
+pub const LATENCY_BOUNDS: [u64; 11] = [5, 10, 25, 50, 100, 250, 500, 1000, 2500, 5000, 10000];
+
+/// The bucket of a [`Histogram`] counting `latency`.
+///
+/// [`Histogram`]: struct.Histogram.html
+fn bucket(latency: Duration) -> usize {
+    let millis = latency.as_millis();
+
+    LATENCY_BOUNDS
+        .iter()
+        .position(|&bound| millis <= u128::from(bound))
+        .unwrap_or(LATENCY_BOUNDS.len())
+}
+
+/// A histogram of the latencies of a command.
+///
+/// Latencies are counted in buckets bounded by [`LATENCY_BOUNDS`], plus one
+/// for latencies exceeding the greatest bound.
+///
+/// [`LATENCY_BOUNDS`]: constant.LATENCY_BOUNDS.html
+#[derive(Clone, Debug, Default)]
+pub struct Histogram {
+    buckets: [u64; LATENCY_BOUNDS.len() + 1],
+    count: u64,
+    sum: Duration,
+    max: Duration,
+}
+
+impl Histogram {
+    #[cfg(test)]
+    fn record(&mut self, latency: Duration) {
+        self.buckets[bucket(latency)] += 1;
+        self.count += 1;
+        self.sum += latency;
+        self.max = self.max.max(latency);
+    }
+
+    /// The number of recorded latencies.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The sum of the recorded latencies.
+    #[inline]
+    pub fn sum(&self) -> Duration {
+        self.sum
+    }
+
+    /// The greatest recorded latency.
+    #[inline]
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// The mean of the recorded latencies, if any have been recorded.
+    pub fn mean(&self) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+
+        Some(Duration::from_nanos((self.sum.as_nanos() / u128::from(self.count)) as u64))
+    }
+
+    /// The number of latencies in each bucket, along with the upper bound of
+    /// the bucket.
+    ///
+    /// The bound of the last bucket is `None`, as it counts the latencies
+    /// exceeding the greatest bound.
+    pub fn buckets(&self) -> Vec<(Option<Duration>, u64)> {
+        LATENCY_BOUNDS
+            .iter()
+            .map(|&bound| Some(Duration::from_millis(bound)))
+            .chain(std::iter::once(None))
+            .zip(self.buckets.iter().copied())
+            .collect()
+    }
+
+    /// Estimates the latency under which the `quantile` of the recorded
+    /// latencies lie, e.g. `0.99` for the 99th percentile, as the upper bound
+    /// of the bucket it falls into.
+    ///
+    /// Returns the greatest recorded latency if the quantile falls into the
+    /// last bucket, or `None` if no latencies have been recorded.
+    pub fn quantile(&self, quantile: f64) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let rank = (quantile * self.count as f64).ceil().max(1.0) as u64;
+        let mut seen = 0;
+
+        for (bound, count) in self.buckets() {
+            seen += count;
+
+            if seen >= rank {
+                return Some(bound.map_or(self.max, |bound| bound.min(self.max)));
+            }
+        }
+
+        Some(self.max)
+    }
+}
+
+/// The usage metrics of a single command.
+#[derive(Clone, Debug, Default)]
+pub struct CommandMetrics {
+    /// How many times the command has been run.
+    pub invocations: u64,
+    /// How many times the command has returned an error, including when its
+    /// arguments have failed to parse.
+    pub failures: u64,
+    /// How many times the command could not be run, by the [`name`] of the
+    /// [`DispatchError`].
+    ///
+    /// [`DispatchError`]: enum.DispatchError.html
+    /// [`name`]: enum.DispatchError.html#method.name
+    pub errors: HashMap<&'static str, u64>,
+    /// The latencies of the command.
+    pub latency: Histogram,
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The counters behind a [`Histogram`], updated without a lock.
+///
+/// [`Histogram`]: struct.Histogram.html
+#[derive(Default)]
+struct HistogramCounters {
+    buckets: [AtomicU64; LATENCY_BOUNDS.len() + 1],
+    count: AtomicU64,
+    sum_nanos: AtomicU64,
+    max_nanos: AtomicU64,
+}
+
+impl HistogramCounters {
+    fn record(&self, latency: Duration) {
+        let nanos = latency.as_nanos() as u64;
+
+        self.buckets[bucket(latency)].fetch_add(1, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_nanos.fetch_add(nanos, Ordering::Relaxed);
+
+        let mut max = self.max_nanos.load(Ordering::Relaxed);
+
+        while nanos > max {
+            match self.max_nanos.compare_exchange_weak(max, nanos, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(_) => break,
+                Err(current) => max = current,
+            }
+        }
+    }
+
+    fn load(&self) -> Histogram {
+        let mut histogram = Histogram {
+            count: self.count.load(Ordering::Relaxed),
+            sum: Duration::from_nanos(self.sum_nanos.load(Ordering::Relaxed)),
+            max: Duration::from_nanos(self.max_nanos.load(Ordering::Relaxed)),
+            ..Histogram::default()
+        };
+
+        for (bucket, counter) in histogram.buckets.iter_mut().zip(self.buckets.iter()) {
+            *bucket = counter.load(Ordering::Relaxed);
+        }
+
+        histogram
+    }
+}
+
+/// Counts [`DispatchError`]s by their [`name`].
+///
+/// The map is only write-locked the first time an error is recorded.
+///
+/// [`DispatchError`]: enum.DispatchError.html
+/// [`name`]: enum.DispatchError.html#method.name
+#[derive(Default)]
+struct ErrorCounters(RwLock<HashMap<&'static str, AtomicU64>>);
+
+impl ErrorCounters {
+    fn record(&self, name: &'static str) {
+        if let Some(counter) = self.0.read().expect("poisoned metrics").get(name) {
+            counter.fetch_add(1, Ordering::Relaxed);
+
+            return;
+        }
+
+        self.0
+            .write()
+            .expect("poisoned metrics")
+            .entry(name)
+            .or_default()
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn load(&self) -> HashMap<&'static str, u64> {
+        self.0
+            .read()
+            .expect("poisoned metrics")
+            .iter()
+            .map(|(name, counter)| (*name, counter.load(Ordering::Relaxed)))
+            .collect()
+    }
+}
+
+/// The counters behind the [`CommandMetrics`] of a command.
+///
+/// [`CommandMetrics`]: struct.CommandMetrics.html
+#[derive(Default)]
+struct CommandCounters {
+    invocations: AtomicU64,
+    failures: AtomicU64,
+    errors: ErrorCounters,
+    latency: HistogramCounters,
+}
+
+impl CommandCounters {
+    fn load(&self) -> CommandMetrics {
+        CommandMetrics {
+            invocations: self.invocations.load(Ordering::Relaxed),
+            failures: self.failures.load(Ordering::Relaxed),
+            errors: self.errors.load(),
+            latency: self.latency.load(),
+            _nonexhaustive: (),
+        }
+    }
+}
+
+/// The counters of the commands are shared, so that recording a run only
+/// takes the lock of the map for reading, unless the command is run for the
+/// first time.
+#[derive(Default)]
+struct MetricsData {
+    commands: RwLock<HashMap<String, Arc<CommandCounters>>>,
+    errors: ErrorCounters,
+}
+
+/// A handle to the usage metrics of the commands dispatched by the
+/// [`StandardFramework`], created via [`StandardFramework::metrics`].
+///
+/// # Examples
+///
+/// Report the slowest commands from within a command, given the handle in
+/// the `Client`'s data:
+///
+/// ```rust,no_run
+/// # use serenity::prelude::*;
+/// # use serenity::model::prelude::*;
+/// use serenity::framework::standard::{macros::command, CommandResult, Metrics};
+/// use std::fmt::Write;
+///
+/// struct MetricsKey;
+///
+/// impl TypeMapKey for MetricsKey {
+///     type Value = Metrics;
+/// }
+///
+/// #[command]
+/// async fn slowest(ctx: &Context, msg: &Message) -> CommandResult {
+///     let metrics = ctx.data.read().await.get::<MetricsKey>().cloned().unwrap();
+///
+///     let mut commands = metrics.commands().into_iter().collect::<Vec<_>>();
+///     commands.sort_by_key(|(_, m)| std::cmp::Reverse(m.latency.quantile(0.99)));
+///
+///     let mut content = String::new();
+///
+///     for (name, m) in commands.iter().take(5) {
+///         let _ = writeln!(content, "`{}`: {:?} (p99), {} runs", name, m.latency.quantile(0.99).unwrap_or_default(), m.invocations);
+///     }
+///
+///     msg.channel_id.say(&ctx.http, content).await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`StandardFramework`]: struct.StandardFramework.html
+/// [`StandardFramework::metrics`]: struct.StandardFramework.html#method.metrics
+#[derive(Clone, Default)]
+pub struct Metrics {
+    inner: Arc<MetricsData>,
+}
+
+impl Metrics {
+    /// The metrics of a command, by its actual name.
+    pub fn command(&self, name: &str) -> Option<CommandMetrics> {
+        self.inner.commands.read().expect("poisoned metrics").get(name).map(|c| c.load())
+    }
+
+    /// The metrics of all commands that have been requested, by their actual
+    /// names.
+    pub fn commands(&self) -> HashMap<String, CommandMetrics> {
+        self.inner.commands.read().expect("poisoned metrics")
+            .iter()
+            .map(|(name, counters)| (name.clone(), counters.load()))
+            .collect()
+    }
+
+    /// How many times a message could not be dispatched, by the [`name`] of
+    /// the [`DispatchError`].
+    ///
+    /// Unlike [`CommandMetrics::errors`], this includes errors occurring
+    /// before a command has been recognised, such as [`BlockedUser`].
+    ///
+    /// [`BlockedUser`]: enum.DispatchError.html#variant.BlockedUser
+    /// [`CommandMetrics::errors`]: struct.CommandMetrics.html#structfield.errors
+    /// [`DispatchError`]: enum.DispatchError.html
+    /// [`name`]: enum.DispatchError.html#method.name
+    pub fn errors(&self) -> HashMap<&'static str, u64> {
+        self.inner.errors.load()
+    }
+
+    /// Clears all metrics.
+    pub fn reset(&self) {
+        self.inner.commands.write().expect("poisoned metrics").clear();
+        self.inner.errors.0.write().expect("poisoned metrics").clear();
+    }
+
+    /// The counters of a command, created on its first run.
+    fn counters(&self, command: &str) -> Arc<CommandCounters> {
+        if let Some(counters) = self.inner.commands.read().expect("poisoned metrics").get(command) {
+            return Arc::clone(counters);
+        }
+
+        let mut commands = self.inner.commands.write().expect("poisoned metrics");
+
+        Arc::clone(commands.entry(command.to_string()).or_default())
+    }
+
+    /// Records a run of a command, and whether it has returned an error or
+    /// its arguments have failed to parse.
+    pub(crate) fn record(&self, command: &str, latency: Duration, failed: bool) {
+        let counters = self.counters(command);
+
+        counters.invocations.fetch_add(1, Ordering::Relaxed);
+        counters.latency.record(latency);
+
+        if failed {
+            counters.failures.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Records an error preventing a message from being dispatched, to the
+    /// command if it has been recognised.
+    ///
+    /// Arguments failing to parse are not recorded here, but as a failed run
+    /// of the command.
+    pub(crate) fn record_error(&self, command: Option<&str>, error: &DispatchError) {
+        if let DispatchError::ArgumentParse { .. } = error {
+            return;
+        }
+
+        let name = error.name();
+
+        self.inner.errors.record(name);
+
+        if let Some(command) = command {
+            self.counters(command).errors.record(name);
+        }
+    }
+}
+
+impl DispatchError {
+    /// The name of the variant of the error, in snake case, e.g.
+    /// `not_enough_arguments`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DispatchError::CheckFailed(..) => "check_failed",
+            DispatchError::Ratelimited { .. } => "ratelimited",
+            DispatchError::ConcurrencyLimited { .. } => "concurrency_limited",
+            DispatchError::CommandDisabled(_) => "command_disabled",
+            DispatchError::BlockedUser => "blocked_user",
+            DispatchError::BlockedGuild => "blocked_guild",
+            DispatchError::BlockedChannel => "blocked_channel",
+            DispatchError::OnlyForDM => "only_for_dm",
+            DispatchError::OnlyForGuilds => "only_for_guilds",
+            DispatchError::OnlyForOwners => "only_for_owners",
+            DispatchError::LackingRole => "lacking_role",
+            DispatchError::LackingPermissions(_) => "lacking_permissions",
+            DispatchError::NotEnoughArguments { .. } => "not_enough_arguments",
+            DispatchError::TooManyArguments { .. } => "too_many_arguments",
+            DispatchError::ArgumentParse { .. } => "argument_parse",
+            DispatchError::IgnoredBot => "ignored_bot",
+            DispatchError::WebhookAuthor => "webhook_author",
+            DispatchError::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn histogram() {
+        let mut histogram = Histogram::default();
+
+        assert_eq!(histogram.mean(), None);
+        assert_eq!(histogram.quantile(0.5), None);
+
+        for millis in &[1, 3, 7, 20, 20000] {
+            histogram.record(Duration::from_millis(*millis));
+        }
+
+        let buckets = histogram.buckets();
+        assert_eq!(buckets.len(), LATENCY_BOUNDS.len() + 1);
+        assert_eq!(buckets[0], (Some(Duration::from_millis(5)), 2));
+        assert_eq!(buckets[1], (Some(Duration::from_millis(10)), 1));
+        assert_eq!(buckets[2], (Some(Duration::from_millis(25)), 1));
+        assert_eq!(buckets[LATENCY_BOUNDS.len()], (None, 1));
+
+        assert_eq!(histogram.count(), 5);
+        assert_eq!(histogram.max(), Duration::from_millis(20000));
+        assert_eq!(histogram.mean(), Some(Duration::from_micros(4_006_200)));
+        assert_eq!(histogram.quantile(0.0), Some(Duration::from_millis(5)));
+        assert_eq!(histogram.quantile(0.6), Some(Duration::from_millis(10)));
+        assert_eq!(histogram.quantile(1.0), Some(Duration::from_millis(20000)));
+    }
+
+    #[test]
+    fn metrics() {
+        let metrics = Metrics::default();
+
+        metrics.record("ping", Duration::from_millis(3), false);
+        metrics.record("ping", Duration::from_millis(30), true);
+        metrics.record_error(Some("ping"), &DispatchError::OnlyForGuilds);
+        metrics.record_error(None, &DispatchError::BlockedUser);
+        metrics.record_error(Some("ping"), &DispatchError::ArgumentParse {
+            parameter: "amount",
+            usage: "<amount>",
+            error: None,
+        });
+
+        let ping = metrics.command("ping").unwrap();
+        assert_eq!(ping.invocations, 2);
+        assert_eq!(ping.failures, 1);
+        assert_eq!(ping.latency.count(), 2);
+        assert_eq!(ping.latency.max(), Duration::from_millis(30));
+        assert_eq!(ping.latency.sum(), Duration::from_millis(33));
+        assert_eq!(ping.latency.buckets()[0].1, 1);
+        assert_eq!(ping.errors.get("only_for_guilds"), Some(&1));
+        assert_eq!(ping.errors.get("blocked_user"), None);
+
+        let errors = metrics.errors();
+        assert_eq!(errors.get("only_for_guilds"), Some(&1));
+        assert_eq!(errors.get("blocked_user"), Some(&1));
+        assert_eq!(errors.get("argument_parse"), None);
+
+        metrics.reset();
+        assert!(metrics.commands().is_empty());
+        assert!(metrics.errors().is_empty());
+    }
+}
@@ -5,15 +5,27 @@ pub mod macros {
 
 mod args;
 mod configuration;
+mod convert;
+mod edits;
+mod localization;
+mod metrics;
 mod parse;
+mod registry;
 mod structures;
 
-pub use args::{Args, Delimiter, Error as ArgError, Iter, RawArguments};
-pub use configuration::{Configuration, WithWhiteSpace};
+pub use args::{Args, Delimiter, Error as ArgError, Iter, RawArguments, Rest};
+use args::ParameterError;
+pub use configuration::{Configuration, GuildSettings, WithWhiteSpace};
+use configuration::GuildConfig;
+pub use convert::{ArgumentConvert, ConvertError};
+pub use edits::EditTracker;
+pub use localization::{Localization, Translator};
+pub use metrics::{CommandMetrics, Histogram, Metrics, LATENCY_BOUNDS};
+pub use registry::CommandRegistry;
 pub use structures::*;
 
-use structures::buckets::{Bucket, Ratelimit};
-pub use structures::buckets::BucketBuilder;
+use structures::buckets::{Bucket, InFlightPermit, Ratelimit};
+pub use structures::buckets::{BucketBuilder, BucketScope};
 
 use parse::{ParseError, Invoke};
 use parse::map::{CommandMap, GroupMap, Map};
@@ -22,14 +34,18 @@ use super::Framework;
 use crate::client::Context;
 use crate::model::{
     channel::Message,
+    event::MessageUpdateEvent,
     permissions::Permissions,
 };
 
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::result::Result as StdResult;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use tokio::sync::Mutex;
+use tokio::time::delay_for;
 use futures::future::BoxFuture;
 use uwl::Stream;
 use async_trait::async_trait;
@@ -49,9 +65,22 @@ use crate::model::{guild::Role, id::RoleId};
 pub enum DispatchError {
     /// When a custom function check has failed.
     CheckFailed(&'static str, Reason),
-    /// When the command requester has exceeded a ratelimit bucket. The attached
-    /// value is the time a requester has to wait to run the command again.
-    Ratelimited(Duration),
+    /// When the command requester has exceeded a ratelimit bucket.
+    Ratelimited {
+        /// The time the requester has to wait to run the command again.
+        remaining: Duration,
+        /// What the exceeded ratelimit applies to, e.g. the requester or
+        /// their guild.
+        scope: BucketScope,
+    },
+    /// When the maximum number of concurrent invocations of a bucket or
+    /// command has been reached.
+    ConcurrencyLimited {
+        /// The maximum number of concurrent invocations.
+        max: u32,
+        /// What the maximum applies to, e.g. the requester or their guild.
+        scope: BucketScope,
+    },
     /// When the requested command is disabled in bot configuration.
     CommandDisabled(String),
     /// When the user is blocked in bot configuration.
@@ -76,6 +105,16 @@ pub enum DispatchError {
     NotEnoughArguments { min: u16, given: usize },
     /// When there are too many arguments.
     TooManyArguments { max: u16, given: usize },
+    /// When the argument of a typed parameter of the command is missing or
+    /// could not be parsed.
+    ArgumentParse {
+        /// The name of the parameter.
+        parameter: &'static str,
+        /// How the command is used, e.g. `<user> [amount] <reason...>`.
+        usage: &'static str,
+        /// Why the argument could not be parsed, or `None` if it is missing.
+        error: Option<String>,
+    },
     /// When the command was requested by a bot user when they are set to be
     /// ignored.
     IgnoredBot,
@@ -85,12 +124,12 @@ pub enum DispatchError {
     __Nonexhaustive,
 }
 
-type DispatchHook = for<'fut> fn(&'fut Context, &'fut Message, DispatchError) -> BoxFuture<'fut , ()>;
-type BeforeHook = for<'fut> fn(&'fut Context, &'fut Message, &'fut str) -> BoxFuture<'fut, bool>;
-type AfterHook = for<'fut> fn(&'fut Context, &'fut Message, &'fut str, Result<(), CommandError>) -> BoxFuture<'fut, ()>;
-type UnrecognisedHook = for<'fut> fn(&'fut Context, &'fut Message, &'fut str) -> BoxFuture<'fut, ()>;
-type NormalMessageHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()>;
-type PrefixOnlyHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()>;
+type DispatchHook = Arc<dyn for<'fut> Fn(&'fut Context, &'fut Message, DispatchError) -> BoxFuture<'fut , ()> + Send + Sync>;
+type BeforeHook = Arc<dyn for<'fut> Fn(&'fut Context, &'fut Message, &'fut str) -> BoxFuture<'fut, bool> + Send + Sync>;
+type AfterHook = Arc<dyn for<'fut> Fn(&'fut Context, &'fut Message, &'fut str, Result<(), CommandError>) -> BoxFuture<'fut, ()> + Send + Sync>;
+type UnrecognisedHook = Arc<dyn for<'fut> Fn(&'fut Context, &'fut Message, &'fut str) -> BoxFuture<'fut, ()> + Send + Sync>;
+type NormalMessageHook = Arc<dyn for<'fut> Fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()> + Send + Sync>;
+type PrefixOnlyHook = Arc<dyn for<'fut> Fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()> + Send + Sync>;
 
 /// A utility for easily managing dispatches to commands.
 ///
@@ -109,6 +148,10 @@ pub struct StandardFramework {
     prefix_only: Option<PrefixOnlyHook>,
     config: Configuration,
     help: Option<&'static HelpCommand>,
+    registry: CommandRegistry,
+    edits: EditTracker,
+    metrics: Metrics,
+    localization: Option<Localization>,
     /// Whether the framework has been "initialized".
     ///
     /// The framework is initialized once one of the following occurs:
@@ -198,6 +241,40 @@ impl StandardFramework {
     ///     .await;
     /// # }
     /// ```
+    ///
+    /// Queue a heavy command so that only one invocation per guild runs at a
+    /// time, telling the user when theirs has to wait:
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::standard::macros::{command, hook};
+    /// use serenity::framework::standard::{BucketScope, StandardFramework, CommandResult};
+    /// use serenity::model::channel::Message;
+    /// use serenity::prelude::*;
+    ///
+    /// #[command]
+    /// #[bucket = "render"]
+    /// async fn render() -> CommandResult {
+    ///     Ok(())
+    /// }
+    ///
+    /// #[hook]
+    /// async fn queued(ctx: &Context, msg: &Message) {
+    ///     let _ = msg.reply(ctx, "Your command has been queued.").await;
+    /// }
+    ///
+    /// # async fn run() {
+    /// let framework = StandardFramework::new()
+    ///     .bucket("render", |b| b
+    ///         .scope(BucketScope::Guild)
+    ///         .max_concurrency(1)
+    ///         .await_ratelimits(true)
+    ///         .delay_action(queued))
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// Ratelimits and the maximum concurrency are only applied once all other
+    /// requirements of a command, such as its checks, are met.
     #[inline]
     pub async fn bucket<F>(self, name: &str, f: F) -> Self
     where
@@ -211,6 +288,10 @@ impl StandardFramework {
             delay,
             time_span,
             limit,
+            scope,
+            max_concurrency,
+            await_ratelimits,
+            delay_action,
             check,
         } = builder;
 
@@ -221,7 +302,12 @@ impl StandardFramework {
                     delay,
                     limit: Some((time_span, limit)),
                 },
+                scope,
                 users: HashMap::new(),
+                max_concurrency,
+                in_flight: HashMap::new(),
+                await_ratelimits,
+                delay_action,
                 check,
             },
         );
@@ -241,13 +327,39 @@ impl StandardFramework {
         None
     }
 
+    /// The configuration for the message, with the settings of its guild
+    /// applied if they override the global ones.
+    async fn guild_config(&self, ctx: &Context, msg: &Message) -> GuildConfig<'_> {
+        let settings = match (&self.config.guild_settings, msg.guild_id) {
+            (Some(guild_settings), Some(guild_id)) => guild_settings(ctx, guild_id).await,
+            _ => None,
+        };
+
+        GuildConfig::new(&self.config, settings)
+    }
+
+    /// Passes an error preventing a message from being dispatched to the
+    /// `dispatch` hook, after recording it to the metrics, and to those of
+    /// the `command` if it has been recognised.
+    async fn dispatch_error(&self, ctx: &Context, msg: &Message, command: Option<&str>, error: DispatchError) {
+        self.metrics.record_error(command, &error);
+
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("error", error.name());
+
+        if let Some(dispatch) = &self.dispatch {
+            dispatch(ctx, msg, error).await;
+        }
+    }
+
     async fn should_fail<'a>(
         &'a self,
+        config: &'a GuildConfig<'_>,
         ctx: &'a Context,
         msg: &'a Message,
         args: &'a mut Args,
-        command: &'static CommandOptions,
-        group: &'static GroupOptions,
+        command: &'a CommandOptions,
+        group: &'a GroupOptions,
     ) -> Option<DispatchError> {
         if let Some(min) = command.min_args {
             if args.len() < min as usize {
@@ -268,12 +380,12 @@ impl StandardFramework {
         }
 
         if (group.owner_privilege && command.owner_privilege)
-            && self.config.owners.contains(&msg.author.id)
+            && config.owners.contains(&msg.author.id)
         {
             return None;
         }
 
-        if self.config.blocked_users.contains(&msg.author.id) {
+        if config.blocked_users().contains(&msg.author.id) {
             return Some(DispatchError::BlockedUser);
         }
 
@@ -282,51 +394,144 @@ impl StandardFramework {
             if let Some(Channel::Guild(channel)) = msg.channel_id.to_channel_cached(&ctx).await {
                 let guild_id = channel.guild_id;
 
-                if self.config.blocked_guilds.contains(&guild_id) {
+                if config.blocked_guilds.contains(&guild_id) {
                     return Some(DispatchError::BlockedGuild);
                 }
 
                 if let Some(guild) = guild_id.to_guild_cached(&ctx.cache).await {
-                    if self.config.blocked_users.contains(&guild.owner_id) {
+                    if config.blocked_users().contains(&guild.owner_id) {
                         return Some(DispatchError::BlockedGuild);
                     }
                 }
             }
         }
 
-        if !self.config.allowed_channels.is_empty() &&
-           !self.config.allowed_channels.contains(&msg.channel_id) {
+        if !config.allowed_channels().is_empty() &&
+           !config.allowed_channels().contains(&msg.channel_id) {
             return Some(DispatchError::BlockedChannel);
         }
 
-        {
+        for check in group.checks.iter().chain(command.checks.iter()) {
+            let res = check.function.call(ctx, msg, args, command).await;
+
+            if let CheckResult::Failure(r) = res {
+                return Some(DispatchError::CheckFailed(check.name, r));
+            }
+        }
+
+        None
+    }
+
+    /// Applies the bucket of a command, waiting if the bucket awaits its
+    /// ratelimits.
+    ///
+    /// Returns a permit to hold while the command runs if the bucket or the
+    /// command limits concurrency.
+    async fn take_bucket(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+        command: &CommandHandle,
+    ) -> StdResult<Option<InFlightPermit>, DispatchError> {
+        let command_name = command.name();
+        let command = command.options();
+
+        let name = match command.bucket {
+            Some(name) => name,
+            None => return Ok(None),
+        };
+
+        let check = match self.buckets.lock().await.get(name) {
+            Some(bucket) => bucket.check.clone(),
+            None => return Ok(None),
+        };
+
+        if let Some(check) = check {
+            if !check(ctx, msg.guild_id, msg.channel_id, msg.author.id).await {
+                return Ok(None);
+            }
+        }
+
+        let (scope, key, in_flight, await_ratelimits, delay_action) = {
             let mut buckets = self.buckets.lock().await;
+            let bucket = match buckets.get_mut(name) {
+                Some(bucket) => bucket,
+                None => return Ok(None),
+            };
+
+            let scope = bucket.scope;
+            let key = scope.key(msg.guild_id, msg.channel_id, msg.author.id);
+            let own_limit = command.max_concurrency.map(|max| (command_name, max));
+
+            (scope, key, bucket.in_flight(own_limit, key), bucket.await_ratelimits, bucket.delay_action.clone())
+        };
+
+        // Only notify about a delay once, however often the invocation waits.
+        let mut delayed = false;
+
+        let permit = match in_flight {
+            Some((max, in_flight_key, semaphore)) => {
+                let permit = match Arc::clone(&semaphore).try_acquire_owned() {
+                    Ok(permit) => permit,
+                    Err(_) if await_ratelimits => {
+                        delayed = true;
 
-            if let Some(ref mut bucket) = command.bucket.as_ref().and_then(|b| buckets.get_mut(*b)) {
-                let rate_limit = bucket.take(msg.author.id.0);
+                        if let Some(delay_action) = &delay_action {
+                            delay_action(ctx, msg).await;
+                        }
 
-                let apply = match bucket.check.as_ref() {
-                    Some(check) => (check)(ctx, msg.guild_id, msg.channel_id, msg.author.id).await,
-                    None => true,
+                        semaphore.acquire_owned().await
+                    },
+                    Err(_) => return Err(DispatchError::ConcurrencyLimited { max, scope }),
                 };
 
-                if let Some(rate_limit)= rate_limit {
-                    if apply {
-                        return Some(DispatchError::Ratelimited(rate_limit))
+                Some(InFlightPermit {
+                    bucket: name,
+                    key: in_flight_key,
+                    permit,
+                })
+            },
+            None => None,
+        };
+
+        loop {
+            let remaining = match self.buckets.lock().await.get_mut(name) {
+                Some(bucket) => bucket.take(key),
+                None => None,
+            };
+
+            match remaining {
+                Some(remaining) if await_ratelimits => {
+                    if !delayed {
+                        delayed = true;
+
+                        if let Some(delay_action) = &delay_action {
+                            delay_action(ctx, msg).await;
+                        }
                     }
-                }
-            }
-        }
 
-        for check in group.checks.iter().chain(command.checks.iter()) {
-            let res = (check.function)(ctx, msg, args, command).await;
+                    delay_for(remaining).await;
+                },
+                Some(remaining) => {
+                    self.release_bucket(permit).await;
 
-            if let CheckResult::Failure(r) = res {
-                return Some(DispatchError::CheckFailed(check.name, r));
+                    return Err(DispatchError::Ratelimited { remaining, scope });
+                },
+                None => return Ok(permit),
             }
         }
+    }
 
-        None
+    /// Hands back the permit returned by [`take_bucket`] once the command has
+    /// finished.
+    ///
+    /// [`take_bucket`]: #method.take_bucket
+    async fn release_bucket(&self, permit: Option<InFlightPermit>) {
+        if let Some(permit) = permit {
+            if let Some(bucket) = self.buckets.lock().await.get_mut(permit.bucket) {
+                bucket.release(permit);
+            }
+        }
     }
 
     /// Adds a group which can organize several related commands.
@@ -393,11 +598,11 @@ impl StandardFramework {
     pub fn group_add(&mut self, group: &'static CommandGroup) {
         let map = if group.options.prefixes.is_empty() {
             Map::Prefixless(
-                GroupMap::new(&group.options.sub_groups, &self.config),
-                CommandMap::new(&group.options.commands, &self.config),
+                GroupMap::new(&group.options.sub_groups),
+                CommandMap::new(&group.options.commands),
             )
         } else {
-            Map::WithPrefixes(GroupMap::new(&[group], &self.config))
+            Map::WithPrefixes(GroupMap::new(&[group]))
         };
 
         self.groups.push((group, map));
@@ -413,6 +618,61 @@ impl StandardFramework {
         self.groups.retain(|&(g, _)| g != group)
     }
 
+    /// A handle to register and unregister groups of commands constructed at
+    /// runtime, e.g. from configuration.
+    ///
+    /// # Examples
+    ///
+    /// Register a group with a tag command:
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::standard::{DynamicCommand, DynamicGroup, StandardFramework};
+    /// use serenity::futures::future::FutureExt;
+    ///
+    /// # async fn run() {
+    /// let framework = StandardFramework::new();
+    /// let registry = framework.registry();
+    ///
+    /// let mut group = DynamicGroup::new("tags");
+    /// group.command(DynamicCommand::new("ping", |ctx, msg, _args| async move {
+    ///     msg.channel_id.say(&ctx.http, "Pong!").await?;
+    ///
+    ///     Ok(())
+    /// }.boxed()));
+    ///
+    /// registry.register_group(group).await;
+    ///
+    /// // Later, e.g. from within a command.
+    /// registry.unregister_command("tags", "ping").await;
+    /// # }
+    /// ```
+    pub fn registry(&self) -> CommandRegistry {
+        self.registry.clone()
+    }
+
+    /// A handle to the messages whose edits are tracked, for commands to
+    /// edit their previous responses when they are run again.
+    ///
+    /// Refer to [`Configuration::track_edits`] for tracking edits.
+    ///
+    /// [`Configuration::track_edits`]: struct.Configuration.html#method.track_edits
+    pub fn edit_tracker(&self) -> EditTracker {
+        self.edits.clone()
+    }
+
+    /// A handle to the usage metrics of the commands, such as how often they
+    /// have been run, the errors preventing them from being run, and their
+    /// latencies.
+    ///
+    /// With the `tracing` feature enabled, each dispatch of a message is
+    /// additionally wrapped in a `dispatch` span, recording the name of the
+    /// command and the [`name`] of the error preventing it from being run.
+    ///
+    /// [`name`]: enum.DispatchError.html#method.name
+    pub fn metrics(&self) -> Metrics {
+        self.metrics.clone()
+    }
+
     /// Specify the function that's called in case a command wasn't executed for one reason or
     /// another.
     ///
@@ -449,15 +709,24 @@ impl StandardFramework {
     /// let framework = StandardFramework::new()
     ///     .on_dispatch_error(dispatch_error_hook);
     /// ```
-    pub fn on_dispatch_error(mut self, f: DispatchHook) -> Self {
-        self.dispatch = Some(f);
+    pub fn on_dispatch_error<F>(mut self, f: F) -> Self
+    where
+        F: for<'fut> Fn(&'fut Context, &'fut Message, DispatchError) -> BoxFuture<'fut, ()>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.dispatch = Some(Arc::new(f));
 
         self
     }
 
     /// Specify the function to be called on messages comprised of only the prefix.
-    pub fn prefix_only(mut self, f: PrefixOnlyHook) -> Self {
-        self.prefix_only = Some(f);
+    pub fn prefix_only<F>(mut self, f: F) -> Self
+    where
+        F: for<'fut> Fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()> + Send + Sync + 'static,
+    {
+        self.prefix_only = Some(Arc::new(f));
 
         self
     }
@@ -509,8 +778,33 @@ impl StandardFramework {
     /// let framework = StandardFramework::new()
     ///     .before(before_hook);
     /// ```
-    pub fn before(mut self, f: BeforeHook) -> Self {
-        self.before = Some(f);
+    ///
+    /// Like the other hooks, it may also be a closure returning a boxed
+    /// future, e.g. to count command usage with some state it captures:
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::StandardFramework;
+    /// use serenity::futures::future::FutureExt;
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let runs = Arc::new(AtomicUsize::new(0));
+    ///
+    /// let framework = StandardFramework::new()
+    ///     .before(move |_, _, _| {
+    ///         runs.fetch_add(1, Ordering::Relaxed);
+    ///
+    ///         async { true }.boxed()
+    ///     });
+    /// ```
+    pub fn before<F>(mut self, f: F) -> Self
+    where
+        F: for<'fut> Fn(&'fut Context, &'fut Message, &'fut str) -> BoxFuture<'fut, bool>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.before = Some(Arc::new(f));
 
         self
     }
@@ -518,6 +812,11 @@ impl StandardFramework {
     /// Specify the function to be called after every command's execution.
     /// Fourth argument exists if command returned an error which you can handle.
     ///
+    /// Not called if the arguments of a command's typed parameters could not be
+    /// parsed; that is reported to the [`on_dispatch_error`] hook instead.
+    ///
+    /// [`on_dispatch_error`]: #method.on_dispatch_error
+    ///
     /// # Examples
     ///
     /// Using `after` to log command usage:
@@ -540,8 +839,14 @@ impl StandardFramework {
     /// let framework = StandardFramework::new()
     ///     .after(after_hook);
     /// ```
-    pub fn after(mut self, f: AfterHook) -> Self {
-        self.after = Some(f);
+    pub fn after<F>(mut self, f: F) -> Self
+    where
+        F: for<'fut> Fn(&'fut Context, &'fut Message, &'fut str, Result<(), CommandError>) -> BoxFuture<'fut, ()>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.after = Some(Arc::new(f));
 
         self
     }
@@ -568,8 +873,14 @@ impl StandardFramework {
     /// let framework = StandardFramework::new()
     ///     .unrecognised_command(unrecognised_command_hook);
     /// ```
-    pub fn unrecognised_command(mut self, f: UnrecognisedHook) -> Self {
-        self.unrecognised_command = Some(f);
+    pub fn unrecognised_command<F>(mut self, f: F) -> Self
+    where
+        F: for<'fut> Fn(&'fut Context, &'fut Message, &'fut str) -> BoxFuture<'fut, ()>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.unrecognised_command = Some(Arc::new(f));
 
         self
     }
@@ -594,8 +905,11 @@ impl StandardFramework {
     /// let framework = StandardFramework::new()
     ///     .normal_message(normal_message_hook);
     /// ```
-    pub fn normal_message(mut self, f: NormalMessageHook) -> Self {
-        self.normal_message = Some(f);
+    pub fn normal_message<F>(mut self, f: F) -> Self
+    where
+        F: for<'fut> Fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()> + Send + Sync + 'static,
+    {
+        self.normal_message = Some(Arc::new(f));
 
         self
     }
@@ -610,11 +924,27 @@ impl StandardFramework {
 
         self
     }
+
+    /// Sets the hooks translating the texts of the framework into the locale
+    /// of an invocation.
+    ///
+    /// While the framework dispatches a message, these are used by the
+    /// [`Translator`] created via [`Translator::new`], such as by the
+    /// functions of [`help_commands`] and for [`DispatchError::describe`].
+    ///
+    /// [`Translator`]: struct.Translator.html
+    /// [`Translator::new`]: struct.Translator.html#method.new
+    /// [`help_commands`]: help_commands/index.html
+    /// [`DispatchError::describe`]: enum.DispatchError.html#method.describe
+    pub fn localization(mut self, localization: Localization) -> Self {
+        self.localization = Some(localization);
+
+        self
+    }
 }
 
-#[async_trait]
-impl Framework for StandardFramework {
-    async fn dispatch(&self, mut ctx: Context, msg: Message) {
+impl StandardFramework {
+    async fn dispatch_message(&self, mut ctx: Context, msg: Message) {
         let mut stream = Stream::new(&msg.content);
 
         stream.take_while_char(|c| c.is_whitespace());
@@ -638,19 +968,24 @@ impl Framework for StandardFramework {
         }
 
         if let Some(error) = self.should_fail_common(&msg) {
-            if let Some(dispatch) = &self.dispatch {
-                dispatch(&mut ctx, &msg, error).await;
-            }
+            self.dispatch_error(&ctx, &msg, None, error).await;
 
             return;
         }
 
+        if let (Some(window), Some(_)) = (self.config.track_edits, &prefix) {
+            self.edits.track(&msg, window).await;
+        }
+
+        let config = self.guild_config(&ctx, &msg).await;
+
         let invocation = parse::command(
             &ctx,
             &msg,
             &mut stream,
             &self.groups,
-            &self.config,
+            &self.registry.groups().await,
+            &config,
             self.help.as_ref().map(|h| h.options.names),
         ).await;
 
@@ -670,9 +1005,7 @@ impl Framework for StandardFramework {
                 return;
             }
             Err(ParseError::Dispatch(error)) => {
-                if let Some(dispatch) = &self.dispatch {
-                    dispatch(&mut ctx, &msg, error).await;
-                }
+                self.dispatch_error(&ctx, &msg, None, error).await;
 
                 return;
             }
@@ -680,9 +1013,12 @@ impl Framework for StandardFramework {
 
         match invoke {
             Invoke::Help(name) => {
-                let args = Args::new(stream.rest(), &self.config.delimiters);
+                #[cfg(feature = "tracing")]
+                tracing::Span::current().record("command", name);
 
-                let owners = self.config.owners.clone();
+                let args = Args::new(stream.rest(), &config.delimiters);
+
+                let owners = config.owners.clone();
                 let groups = self.groups.iter().map(|(g, _)| *g).collect::<Vec<_>>();
 
                 // `parse_command` promises to never return a help invocation if `StandardFramework::help` is `None`.
@@ -694,24 +1030,30 @@ impl Framework for StandardFramework {
                     }
                 }
 
+                let start = Instant::now();
                 let res = (help.fun)(&mut ctx, &msg, args, help.options, &groups, owners).await;
 
+                self.metrics.record(name, start.elapsed(), res.is_err());
+
                 if let Some(after) = &self.after {
                     after(&mut ctx, &msg, name, res).await;
                 }
             }
             Invoke::Command { command, group } => {
-                let mut args = {
-                    use std::borrow::Cow;
+                let name = command.name();
+
+                #[cfg(feature = "tracing")]
+                tracing::Span::current().record("command", name);
 
-                    let mut delims = Cow::Borrowed(&self.config.delimiters);
+                let mut args = {
+                    let mut delims = Cow::Borrowed(&config.delimiters);
 
                     // If user has configured the command's own delimiters, use those instead.
-                    if !command.options.delimiters.is_empty() {
+                    if !command.options().delimiters.is_empty() {
                         // FIXME: Get rid of this allocation.
-                        let mut v = Vec::with_capacity(command.options.delimiters.len());
+                        let mut v = Vec::with_capacity(command.options().delimiters.len());
 
-                        for delim in command.options.delimiters {
+                        for delim in command.options().delimiters {
                             if delim.len() == 1 {
                                 v.push(Delimiter::Single(delim.chars().next().unwrap()));
                             } else {
@@ -727,24 +1069,63 @@ impl Framework for StandardFramework {
                 };
 
                 if let Some(error) =
-                    self.should_fail(&mut ctx, &msg, &mut args, &command.options, &group.options).await
+                    self.should_fail(&config, &mut ctx, &msg, &mut args, command.options(), group.options()).await
                 {
-                    if let Some(dispatch) = &self.dispatch {
-                        dispatch(&mut ctx, &msg, error).await;
-                    }
+                    self.dispatch_error(&ctx, &msg, Some(name), error).await;
 
                     return;
                 }
 
-                let name = command.options.names[0].clone();
+                // Parse the typed parameters before anything is counted
+                // against the command.
+                let start = Instant::now();
+                let fun = match command.prepare(&ctx, &msg, args).await {
+                    Ok(fun) => fun,
+                    Err(error) => {
+                        // Counted as a failed run, not as a dispatch error.
+                        self.metrics.record(name, start.elapsed(), true);
+
+                        match error.downcast::<ParameterError>() {
+                            Ok(error) => {
+                                let ParameterError { parameter, usage, error } = *error;
+                                let error = DispatchError::ArgumentParse { parameter, usage, error };
+
+                                self.dispatch_error(&ctx, &msg, Some(name), error).await;
+                            },
+                            Err(error) => {
+                                if let Some(after) = &self.after {
+                                    after(&ctx, &msg, name, Err(error)).await;
+                                }
+                            },
+                        }
+
+                        return;
+                    },
+                };
+
+                // Held until the command has finished.
+                let permit = match self.take_bucket(&ctx, &msg, &command).await {
+                    Ok(permit) => permit,
+                    Err(error) => {
+                        self.dispatch_error(&ctx, &msg, Some(name), error).await;
+
+                        return;
+                    },
+                };
 
                 if let Some(before) = &self.before {
-                    if !before(&mut ctx, &msg, name).await {
+                    if !before(&ctx, &msg, name).await {
+                        self.release_bucket(permit).await;
+
                         return;
                     }
                 }
 
-                let res = (command.fun)(&mut ctx, &msg, args).await;
+                let start = Instant::now();
+                let res = fun.await;
+
+                self.release_bucket(permit).await;
+                self.metrics.record(name, start.elapsed(), res.is_err());
 
                 if let Some(after) = &self.after {
                     after(&mut ctx, &msg, name, res).await;
@@ -754,6 +1135,59 @@ impl Framework for StandardFramework {
     }
 }
 
+#[async_trait]
+impl Framework for StandardFramework {
+    async fn dispatch(&self, ctx: Context, msg: Message) {
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "dispatch",
+            message_id = %msg.id,
+            author_id = %msg.author.id,
+            command = tracing::field::Empty,
+            error = tracing::field::Empty,
+        );
+
+        let dispatch = self.dispatch_message(ctx, msg);
+
+        #[cfg(feature = "tracing")]
+        let dispatch = tracing::Instrument::instrument(dispatch, span);
+
+        match &self.localization {
+            Some(localization) => localization.clone().scope(dispatch).await,
+            None => dispatch.await,
+        }
+    }
+
+    async fn dispatch_edit(&self, ctx: Context, event: MessageUpdateEvent) {
+        let window = match self.config.track_edits {
+            Some(window) => window,
+            None => return,
+        };
+
+        if !self.edits.edit(&event, window).await {
+            return;
+        }
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(mut msg) = ctx.cache.message(event.channel_id, event.id).await {
+                // The cache may not have applied the edit yet.
+                edits::apply(&mut msg, &event);
+
+                return self.dispatch(ctx, msg).await;
+            }
+        }
+
+        if let Ok(msg) = event.channel_id.message(&ctx.http, event.id).await {
+            self.dispatch(ctx, msg).await;
+        }
+    }
+
+    fn tracks_edits(&self) -> bool {
+        self.config.track_edits.is_some()
+    }
+}
+
 pub trait CommonOptions {
     fn required_permissions(&self) -> &Permissions;
     fn allowed_roles(&self) -> &'static [&'static str];
@@ -856,3 +1290,316 @@ pub(crate) fn has_correct_roles(
             .any(|g| member.roles.contains(&g.id))
     }
 }
+
+#[cfg(all(test, feature = "cache", feature = "gateway"))]
+mod test {
+    use super::*;
+    use crate::http::Http;
+    use crate::model::id::{GuildId, MessageId};
+    use crate::utils::CustomMessage;
+    use futures::channel::mpsc;
+    use futures::future::FutureExt;
+    use std::sync::Mutex as StdMutex;
+    use tokio::sync::{Notify, RwLock};
+    use typemap_rev::TypeMap;
+
+    /// A context with an empty cache, whose HTTP requests fail without being
+    /// sent to Discord.
+    pub(super) fn context() -> Context {
+        let (tx, _) = mpsc::unbounded();
+        let client = reqwest::Client::builder()
+            .proxy(reqwest::Proxy::all("http://127.0.0.1:1").unwrap())
+            .build()
+            .unwrap();
+
+        Context::new(
+            Arc::new(RwLock::new(TypeMap::new())),
+            tx,
+            0,
+            Arc::new(Http::new(Arc::new(client), "Bot token")),
+            Arc::new(Cache::new()),
+        )
+    }
+
+    fn message(content: &str) -> Message {
+        let mut msg = CustomMessage::new();
+        msg.content(content);
+
+        msg.build()
+    }
+
+    fn guild_message(guild_id: u64, content: &str) -> Message {
+        let mut msg = CustomMessage::new();
+        msg.content(content).guild_id(GuildId(guild_id));
+
+        msg.build()
+    }
+
+    /// A framework with the prefix `!`, recording the names of the dispatch
+    /// errors.
+    fn framework() -> (StandardFramework, Arc<StdMutex<Vec<&'static str>>>) {
+        let errors = Arc::new(StdMutex::new(Vec::new()));
+        let recorded = Arc::clone(&errors);
+
+        let framework = StandardFramework::new()
+            .configure(|c| c.prefix("!"))
+            .on_dispatch_error(move |_, _, error| {
+                recorded.lock().unwrap().push(error.name());
+
+                async {}.boxed()
+            });
+
+        (framework, errors)
+    }
+
+    #[tokio::test]
+    async fn bucket_concurrency() {
+        let started = Arc::new(Notify::new());
+        let finish = Arc::new(Notify::new());
+
+        let (framework, errors) = framework();
+        let framework = Arc::new(framework.bucket("heavy", |b| b.max_concurrency(1)).await);
+
+        let mut command = {
+            let started = Arc::clone(&started);
+            let finish = Arc::clone(&finish);
+
+            DynamicCommand::new("heavy", move |_, _, _| {
+                let started = Arc::clone(&started);
+                let finish = Arc::clone(&finish);
+
+                async move {
+                    started.notify();
+                    finish.notified().await;
+
+                    Ok(())
+                }.boxed()
+            })
+        };
+        command.options.bucket = Some("heavy");
+
+        let mut group = DynamicGroup::new("test");
+        group.command(command);
+        framework.registry().register_group(group).await;
+
+        let first = {
+            let framework = Arc::clone(&framework);
+
+            tokio::spawn(async move { framework.dispatch(context(), message("!heavy")).await })
+        };
+        started.notified().await;
+
+        framework.dispatch(context(), message("!heavy")).await;
+        assert_eq!(*errors.lock().unwrap(), vec!["concurrency_limited"]);
+
+        finish.notify();
+        first.await.unwrap();
+        assert!(framework.buckets.lock().await["heavy"].in_flight.is_empty());
+
+        // The pool is created anew for the next invocation.
+        finish.notify();
+        framework.dispatch(context(), message("!heavy")).await;
+        assert_eq!(errors.lock().unwrap().len(), 1);
+        assert!(framework.buckets.lock().await["heavy"].in_flight.is_empty());
+    }
+
+    // What `#[command]` generates for `async fn amount(ctx, msg, amount: u32)`.
+    fn amount_prepare<'fut>(
+        ctx: &'fut Context,
+        msg: &'fut Message,
+        mut args: Args,
+    ) -> BoxFuture<'fut, CommandResult<BoxFuture<'fut, CommandResult>>> {
+        async move {
+            let amount: u32 = args.__parameter(ctx, msg, "amount", "<amount>").await?;
+
+            Ok(async move {
+                assert_eq!(amount, 5);
+
+                Ok(())
+            }.boxed())
+        }.boxed()
+    }
+
+    fn amount<'fut>(ctx: &'fut Context, msg: &'fut Message, args: Args) -> BoxFuture<'fut, CommandResult> {
+        async move { amount_prepare(ctx, msg, args).await?.await }.boxed()
+    }
+
+    static AMOUNT_OPTIONS: CommandOptions = CommandOptions {
+        checks: &[],
+        bucket: Some("limited"),
+        max_concurrency: None,
+        names: &["amount"],
+        desc: None,
+        delimiters: &[],
+        usage: Some("<amount>"),
+        examples: &[],
+        min_args: Some(1),
+        max_args: Some(1),
+        allowed_roles: &[],
+        required_permissions: Permissions::empty(),
+        help_available: true,
+        only_in: OnlyIn::None,
+        owners_only: false,
+        owner_privilege: true,
+        sub_commands: &[],
+        default_sub_command: None,
+    };
+
+    static AMOUNT_COMMAND: Command = Command {
+        fun: amount,
+        prepare: Some(amount_prepare),
+        options: &AMOUNT_OPTIONS,
+    };
+
+    static TEST_OPTIONS: GroupOptions = GroupOptions {
+        prefixes: &[],
+        only_in: OnlyIn::None,
+        owners_only: false,
+        owner_privilege: true,
+        help_available: true,
+        allowed_roles: &[],
+        required_permissions: Permissions::empty(),
+        checks: &[],
+        default_command: None,
+        description: None,
+        commands: &[&AMOUNT_COMMAND],
+        sub_groups: &[],
+    };
+
+    static TEST_GROUP: CommandGroup = CommandGroup {
+        name: "test",
+        options: &TEST_OPTIONS,
+    };
+
+    #[tokio::test]
+    async fn argument_parse() {
+        let befores = Arc::new(StdMutex::new(0));
+        let counted = Arc::clone(&befores);
+
+        let (framework, errors) = framework();
+        let framework = framework
+            .bucket("limited", |b| b.limit(1).time_span(60))
+            .await
+            .group(&TEST_GROUP)
+            .before(move |_, _, _| {
+                *counted.lock().unwrap() += 1;
+
+                async { true }.boxed()
+            });
+
+        // Neither the `before` hook nor the bucket see the invalid argument.
+        framework.dispatch(context(), message("!amount five")).await;
+        assert_eq!(*errors.lock().unwrap(), vec!["argument_parse"]);
+        assert_eq!(*befores.lock().unwrap(), 0);
+
+        framework.dispatch(context(), message("!amount 5")).await;
+        assert_eq!(errors.lock().unwrap().len(), 1);
+        assert_eq!(*befores.lock().unwrap(), 1);
+
+        framework.dispatch(context(), message("!amount 5")).await;
+        assert_eq!(*errors.lock().unwrap(), vec!["argument_parse", "ratelimited"]);
+
+        // The invalid argument is counted once, as a failed run.
+        let metrics = framework.metrics();
+        let amount = metrics.command("amount").unwrap();
+        assert_eq!((amount.invocations, amount.failures), (2, 1));
+        assert_eq!(amount.errors.len(), 1);
+        assert_eq!(amount.errors.get("ratelimited"), Some(&1));
+        assert_eq!(metrics.errors().get("argument_parse"), None);
+    }
+
+    #[tokio::test]
+    async fn guild_settings() {
+        let ran = Arc::new(StdMutex::new(Vec::new()));
+
+        let (framework, errors) = framework();
+        let framework = framework.configure(|c| c
+            .dynamic_prefix(|_, msg| async move {
+                if msg.guild_id == Some(GuildId(2)) {
+                    Some("?".to_string())
+                } else {
+                    None
+                }
+            }.boxed())
+            .guild_settings(|_, guild_id| async move {
+                if guild_id != GuildId(1) {
+                    return None;
+                }
+
+                let mut settings = GuildSettings::default();
+                settings
+                    .case_insensitivity(true)
+                    .disabled_commands(vec!["pong".to_string()].into_iter().collect());
+
+                Some(settings)
+            }.boxed()));
+
+        let mut group = DynamicGroup::new("test");
+        for &name in &["ping", "pong"] {
+            let ran = Arc::clone(&ran);
+
+            group.command(DynamicCommand::new(name, move |_, msg, _| {
+                let ran = Arc::clone(&ran);
+                let guild_id = msg.guild_id.map_or(0, |id| id.0);
+
+                async move {
+                    ran.lock().unwrap().push((name.to_string(), guild_id));
+
+                    Ok(())
+                }.boxed()
+            }));
+        }
+        framework.registry().register_group(group).await;
+
+        // The prefix of the guild.
+        framework.dispatch(context(), guild_message(2, "?ping")).await;
+        framework.dispatch(context(), guild_message(3, "?ping")).await;
+
+        // The case insensitivity of the guild.
+        framework.dispatch(context(), guild_message(1, "!PING")).await;
+        framework.dispatch(context(), guild_message(3, "!PING")).await;
+
+        // The disabled commands of the guild.
+        framework.dispatch(context(), guild_message(1, "!pong")).await;
+        framework.dispatch(context(), guild_message(3, "!pong")).await;
+
+        assert_eq!(*ran.lock().unwrap(), vec![
+            ("ping".to_string(), 2),
+            ("ping".to_string(), 1),
+            ("pong".to_string(), 3),
+        ]);
+        assert_eq!(*errors.lock().unwrap(), vec!["command_disabled"]);
+    }
+
+    #[tokio::test]
+    async fn track_edits() {
+        let (framework, _) = framework();
+        let framework = framework.configure(|c| c.track_edits(std::time::Duration::from_secs(60)));
+        let tracker = framework.edit_tracker();
+
+        let mut bot = crate::model::user::User::default();
+        bot.bot = true;
+
+        let mut from_bot = CustomMessage::new();
+        from_bot.id(MessageId(1)).author(bot).content("!ping");
+        let from_bot = from_bot.build();
+
+        let mut unprefixed = CustomMessage::new();
+        unprefixed.id(MessageId(2)).content("ping");
+        let unprefixed = unprefixed.build();
+
+        let mut prefixed = CustomMessage::new();
+        prefixed.id(MessageId(3)).content("!ping");
+        let prefixed = prefixed.build();
+
+        // Responses are only recorded for tracked messages.
+        for msg in &[&from_bot, &unprefixed, &prefixed] {
+            framework.dispatch(context(), (*msg).clone()).await;
+            tracker.track_response(msg, msg).await;
+        }
+
+        assert!(tracker.responses(&from_bot).await.is_empty());
+        assert!(tracker.responses(&unprefixed).await.is_empty());
+        assert_eq!(tracker.responses(&prefixed).await.len(), 1);
+    }
+}
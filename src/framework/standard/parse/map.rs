@@ -9,10 +9,27 @@ pub enum Map {
     Prefixless(GroupMap, CommandMap),
 }
 
+impl Map {
+    /// Creates the map of a group registered at runtime.
+    pub fn new_dynamic(group: &Arc<DynamicGroup>) -> Self {
+        let commands = CommandMap::new_dynamic(&group.commands);
+
+        if group.prefixes.is_empty() {
+            Map::Prefixless(GroupMap::default(), commands)
+        } else {
+            let mut map = GroupMap::default();
+            let handle = GroupHandle::Dynamic(Arc::clone(group));
+            map.insert(handle, &group.prefixes, Arc::default(), Arc::new(commands));
+
+            Map::WithPrefixes(map)
+        }
+    }
+}
+
 pub trait ParseMap {
     type Storage;
 
-    fn get(&self, n: &str) -> Option<Self::Storage>;
+    fn get(&self, n: &str, case_insensitive: bool) -> Option<Self::Storage>;
     fn min_length(&self) -> usize;
     fn max_length(&self) -> usize;
     fn is_empty(&self) -> bool;
@@ -20,39 +37,61 @@ pub trait ParseMap {
 
 #[derive(Debug, Default)]
 pub struct CommandMap {
-    cmds: HashMap<String, (&'static Command, Arc<CommandMap>)>,
+    cmds: HashMap<String, (CommandHandle, Arc<CommandMap>)>,
+    /// The names of the commands by their lowercase forms, for looking them
+    /// up case-insensitively.
+    lowercase: HashMap<String, String>,
     min_length: usize,
     max_length: usize,
 }
 
 impl CommandMap {
-    pub fn new(cmds: &[&'static Command], conf: &Configuration) -> Self {
+    pub fn new(cmds: &[&'static Command]) -> Self {
         let mut map = Self::default();
 
         for cmd in cmds {
-            let sub_map = Arc::new(Self::new(&cmd.options.sub_commands, conf));
+            let sub_map = Arc::new(Self::new(&cmd.options.sub_commands));
+
+            map.insert(CommandHandle::Static(cmd), sub_map);
+        }
 
-            for name in cmd.options.names {
-                let len = name.chars().count();
-                map.min_length = std::cmp::min(len, map.min_length);
-                map.max_length = std::cmp::max(len, map.max_length);
+        map
+    }
+
+    /// Creates a map of commands registered at runtime.
+    pub fn new_dynamic(cmds: &[Arc<DynamicCommand>]) -> Self {
+        let mut map = Self::default();
+
+        for cmd in cmds {
+            let mut sub_map = Self::new_dynamic(&cmd.sub_commands);
 
-                let name = if conf.case_insensitive {
-                    name.to_lowercase()
-                } else {
-                    name.to_string()
-                };
+            for sub_command in cmd.options.sub_commands {
+                let sub_sub_map = Arc::new(Self::new(sub_command.options.sub_commands));
 
-                map.cmds.insert(name, (*cmd, sub_map.clone()));
+                sub_map.insert(CommandHandle::Static(sub_command), sub_sub_map);
             }
+
+            map.insert(CommandHandle::Dynamic(Arc::clone(cmd)), Arc::new(sub_map));
         }
 
         map
     }
+
+    /// Registers a command under all of its names.
+    pub fn insert(&mut self, cmd: CommandHandle, sub_map: Arc<CommandMap>) {
+        for name in cmd.names() {
+            let len = name.chars().count();
+            self.min_length = std::cmp::min(len, self.min_length);
+            self.max_length = std::cmp::max(len, self.max_length);
+
+            self.lowercase.insert(name.to_lowercase(), name.to_string());
+            self.cmds.insert(name.to_string(), (cmd.clone(), sub_map.clone()));
+        }
+    }
 }
 
 impl ParseMap for CommandMap {
-    type Storage = (&'static Command, Arc<CommandMap>);
+    type Storage = (CommandHandle, Arc<CommandMap>);
 
     #[inline]
     fn min_length(&self) -> usize {
@@ -65,7 +104,13 @@ impl ParseMap for CommandMap {
     }
 
     #[inline]
-    fn get(&self, name: &str) -> Option<Self::Storage> {
+    fn get(&self, name: &str, case_insensitive: bool) -> Option<Self::Storage> {
+        let name = if case_insensitive {
+            self.lowercase.get(name)?
+        } else {
+            name
+        };
+
         self.cmds.get(name).cloned()
     }
 
@@ -77,34 +122,46 @@ impl ParseMap for CommandMap {
 
 #[derive(Debug, Default)]
 pub struct GroupMap {
-    groups: HashMap<&'static str, (&'static CommandGroup, Arc<GroupMap>, Arc<CommandMap>)>,
+    groups: HashMap<String, (GroupHandle, Arc<GroupMap>, Arc<CommandMap>)>,
     min_length: usize,
     max_length: usize,
 }
 
 impl GroupMap {
-    pub fn new(groups: &[&'static CommandGroup], conf: &Configuration) -> Self {
+    pub fn new(groups: &[&'static CommandGroup]) -> Self {
         let mut map = Self::default();
 
         for group in groups {
-            let subgroups_map = Arc::new(Self::new(&group.options.sub_groups, conf));
-            let commands_map = Arc::new(CommandMap::new(&group.options.commands, conf));
+            let subgroups_map = Arc::new(Self::new(&group.options.sub_groups));
+            let commands_map = Arc::new(CommandMap::new(&group.options.commands));
 
-            for prefix in group.options.prefixes {
-                let len = prefix.chars().count();
-                map.min_length = std::cmp::min(len, map.min_length);
-                map.max_length = std::cmp::max(len, map.max_length);
-
-                map.groups.insert(*prefix, (*group, subgroups_map.clone(), commands_map.clone()));
-            }
+            map.insert(GroupHandle::Static(group), group.options.prefixes, subgroups_map, commands_map);
         }
 
         map
     }
+
+    /// Registers a group under all of its prefixes.
+    pub fn insert<S: AsRef<str>>(
+        &mut self,
+        group: GroupHandle,
+        prefixes: &[S],
+        subgroups_map: Arc<GroupMap>,
+        commands_map: Arc<CommandMap>,
+    ) {
+        for prefix in prefixes {
+            let prefix = prefix.as_ref();
+            let len = prefix.chars().count();
+            self.min_length = std::cmp::min(len, self.min_length);
+            self.max_length = std::cmp::max(len, self.max_length);
+
+            self.groups.insert(prefix.to_string(), (group.clone(), subgroups_map.clone(), commands_map.clone()));
+        }
+    }
 }
 
 impl ParseMap for GroupMap {
-    type Storage = (&'static CommandGroup, Arc<GroupMap>, Arc<CommandMap>);
+    type Storage = (GroupHandle, Arc<GroupMap>, Arc<CommandMap>);
 
     #[inline]
     fn min_length(&self) -> usize {
@@ -117,8 +174,8 @@ impl ParseMap for GroupMap {
     }
 
     #[inline]
-    fn get(&self, name: &str) -> Option<Self::Storage> {
-        self.groups.get(&name).cloned()
+    fn get(&self, name: &str, _: bool) -> Option<Self::Storage> {
+        self.groups.get(name).cloned()
     }
 
     #[inline]
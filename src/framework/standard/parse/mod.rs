@@ -11,8 +11,8 @@ use std::borrow::Cow;
 use futures::future::{BoxFuture, FutureExt};
 
 #[inline]
-fn to_lowercase<'a>(config: &Configuration, s: &'a str) -> Cow<'a, str> {
-    if config.case_insensitive {
+fn to_lowercase(case_insensitive: bool, s: &str) -> Cow<'_, str> {
+    if case_insensitive {
         Cow::Owned(s.to_lowercase())
     } else {
         Cow::Borrowed(s)
@@ -62,7 +62,7 @@ async fn find_prefix<'a>(
 ) -> Option<Cow<'a, str>> {
     let try_match = |prefix: &str| {
         let peeked = stream.peek_for_char(prefix.chars().count());
-        let peeked = to_lowercase(config, peeked);
+        let peeked = to_lowercase(config.case_insensitive, peeked);
 
         if prefix == &peeked {
             Some(peeked)
@@ -73,7 +73,7 @@ async fn find_prefix<'a>(
 
     for f in &config.dynamic_prefixes {
         if let Some(p) = f(ctx, msg).await {
-            let p = to_lowercase(config, &p);
+            let p = to_lowercase(config.case_insensitive, &p);
             if let Some(p) = try_match(&p) {
                 return Some(p);
             }
@@ -125,7 +125,7 @@ async fn check_discrepancy(
     #[allow(unused_variables)]
     ctx: &Context,
     msg: &Message,
-    config: &Configuration,
+    config: &GuildConfig<'_>,
     options: &impl CommonOptions,
 ) -> Result<(), DispatchError> {
     if options.owners_only() && !config.owners.contains(&msg.author.id) {
@@ -172,13 +172,13 @@ async fn check_discrepancy(
 fn try_parse<M: ParseMap>(
     stream: &mut Stream<'_>,
     map: &M,
-    by_space: bool,
+    config: &GuildConfig<'_>,
     f: impl Fn(&str) -> String,
 ) -> (String, Option<M::Storage>) {
-    if by_space {
+    if config.by_space {
         let n = f(stream.peek_until_char(|c| c.is_whitespace()));
 
-        let o = map.get(&n);
+        let o = map.get(&n, config.case_insensitive());
 
         (n, o)
     } else {
@@ -186,7 +186,7 @@ fn try_parse<M: ParseMap>(
         let mut o = None;
 
         for _ in 0..(map.max_length() - map.min_length()) {
-            o = map.get(&n);
+            o = map.get(&n, config.case_insensitive());
 
             if o.is_some() {
                 break;
@@ -203,15 +203,15 @@ fn parse_cmd<'a>(
     stream: &'a mut Stream<'_>,
     ctx: &'a Context,
     msg: &'a Message,
-    config: &'a Configuration,
+    config: &'a GuildConfig<'_>,
     map: &'a CommandMap,
-) -> BoxFuture<'a, Result<&'static Command, ParseError>> {
+) -> BoxFuture<'a, Result<CommandHandle, ParseError>> {
     async move {
-        let (n, r) = try_parse(stream, map, config.by_space, |s| {
-            to_lowercase(config, s).into_owned()
+        let (n, r) = try_parse(stream, map, config, |s| {
+            to_lowercase(config.case_insensitive(), s).into_owned()
         });
 
-        if config.disabled_commands.contains(&n) {
+        if config.disabled_commands().contains(&n) {
             return Err(ParseError::Dispatch(DispatchError::CommandDisabled(n)));
         }
 
@@ -222,16 +222,23 @@ fn parse_cmd<'a>(
                 stream.take_while_char(|c| c.is_whitespace());
             }
 
-            check_discrepancy(ctx, msg, config, &cmd.options).await?;
+            check_discrepancy(ctx, msg, config, &cmd.options()).await?;
 
-            if map.is_empty() {
-                return Ok(cmd);
+            if !map.is_empty() {
+                match parse_cmd(stream, ctx, msg, config, &map).await {
+                    Err(ParseError::UnrecognisedCommand(Some(_))) => (),
+                    res => return res,
+                }
             }
 
-            return match parse_cmd(stream, ctx, msg, config, &map).await {
-                Err(ParseError::UnrecognisedCommand(Some(_))) => Ok(cmd),
-                res => res,
-            };
+            // Run in place of the command if none of its sub commands matched.
+            if let Some(default) = cmd.options().default_sub_command {
+                check_discrepancy(ctx, msg, config, &default.options).await?;
+
+                return Ok(CommandHandle::Static(default));
+            }
+
+            return Ok(cmd);
         }
 
         Err(ParseError::UnrecognisedCommand(Some(n.to_string())))
@@ -242,11 +249,11 @@ fn parse_group<'a>(
     stream: &'a mut Stream<'_>,
     ctx: &'a Context,
     msg: &'a Message,
-    config: &'a Configuration,
+    config: &'a GuildConfig<'_>,
     map: &'a GroupMap,
-) -> BoxFuture<'a, Result<(&'static CommandGroup, Arc<CommandMap>), ParseError>> {
+) -> BoxFuture<'a, Result<(GroupHandle, Arc<CommandMap>), ParseError>> {
     async move {
-        let (n, o) = try_parse(stream, map, config.by_space, ToString::to_string);
+        let (n, o) = try_parse(stream, map, config, ToString::to_string);
 
         if let Some((group, map, commands)) = o {
             stream.increment(n.len());
@@ -255,7 +262,7 @@ fn parse_group<'a>(
                 stream.take_while_char(|c| c.is_whitespace());
             }
 
-            check_discrepancy(ctx, msg, config, &group.options).await?;
+            check_discrepancy(ctx, msg, config, &group.options()).await?;
 
             if map.is_empty() {
                 return Ok((group, commands));
@@ -276,14 +283,14 @@ async fn handle_command<'a>(
     stream: &'a mut Stream<'_>,
     ctx: &'a Context,
     msg: &'a Message,
-    config: &'a Configuration,
+    config: &'a GuildConfig<'_>,
     map: &'a CommandMap,
-    group: &'static CommandGroup,
+    group: GroupHandle,
 ) -> Result<Invoke, ParseError> {
     match parse_cmd(stream, ctx, msg, config, map).await {
         Ok(command) => Ok(Invoke::Command { group, command }),
-        Err(err) => match group.options.default_command {
-            Some(command) => Ok(Invoke::Command { group, command }),
+        Err(err) => match group.options().default_command {
+            Some(command) => Ok(Invoke::Command { group, command: CommandHandle::Static(command) }),
             None => Err(err),
         },
     }
@@ -294,7 +301,7 @@ async fn handle_group<'a>(
     stream: &mut Stream<'_>,
     ctx: &'a Context,
     msg: &'a Message,
-    config: &'a Configuration,
+    config: &'a GuildConfig<'_>,
     map: &'a GroupMap,
 ) -> Result<Invoke, ParseError> {
     match parse_group(stream, ctx, msg, config, map).await {
@@ -325,18 +332,22 @@ impl From<DispatchError> for ParseError {
 ///
 /// 2. A command defined under another command or a group, which may also belong to another group and so on.
 /// To invoke this command, all names and prefixes of its parent commands and groups must be specified before it.
+/// If none of the sub commands of a command is specified, its default sub command is invoked instead, if it has one.
+///
+/// The groups registered at runtime are tried after the static groups.
 pub async fn command(
     ctx: &Context,
     msg: &Message,
     stream: &mut Stream<'_>,
     groups: &[(&'static CommandGroup, Map)],
-    config: &Configuration,
+    dynamic_groups: &[(Arc<DynamicGroup>, Arc<Map>)],
+    config: &GuildConfig<'_>,
     help_was_set: Option<&[&'static str]>,
 ) -> Result<Invoke, ParseError> {
     // Precedence is taken over commands named as one of the help names.
     if let Some(names) = help_was_set {
         for name in names {
-            let n = to_lowercase(config, stream.peek_for_char(name.chars().count()));
+            let n = to_lowercase(config.case_insensitive(), stream.peek_for_char(name.chars().count()));
 
             if name == &n {
                 stream.increment(n.len());
@@ -351,6 +362,11 @@ pub async fn command(
     let mut last = Err(ParseError::UnrecognisedCommand(None));
     let mut is_prefixless = false;
 
+    let groups = groups
+        .iter()
+        .map(|(group, map)| (GroupHandle::Static(group), map))
+        .chain(dynamic_groups.iter().map(|(group, map)| (GroupHandle::Dynamic(Arc::clone(group)), &**map)));
+
     for (group, map) in groups {
         match map {
             // Includes [group] itself.
@@ -371,15 +387,15 @@ pub async fn command(
                 let res = handle_group(stream, ctx, msg, config, subgroups).await;
 
                 if res.is_ok() {
-                    check_discrepancy(ctx, msg, config, &group.options).await?;
+                    check_discrepancy(ctx, msg, config, &group.options()).await?;
 
                     return res;
                 }
 
-                let res = handle_command(stream, ctx, msg, config, commands, group).await;
+                let res = handle_command(stream, ctx, msg, config, commands, group.clone()).await;
 
                 if res.is_ok() {
-                    check_discrepancy(ctx, msg, config, &group.options).await?;
+                    check_discrepancy(ctx, msg, config, &group.options()).await?;
 
                     return res;
                 }
@@ -395,8 +411,8 @@ pub async fn command(
 #[derive(Debug)]
 pub enum Invoke {
     Command {
-        group: &'static CommandGroup,
-        command: &'static Command,
+        group: GroupHandle,
+        command: CommandHandle,
     },
     Help(&'static str),
 }
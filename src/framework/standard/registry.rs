@@ -0,0 +1,192 @@
+use super::parse::map::Map;
+use super::{DynamicCommand, DynamicGroup};
+
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Default)]
+struct Registered {
+    groups: Vec<(Arc<DynamicGroup>, Arc<Map>)>,
+}
+
+impl Registered {
+    fn insert(&mut self, group: Arc<DynamicGroup>) {
+        let map = Arc::new(Map::new_dynamic(&group));
+
+        match self.groups.iter_mut().find(|(g, _)| g.name == group.name) {
+            Some(entry) => *entry = (group, map),
+            None => self.groups.push((group, map)),
+        }
+    }
+}
+
+/// A handle to the groups of the [`StandardFramework`] that are registered at
+/// runtime, created via [`StandardFramework::registry`].
+///
+/// The handle can be cloned and kept around, e.g. in the `Client`'s data, to
+/// register and unregister groups and commands while the framework is
+/// running. The registered groups are tried after the groups added via
+/// [`StandardFramework::group`], and are not listed by the help command.
+///
+/// [`StandardFramework`]: struct.StandardFramework.html
+/// [`StandardFramework::group`]: struct.StandardFramework.html#method.group
+/// [`StandardFramework::registry`]: struct.StandardFramework.html#method.registry
+#[derive(Clone, Default)]
+pub struct CommandRegistry {
+    inner: Arc<RwLock<Registered>>,
+}
+
+impl CommandRegistry {
+    /// Registers a group, replacing the group with the same name.
+    pub async fn register_group(&self, group: DynamicGroup) {
+        self.inner.write().await.insert(Arc::new(group));
+    }
+
+    /// Unregisters the group named `name`, returning whether it was
+    /// registered.
+    pub async fn unregister_group(&self, name: &str) -> bool {
+        let mut inner = self.inner.write().await;
+        let len = inner.groups.len();
+        inner.groups.retain(|(g, _)| g.name != name);
+
+        inner.groups.len() != len
+    }
+
+    /// Registers a command in the group named `group`, replacing the command
+    /// with the same name.
+    ///
+    /// Returns `false` if the group is not registered.
+    pub async fn register_command(&self, group: &str, command: DynamicCommand) -> bool {
+        self.update_group(group, |commands| {
+            commands.retain(|c| c.name() != command.name());
+            commands.push(Arc::new(command));
+        }).await
+    }
+
+    /// Unregisters the command named `name` from the group named `group`.
+    ///
+    /// Returns `false` if the group or the command is not registered.
+    pub async fn unregister_command(&self, group: &str, name: &str) -> bool {
+        let mut removed = false;
+
+        let found = self.update_group(group, |commands| {
+            let len = commands.len();
+            commands.retain(|c| c.name() != name);
+
+            removed = commands.len() != len;
+        }).await;
+
+        found && removed
+    }
+
+    /// The names of the registered groups.
+    pub async fn group_names(&self) -> Vec<String> {
+        self.inner.read().await.groups.iter().map(|(g, _)| g.name.clone()).collect()
+    }
+
+    async fn update_group<F>(&self, name: &str, f: F) -> bool
+    where
+        F: FnOnce(&mut Vec<Arc<DynamicCommand>>),
+    {
+        let mut inner = self.inner.write().await;
+
+        let group = match inner.groups.iter().find(|(g, _)| g.name == name) {
+            Some((group, _)) => group,
+            None => return false,
+        };
+
+        let mut commands = group.commands.clone();
+        f(&mut commands);
+
+        let group = DynamicGroup {
+            name: group.name.clone(),
+            prefixes: group.prefixes.clone(),
+            commands,
+            options: group.options.clone(),
+        };
+
+        inner.insert(Arc::new(group));
+
+        true
+    }
+
+    /// The registered groups and their maps.
+    pub(crate) async fn groups(&self) -> Vec<(Arc<DynamicGroup>, Arc<Map>)> {
+        self.inner.read().await.groups.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::parse::map::ParseMap;
+    use futures::future::FutureExt;
+
+    fn command(name: &str) -> DynamicCommand {
+        DynamicCommand::new(name, |_, _, _| async { Ok(()) }.boxed())
+    }
+
+    async fn has_command(registry: &CommandRegistry, name: &str) -> bool {
+        let groups = registry.groups().await;
+
+        match &*groups[0].1 {
+            Map::WithPrefixes(map) => map.get("tag", false).unwrap().2.get(name, false).is_some(),
+            Map::Prefixless(..) => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn register_and_unregister() {
+        let registry = CommandRegistry::default();
+
+        let mut group = DynamicGroup::new("tags");
+        group.prefix("tag").command(command("rules"));
+        registry.register_group(group).await;
+
+        assert!(registry.register_command("tags", command("faq")).await);
+        assert!(!registry.register_command("other", command("faq")).await);
+        assert!(has_command(&registry, "rules").await);
+        assert!(has_command(&registry, "faq").await);
+
+        assert!(registry.unregister_command("tags", "rules").await);
+        assert!(!registry.unregister_command("tags", "rules").await);
+        assert!(!has_command(&registry, "rules").await);
+
+        assert!(registry.unregister_group("tags").await);
+        assert!(registry.group_names().await.is_empty());
+    }
+
+    #[test]
+    fn options() {
+        let mut faq = command("faq");
+        faq.alias("f").description("Answers questions.").usage("[question]");
+
+        assert_eq!(faq.names(), &["faq", "f"]);
+        assert_eq!(faq.desc(), Some("Answers questions."));
+        assert_eq!(faq.usage, Some("[question]".to_string()));
+        assert!(faq.options.names.is_empty());
+        assert_eq!(faq.options.desc, None);
+    }
+
+    #[tokio::test]
+    async fn sub_commands() {
+        let registry = CommandRegistry::default();
+
+        let mut config = command("config");
+        config.sub_command(command("set"));
+
+        let mut group = DynamicGroup::new("settings");
+        group.command(config);
+        registry.register_group(group).await;
+
+        let groups = registry.groups().await;
+
+        let (_, sub_map) = match &*groups[0].1 {
+            Map::Prefixless(_, map) => map.get("config", false).unwrap(),
+            Map::WithPrefixes(..) => unreachable!(),
+        };
+
+        assert_eq!(sub_map.get("set", false).unwrap().0.name(), "set");
+        assert!(sub_map.get("config", false).is_none());
+    }
+}
@@ -1,11 +1,72 @@
 use crate::client::Context;
+use crate::model::channel::Message;
 use crate::model::id::{ChannelId, GuildId, UserId};
 use futures::future::BoxFuture;
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
-type Check =
-    for<'fut> fn(&'fut Context, Option<GuildId>, ChannelId, UserId) -> BoxFuture<'fut, bool>;
+type Check = Arc<
+    dyn for<'fut> Fn(&'fut Context, Option<GuildId>, ChannelId, UserId) -> BoxFuture<'fut, bool>
+        + Send
+        + Sync,
+>;
+type DelayHook = Arc<dyn for<'fut> Fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()> + Send + Sync>;
+
+/// The pool of in-flight invocations: the command if it limits its own
+/// concurrency, and the key of the scope.
+pub(crate) type InFlightKey = (Option<String>, (u64, u64));
+
+/// A permit held while an invocation runs, to be handed back to its bucket
+/// via [`Bucket::release`].
+///
+/// [`Bucket::release`]: struct.Bucket.html#method.release
+pub(crate) struct InFlightPermit {
+    pub bucket: &'static str,
+    pub key: InFlightKey,
+    pub permit: OwnedSemaphorePermit,
+}
+
+/// What the invocations counted by a bucket are grouped by.
+///
+/// Each distinct user, guild, etc. gets its own ratelimit, depending on the
+/// scope.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BucketScope {
+    /// Ratelimit each user, regardless of where the command is invoked.
+    User,
+    /// Ratelimit each guild. Direct messages are ratelimited per channel.
+    Guild,
+    /// Ratelimit each channel.
+    Channel,
+    /// Ratelimit each user separately in each guild. In direct messages, this
+    /// is the same as `User`.
+    Member,
+    /// Share one ratelimit between all invocations.
+    Global,
+    #[doc(hidden)]
+    __Nonexhaustive,
+}
+
+impl Default for BucketScope {
+    fn default() -> Self {
+        BucketScope::User
+    }
+}
+
+impl BucketScope {
+    /// The key an invocation is counted under in this scope.
+    pub(crate) fn key(self, guild_id: Option<GuildId>, channel_id: ChannelId, user_id: UserId) -> (u64, u64) {
+        match self {
+            BucketScope::User => (0, user_id.0),
+            BucketScope::Guild => (guild_id.map_or(channel_id.0, |g| g.0), 0),
+            BucketScope::Channel => (channel_id.0, 0),
+            BucketScope::Member => (guild_id.map_or(0, |g| g.0), user_id.0),
+            BucketScope::Global | BucketScope::__Nonexhaustive => (0, 0),
+        }
+    }
+}
 
 pub(crate) struct Ratelimit {
     pub delay: Duration,
@@ -21,23 +82,70 @@ pub(crate) struct MemberRatelimit {
 
 pub(crate) struct Bucket {
     pub ratelimit: Ratelimit,
-    pub users: HashMap<u64, MemberRatelimit>,
+    pub scope: BucketScope,
+    pub users: HashMap<(u64, u64), MemberRatelimit>,
+    pub max_concurrency: Option<u32>,
+    /// The permits of in-flight invocations, per command if the command
+    /// limits its own concurrency, and per key.
+    ///
+    /// Pools are removed once none of their permits is held or awaited.
+    pub in_flight: HashMap<InFlightKey, Arc<Semaphore>>,
+    pub await_ratelimits: bool,
+    pub delay_action: Option<DelayHook>,
     pub check: Option<Check>,
 }
 
 impl Bucket {
-    pub fn take(&mut self, user_id: u64) -> Option<Duration> {
+    /// The maximum and the pool of in-flight invocations an invocation under
+    /// `key` counts against, if concurrency is limited.
+    ///
+    /// A command with its own `(name, maximum)` gets a pool separate from the
+    /// other commands of the bucket.
+    pub fn in_flight(
+        &mut self,
+        command: Option<(&str, u32)>,
+        key: (u64, u64),
+    ) -> Option<(u32, InFlightKey, Arc<Semaphore>)> {
+        let (name, max) = match command {
+            Some((name, max)) => (Some(name.to_string()), max),
+            None => (None, self.max_concurrency?),
+        };
+
+        let key = (name, key);
+        let semaphore = self.in_flight
+            .entry(key.clone())
+            .or_insert_with(|| Arc::new(Semaphore::new(max as usize)));
+
+        Some((max, key, Arc::clone(semaphore)))
+    }
+
+    /// Hands back the permit of a finished invocation, removing its pool if
+    /// no other invocation holds or awaits a permit of it anymore.
+    pub fn release(&mut self, permit: InFlightPermit) {
+        let InFlightPermit { key, permit, .. } = permit;
+        drop(permit);
+
+        // Held or awaited permits keep a reference to the pool each.
+        if let Some(1) = self.in_flight.get(&key).map(Arc::strong_count) {
+            self.in_flight.remove(&key);
+        }
+    }
+
+    /// Counts an invocation under `key`, returning how long to wait instead
+    /// if the ratelimit of the key is exhausted.
+    pub fn take(&mut self, key: (u64, u64)) -> Option<Duration> {
         let now = Instant::now();
         let Self {
             users, ratelimit, ..
         } = self;
-        let user = users.entry(user_id).or_default();
+        let user = users.entry(key).or_default();
 
         if let Some((timespan, limit)) = ratelimit.limit {
             if (user.tickets + 1) > limit {
                 if let Some(res) = user
                     .set_time
-                    .and_then(|x| now.checked_duration_since(x + timespan))
+                    .and_then(|x| (x + timespan).checked_duration_since(now))
+                    .filter(|res| *res > Duration::default())
                 {
                     return Some(res);
                 } else {
@@ -49,12 +157,15 @@ impl Bucket {
 
         if let Some(res) = user
             .last_time
-            .and_then(|x| now.checked_duration_since(x + ratelimit.delay))
+            .and_then(|x| (x + ratelimit.delay).checked_duration_since(now))
+            .filter(|res| *res > Duration::default())
         {
             return Some(res);
         } else {
             user.tickets += 1;
             user.last_time = Some(now);
+            // The time span starts with the first counted invocation.
+            user.set_time.get_or_insert(now);
         }
 
         None
@@ -66,6 +177,10 @@ pub struct BucketBuilder {
     pub(crate) delay: Duration,
     pub(crate) time_span: Duration,
     pub(crate) limit: u32,
+    pub(crate) scope: BucketScope,
+    pub(crate) max_concurrency: Option<u32>,
+    pub(crate) await_ratelimits: bool,
+    pub(crate) delay_action: Option<DelayHook>,
     pub(crate) check: Option<Check>,
 }
 
@@ -102,12 +217,176 @@ impl BucketBuilder {
         self
     }
 
+    /// What invocations are counted together, each user by default.
+    ///
+    /// For example, [`BucketScope::Guild`] limits a command like "play" for
+    /// a whole guild, while [`BucketScope::Global`] limits an expensive
+    /// command across all of them.
+    ///
+    /// [`BucketScope::Global`]: enum.BucketScope.html#variant.Global
+    /// [`BucketScope::Guild`]: enum.BucketScope.html#variant.Guild
+    #[inline]
+    pub fn scope(&mut self, scope: BucketScope) -> &mut Self {
+        self.scope = scope;
+
+        self
+    }
+
+    /// The maximum number of invocations that may run at the same time, per
+    /// [`scope`].
+    ///
+    /// Individual commands may set their own maximum via the `#[bucket]`
+    /// attribute, e.g. `#[bucket("heavy", max_concurrency = 1)]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0`, as no invocation could ever run.
+    ///
+    /// [`scope`]: #method.scope
+    #[inline]
+    pub fn max_concurrency(&mut self, n: u32) -> &mut Self {
+        assert!(n >= 1, "the maximum concurrency of a bucket must be at least 1");
+
+        self.max_concurrency = Some(n);
+
+        self
+    }
+
+    /// Whether to delay invocations until the ratelimit or the
+    /// [`max_concurrency`] allows them, instead of failing with a
+    /// [`DispatchError`].
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`DispatchError`]: ../enum.DispatchError.html
+    /// [`max_concurrency`]: #method.max_concurrency
+    #[inline]
+    pub fn await_ratelimits(&mut self, b: bool) -> &mut Self {
+        self.await_ratelimits = b;
+
+        self
+    }
+
+    /// A function called once when an invocation is delayed due to
+    /// [`await_ratelimits`], e.g. to let the user know that their command has
+    /// been queued.
+    ///
+    /// [`await_ratelimits`]: #method.await_ratelimits
+    #[inline]
+    pub fn delay_action<F>(&mut self, f: F) -> &mut Self
+    where
+        F: for<'fut> Fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()> + Send + Sync + 'static,
+    {
+        self.delay_action = Some(Arc::new(f));
+
+        self
+    }
+
     /// Middleware confirming (or denying) that the bucket is eligible to apply.
     /// For instance, to limit the bucket to just one user.
     #[inline]
-    pub fn check(&mut self, f: Check) -> &mut Self {
-        self.check = Some(f);
+    pub fn check<F>(&mut self, f: F) -> &mut Self
+    where
+        F: for<'fut> Fn(&'fut Context, Option<GuildId>, ChannelId, UserId) -> BoxFuture<'fut, bool>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.check = Some(Arc::new(f));
 
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn bucket(scope: BucketScope) -> Bucket {
+        Bucket {
+            ratelimit: Ratelimit {
+                delay: Duration::from_secs(0),
+                limit: Some((Duration::from_secs(60), 1)),
+            },
+            scope,
+            users: HashMap::new(),
+            max_concurrency: None,
+            in_flight: HashMap::new(),
+            await_ratelimits: false,
+            delay_action: None,
+            check: None,
+        }
+    }
+
+    fn take(bucket: &mut Bucket, guild_id: u64, channel_id: u64, user_id: u64) -> Option<Duration> {
+        let key = bucket.scope.key(Some(GuildId(guild_id)), ChannelId(channel_id), UserId(user_id));
+
+        bucket.take(key)
+    }
+
+    #[test]
+    fn user_scope() {
+        let mut bucket = bucket(BucketScope::User);
+
+        assert!(take(&mut bucket, 1, 1, 1).is_none());
+        assert!(take(&mut bucket, 2, 2, 1).is_some());
+        assert!(take(&mut bucket, 1, 1, 2).is_none());
+    }
+
+    #[test]
+    fn guild_scope() {
+        let mut bucket = bucket(BucketScope::Guild);
+
+        assert!(take(&mut bucket, 1, 1, 1).is_none());
+        assert!(take(&mut bucket, 1, 2, 2).is_some());
+        assert!(take(&mut bucket, 2, 3, 1).is_none());
+    }
+
+    #[test]
+    fn member_scope() {
+        let mut bucket = bucket(BucketScope::Member);
+
+        assert!(take(&mut bucket, 1, 1, 1).is_none());
+        assert!(take(&mut bucket, 1, 2, 1).is_some());
+        assert!(take(&mut bucket, 2, 1, 1).is_none());
+        assert!(take(&mut bucket, 1, 1, 2).is_none());
+    }
+
+    #[test]
+    fn in_flight() {
+        let mut bucket = bucket(BucketScope::Guild);
+        assert!(bucket.in_flight(None, (1, 0)).is_none());
+
+        bucket.max_concurrency = Some(1);
+        let (_, key, semaphore) = bucket.in_flight(None, (1, 0)).unwrap();
+        let permit = semaphore.try_acquire_owned().unwrap();
+
+        assert!(bucket.in_flight(None, (1, 0)).unwrap().2.try_acquire().is_err());
+        assert!(bucket.in_flight(None, (2, 0)).unwrap().2.try_acquire().is_ok());
+        assert!(bucket.in_flight(Some(("play", 2)), (1, 0)).unwrap().2.try_acquire().is_ok());
+
+        bucket.in_flight.remove(&(None, (2, 0)));
+        bucket.in_flight.remove(&(Some("play".to_string()), (1, 0)));
+        bucket.release(InFlightPermit {
+            bucket: "test",
+            key,
+            permit,
+        });
+
+        assert!(bucket.in_flight.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_max_concurrency() {
+        BucketBuilder::default().max_concurrency(0);
+    }
+
+    #[test]
+    fn global_scope() {
+        let mut bucket = bucket(BucketScope::Global);
+
+        assert!(take(&mut bucket, 1, 1, 1).is_none());
+        assert!(take(&mut bucket, 2, 2, 2).is_some());
+    }
+}
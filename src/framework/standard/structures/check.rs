@@ -4,6 +4,7 @@ use crate::model::channel::Message;
 use crate::client::Context;
 use crate::framework::standard::{Args, CommandOptions};
 use futures::future::BoxFuture;
+use std::sync::Arc;
 
 /// This type describes why a check has failed and occurs on
 /// [`CheckResult::Failure`].
@@ -112,13 +113,56 @@ impl From<Reason> for CheckResult {
     }
 }
 
-pub type CheckFunction = for<'fut> fn(
+/// The function of a [`Check`] declared via the `#[check]` macro.
+///
+/// [`Check`]: struct.Check.html
+pub type CheckFn = for<'fut> fn(
     &'fut Context,
     &'fut Message,
     &'fut mut Args,
     &'fut CommandOptions,
 ) -> BoxFuture<'fut, CheckResult>;
 
+/// The function of a [`Check`] created at runtime via [`Check::new`], which
+/// may capture state.
+///
+/// [`Check`]: struct.Check.html
+/// [`Check::new`]: struct.Check.html#method.new
+pub type DynamicCheckFn = Arc<
+    dyn for<'fut> Fn(&'fut Context, &'fut Message, &'fut mut Args, &'fut CommandOptions) -> BoxFuture<'fut, CheckResult>
+        + Send
+        + Sync,
+>;
+
+/// The function executed by a [`Check`].
+///
+/// [`Check`]: struct.Check.html
+#[derive(Clone)]
+pub enum CheckFunction {
+    /// A function, as declared via the `#[check]` macro.
+    Static(CheckFn),
+    /// A closure, as passed to [`Check::new`].
+    ///
+    /// [`Check::new`]: struct.Check.html#method.new
+    Dynamic(DynamicCheckFn),
+}
+
+impl CheckFunction {
+    /// Runs the check.
+    pub fn call<'fut>(
+        &'fut self,
+        ctx: &'fut Context,
+        msg: &'fut Message,
+        args: &'fut mut Args,
+        options: &'fut CommandOptions,
+    ) -> BoxFuture<'fut, CheckResult> {
+        match self {
+            CheckFunction::Static(fun) => fun(ctx, msg, args, options),
+            CheckFunction::Dynamic(fun) => fun(ctx, msg, args, options),
+        }
+    }
+}
+
 /// A check can be part of a command or group and will be executed to
 /// determine whether a user is permitted to use related item.
 ///
@@ -137,6 +181,54 @@ pub struct Check {
     pub display_in_help: bool,
 }
 
+impl Check {
+    /// Creates a check named `name` that runs `fun`, which, unlike a function
+    /// declared via the `#[check]` macro, may capture state.
+    ///
+    /// The check is evaluated and displayed in the help, as by default with
+    /// the `#[check]` macro.
+    ///
+    /// # Examples
+    ///
+    /// Only permit a set of users loaded at runtime to use a [`DynamicCommand`].
+    /// As commands refer to their checks for the lifetime of the program, the
+    /// check is leaked:
+    ///
+    /// ```rust
+    /// # use serenity::model::id::UserId;
+    /// use serenity::framework::standard::{Check, DynamicCommand};
+    /// use serenity::futures::future::FutureExt;
+    /// use std::collections::HashSet;
+    ///
+    /// let moderators: HashSet<UserId> = vec![UserId(7)].into_iter().collect();
+    ///
+    /// let check = Check::new("Moderator", move |_, msg, _, _| {
+    ///     let permitted = moderators.contains(&msg.author.id);
+    ///
+    ///     async move { permitted.into() }.boxed()
+    /// });
+    ///
+    /// let mut command = DynamicCommand::new("kick", |_, _, _| async { Ok(()) }.boxed());
+    /// command.options.checks = Box::leak(vec![&*Box::leak(Box::new(check))].into_boxed_slice());
+    /// ```
+    ///
+    /// [`DynamicCommand`]: struct.DynamicCommand.html
+    pub fn new<F>(name: &'static str, fun: F) -> Self
+    where
+        F: for<'fut> Fn(&'fut Context, &'fut Message, &'fut mut Args, &'fut CommandOptions) -> BoxFuture<'fut, CheckResult>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Check {
+            name,
+            function: CheckFunction::Dynamic(Arc::new(fun)),
+            check_in_help: true,
+            display_in_help: true,
+        }
+    }
+}
+
 impl Debug for Check {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Check")
@@ -0,0 +1,296 @@
+use super::*;
+use std::sync::Arc;
+
+/// The handler of a [`DynamicCommand`].
+///
+/// [`DynamicCommand`]: struct.DynamicCommand.html
+pub type DynamicCommandFn = Arc<
+    dyn for<'fut> Fn(&'fut Context, &'fut Message, Args) -> BoxFuture<'fut, CommandResult>
+        + Send
+        + Sync,
+>;
+
+/// A command constructed at runtime, e.g. from configuration, with a closure
+/// as its handler.
+///
+/// Unlike a [`Command`] declared via the `#[command]` macro, it owns its names
+/// and texts. It is registered and unregistered through a [`CommandRegistry`]
+/// while the framework is running, as part of a [`DynamicGroup`].
+///
+/// # Examples
+///
+/// A tag command replying with some configured text:
+///
+/// ```rust
+/// use serenity::framework::standard::DynamicCommand;
+/// use serenity::futures::future::FutureExt;
+///
+/// let text = "Read the rules in #rules!".to_string();
+///
+/// let mut command = DynamicCommand::new("rules", move |ctx, msg, _args| {
+///     let text = text.clone();
+///
+///     async move {
+///         msg.channel_id.say(&ctx.http, text).await?;
+///
+///         Ok(())
+///     }.boxed()
+/// });
+///
+/// command.description("Shows the rules.").alias("r");
+/// ```
+///
+/// The names, description, and usage are owned by the command rather than
+/// set in its [`options`], so that commands can be created and dropped at
+/// runtime without leaking them. The `names` of the options that checks
+/// receive are thus empty.
+///
+/// [`Command`]: struct.Command.html
+/// [`CommandRegistry`]: struct.CommandRegistry.html
+/// [`DynamicGroup`]: struct.DynamicGroup.html
+/// [`options`]: #structfield.options
+pub struct DynamicCommand {
+    pub(crate) names: Vec<String>,
+    pub(crate) description: Option<String>,
+    pub(crate) usage: Option<String>,
+    pub(crate) fun: DynamicCommandFn,
+    pub(crate) sub_commands: Vec<Arc<DynamicCommand>>,
+    /// The options of the command.
+    ///
+    /// Its `names`, `desc`, and `usage` are not used by the framework; set
+    /// them via the methods of the command instead.
+    pub options: CommandOptions,
+}
+
+impl DynamicCommand {
+    /// Creates a command named `name` that runs `fun`.
+    ///
+    /// The options are the same as the defaults of the `#[command]` macro.
+    pub fn new<F>(name: impl Into<String>, fun: F) -> Self
+    where
+        F: for<'fut> Fn(&'fut Context, &'fut Message, Args) -> BoxFuture<'fut, CommandResult>
+            + Send
+            + Sync
+            + 'static,
+    {
+        DynamicCommand {
+            names: vec![name.into()],
+            description: None,
+            usage: None,
+            fun: Arc::new(fun),
+            sub_commands: Vec::new(),
+            options: CommandOptions {
+                help_available: true,
+                owner_privilege: true,
+                ..CommandOptions::default()
+            },
+        }
+    }
+
+    /// Adds an alternative name to refer to the command by.
+    #[inline]
+    pub fn alias(&mut self, name: impl Into<String>) -> &mut Self {
+        self.names.push(name.into());
+
+        self
+    }
+
+    /// Sets the description of the command.
+    #[inline]
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.description = Some(description.into());
+
+        self
+    }
+
+    /// Sets the usage of the command, e.g. `<user> [reason]`.
+    #[inline]
+    pub fn usage(&mut self, usage: impl Into<String>) -> &mut Self {
+        self.usage = Some(usage.into());
+
+        self
+    }
+
+    /// Adds a sub command, invoked by its name following the name of this
+    /// command, replacing the sub command with the same name.
+    ///
+    /// Sub commands declared via `#[command]` can be set in the
+    /// [`options`] instead, as can the default sub command.
+    ///
+    /// [`options`]: #structfield.options
+    pub fn sub_command(&mut self, command: DynamicCommand) -> &mut Self {
+        self.sub_commands.retain(|c| c.name() != command.name());
+        self.sub_commands.push(Arc::new(command));
+
+        self
+    }
+
+    /// The names of the command, the first being its actual name.
+    #[inline]
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// The name of the command.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.names[0]
+    }
+
+    /// The description of the command, if set.
+    #[inline]
+    pub fn desc(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+}
+
+impl fmt::Debug for DynamicCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynamicCommand")
+            .field("names", &self.names)
+            .field("description", &self.description)
+            .field("usage", &self.usage)
+            .field("fun", &"<function>")
+            .field("sub_commands", &self.sub_commands)
+            .field("options", &self.options)
+            .finish()
+    }
+}
+
+/// A group of [`DynamicCommand`]s, constructed at runtime.
+///
+/// [`DynamicCommand`]: struct.DynamicCommand.html
+#[derive(Debug)]
+pub struct DynamicGroup {
+    pub(crate) name: String,
+    pub(crate) prefixes: Vec<String>,
+    pub(crate) commands: Vec<Arc<DynamicCommand>>,
+    /// The options of the group besides its prefixes, commands, and sub
+    /// groups, which are left empty.
+    pub options: GroupOptions,
+}
+
+impl DynamicGroup {
+    /// Creates a group named `name`, without any prefixes or commands.
+    ///
+    /// The options are the same as the defaults of the `#[group]` macro.
+    pub fn new(name: impl Into<String>) -> Self {
+        DynamicGroup {
+            name: name.into(),
+            prefixes: Vec::new(),
+            commands: Vec::new(),
+            options: GroupOptions {
+                help_available: true,
+                owner_privilege: true,
+                ..GroupOptions::default()
+            },
+        }
+    }
+
+    /// Adds a prefix the commands of the group have to be invoked with.
+    ///
+    /// Without any prefixes, the commands are invoked on their own.
+    #[inline]
+    pub fn prefix(&mut self, prefix: impl Into<String>) -> &mut Self {
+        self.prefixes.push(prefix.into());
+
+        self
+    }
+
+    /// Adds a command to the group, replacing the command with the same name.
+    #[inline]
+    pub fn command(&mut self, command: DynamicCommand) -> &mut Self {
+        self.commands.retain(|c| c.name() != command.name());
+        self.commands.push(Arc::new(command));
+
+        self
+    }
+
+    /// The name of the group.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The commands of the group.
+    #[inline]
+    pub fn commands(&self) -> impl Iterator<Item = &DynamicCommand> {
+        self.commands.iter().map(|c| &**c)
+    }
+}
+
+/// A command as stored by the framework, either declared statically or
+/// registered at runtime.
+#[derive(Clone, Debug)]
+pub enum CommandHandle {
+    Static(&'static Command),
+    Dynamic(Arc<DynamicCommand>),
+}
+
+impl CommandHandle {
+    #[inline]
+    pub(crate) fn options(&self) -> &CommandOptions {
+        match self {
+            CommandHandle::Static(command) => command.options,
+            CommandHandle::Dynamic(command) => &command.options,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn name(&self) -> &str {
+        match self {
+            CommandHandle::Static(command) => command.options.names[0],
+            CommandHandle::Dynamic(command) => command.name(),
+        }
+    }
+
+    pub(crate) fn names(&self) -> Vec<&str> {
+        match self {
+            CommandHandle::Static(command) => command.options.names.to_vec(),
+            CommandHandle::Dynamic(command) => command.names.iter().map(String::as_str).collect(),
+        }
+    }
+
+    /// Parses the typed parameters of the command, if any, returning the
+    /// command to run.
+    pub(crate) fn prepare<'fut>(
+        &self,
+        ctx: &'fut Context,
+        msg: &'fut Message,
+        args: Args,
+    ) -> BoxFuture<'fut, CommandResult<BoxFuture<'fut, CommandResult>>> {
+        use futures::future::FutureExt;
+
+        match self {
+            CommandHandle::Static(Command { prepare: Some(prepare), .. }) => prepare(ctx, msg, args),
+            CommandHandle::Static(command) => {
+                let fun = (command.fun)(ctx, msg, args);
+
+                async move { Ok(fun) }.boxed()
+            },
+            CommandHandle::Dynamic(command) => {
+                let fun = (command.fun)(ctx, msg, args);
+
+                async move { Ok(fun) }.boxed()
+            },
+        }
+    }
+}
+
+/// A group as stored by the framework, either declared statically or
+/// registered at runtime.
+#[derive(Clone, Debug)]
+pub enum GroupHandle {
+    Static(&'static CommandGroup),
+    Dynamic(Arc<DynamicGroup>),
+}
+
+impl GroupHandle {
+    #[inline]
+    pub(crate) fn options(&self) -> &GroupOptions {
+        match self {
+            GroupHandle::Static(group) => group.options,
+            GroupHandle::Dynamic(group) => &group.options,
+        }
+    }
+}
@@ -14,9 +14,12 @@ use super::Args;
 use futures::future::BoxFuture;
 
 mod check;
+mod dynamic;
 pub mod buckets;
 
 pub use self::check::*;
+pub use self::dynamic::{DynamicCommand, DynamicCommandFn, DynamicGroup};
+pub(crate) use self::dynamic::{CommandHandle, GroupHandle};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OnlyIn {
@@ -38,6 +41,9 @@ pub struct CommandOptions {
     pub checks: &'static [&'static Check],
     /// Ratelimit bucket.
     pub bucket: Option<&'static str>,
+    /// Maximum number of invocations of this command that may run at the same
+    /// time, overriding the maximum of the bucket.
+    pub max_concurrency: Option<u32>,
     /// Names that the command can be referred to.
     pub names: &'static [&'static str],
     /// Command description, used by other commands.
@@ -67,14 +73,28 @@ pub struct CommandOptions {
     pub owner_privilege: bool,
     /// Other commands belonging to this command.
     pub sub_commands: &'static [&'static Command],
+    /// The command executed instead of this one if none of its sub commands
+    /// has been requested.
+    pub default_sub_command: Option<&'static Command>,
 }
 
 pub type CommandError = Box<dyn StdError + Send + Sync>;
 pub type CommandResult<T = ()> = std::result::Result<T, CommandError>;
 pub type CommandFn = for<'fut> fn(&'fut Context, &'fut Message, Args) -> BoxFuture<'fut, CommandResult>;
+pub type CommandPrepareFn =
+    for<'fut> fn(&'fut Context, &'fut Message, Args) -> BoxFuture<'fut, CommandResult<BoxFuture<'fut, CommandResult>>>;
 
 pub struct Command {
     pub fun: CommandFn,
+    /// Parses the typed parameters of the command, returning the command to
+    /// run with them.
+    ///
+    /// The framework calls this instead of [`fun`] if set, so that arguments
+    /// failing to parse are reported before the command's bucket and the
+    /// `before` hook are applied.
+    ///
+    /// [`fun`]: #structfield.fun
+    pub prepare: Option<CommandPrepareFn>,
     pub options: &'static CommandOptions,
 }
 
@@ -167,6 +187,8 @@ pub struct HelpOptions {
     pub checks_label: &'static str,
     /// Text labelling a command's subcommands
     pub sub_commands_label: &'static str,
+    /// Text marking the default subcommand of a command.
+    pub default_sub_command_label: &'static str,
     /// Text specifying that a command is only usable in via DM.
     pub dm_only_text: &'static str,
     /// Text specifying that a command can be used via DM and in guilds.
@@ -213,9 +235,12 @@ pub struct HelpOptions {
     /// Help will use this as prefix to express how deeply nested a command or
     /// group is.
     pub indention_prefix: &'static str,
+    /// How many seconds the paginated help waits for the next reaction to
+    /// turn its pages.
+    pub page_timeout: u64,
 }
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct GroupOptions {
     pub prefixes: &'static [&'static str],
     pub only_in: OnlyIn,
@@ -21,6 +21,7 @@ use super::{
     HttpError,
 };
 use bytes::buf::Buf;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use serde::de::DeserializeOwned;
 use serde_json::json;
 use log::{debug, trace};
@@ -72,10 +73,10 @@ impl Http {
     /// [`Member`]: ../../model/guild/struct.Member.html
     /// [`Role`]: ../../model/guild/struct.Role.html
     /// [Manage Roles]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
-    pub async fn add_member_role(&self, guild_id: u64, user_id: u64, role_id: u64) -> Result<()> {
+    pub async fn add_member_role(&self, guild_id: u64, user_id: u64, role_id: u64, audit_log_reason: Option<&str>) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::AddMemberRole { guild_id, role_id, user_id },
         }).await
     }
@@ -131,12 +132,12 @@ impl Http {
     /// [`GuildChannel`]: ../../model/channel/struct.GuildChannel.html
     /// [docs]: https://discord.com/developers/docs/resources/guild#create-guild-channel
     /// [Manage Channels]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
-    pub async fn create_channel(&self, guild_id: u64, map: &JsonMap) -> Result<GuildChannel> {
+    pub async fn create_channel(&self, guild_id: u64, map: &JsonMap, audit_log_reason: Option<&str>) -> Result<GuildChannel> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::CreateChannel { guild_id },
         }).await
     }
@@ -151,10 +152,10 @@ impl Http {
     /// [`create_emoji`]: ../../model/guild/struct.Guild.html#method.create_emoji
     /// [`Guild`]: ../../model/guild/struct.Guild.html
     /// [Manage Emojis]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
-    pub async fn create_emoji(&self, guild_id: u64, map: &Value) -> Result<Emoji> {
+    pub async fn create_emoji(&self, guild_id: u64, map: &Value, audit_log_reason: Option<&str>) -> Result<Emoji> {
         self.fire(Request {
             body: Some(map.to_string().as_bytes()),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::CreateEmoji { guild_id },
         }).await
     }
@@ -214,10 +215,10 @@ impl Http {
     /// [`Integration`]: ../../model/guild/struct.Integration.html
     /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     /// [docs]: https://discord.com/developers/docs/resources/guild#create-guild-integration
-    pub async fn create_guild_integration(&self, guild_id: u64, integration_id: u64, map: &Value) -> Result<()> {
+    pub async fn create_guild_integration(&self, guild_id: u64, integration_id: u64, map: &Value, audit_log_reason: Option<&str>) -> Result<()> {
         self.wind(204, Request {
             body: Some(map.to_string().as_bytes()),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::CreateGuildIntegration { guild_id, integration_id },
         }).await
     }
@@ -234,23 +235,23 @@ impl Http {
     /// [`RichInvite`]: ../../model/invite/struct.RichInvite.html
     /// [Create Invite]: ../../model/permissions/struct.Permissions.html#associatedconstant.CREATE_INVITE
     /// [docs]: https://discord.com/developers/docs/resources/channel#create-channel-invite
-    pub async fn create_invite(&self, channel_id: u64, map: &JsonMap) -> Result<RichInvite> {
+    pub async fn create_invite(&self, channel_id: u64, map: &JsonMap, audit_log_reason: Option<&str>) -> Result<RichInvite> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::CreateInvite { channel_id },
         }).await
     }
 
     /// Creates a permission override for a member or a role in a channel.
-    pub async fn create_permission(&self, channel_id: u64, target_id: u64, map: &Value) -> Result<()> {
+    pub async fn create_permission(&self, channel_id: u64, target_id: u64, map: &Value, audit_log_reason: Option<&str>) -> Result<()> {
         let body = serde_json::to_vec(map)?;
 
         self.wind(204, Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::CreatePermission { channel_id, target_id },
         }).await
     }
@@ -285,11 +286,11 @@ impl Http {
     }
 
     /// Creates a role.
-    pub async fn create_role(&self, guild_id: u64, map: &JsonMap) -> Result<Role> {
+    pub async fn create_role(&self, guild_id: u64, map: &JsonMap, audit_log_reason: Option<&str>) -> Result<Role> {
         let body = serde_json::to_vec(map)?;
         let mut value = self.request(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::CreateRole { guild_id },
         }).await?.json::<Value>().await?;
 
@@ -325,36 +326,47 @@ impl Http {
     /// let channel_id = 81384788765712384;
     /// let map = json!({"name": "test"});
     ///
-    /// let webhook = http.create_webhook(channel_id, &map).await?;
+    /// let webhook = http.create_webhook(channel_id, &map, None).await?;
     /// #     Ok(())
     /// # }
     /// ```
     ///
     /// [`GuildChannel`]: ../../model/channel/struct.GuildChannel.html
-    pub async fn create_webhook(&self, channel_id: u64, map: &Value) -> Result<Webhook> {
+    pub async fn create_webhook(&self, channel_id: u64, map: &Value, audit_log_reason: Option<&str>) -> Result<Webhook> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::CreateWebhook { channel_id },
         }).await
     }
 
-    /// Deletes a private channel or a channel in a guild.
-    pub async fn delete_channel(&self, channel_id: u64) -> Result<Channel> {
+    /// Crossposts a message by Id.
+    ///
+    /// **Note**: Only available on announcements channels.
+    pub async fn crosspost_message(&self, channel_id: u64, message_id: u64) -> Result<Message> {
         self.fire(Request {
             body: None,
             headers: None,
+            route: RouteInfo::CrosspostMessage { channel_id, message_id },
+        }).await
+    }
+
+    /// Deletes a private channel or a channel in a guild.
+    pub async fn delete_channel(&self, channel_id: u64, audit_log_reason: Option<&str>) -> Result<Channel> {
+        self.fire(Request {
+            body: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::DeleteChannel { channel_id },
         }).await
     }
 
     /// Deletes an emoji from a server.
-    pub async fn delete_emoji(&self, guild_id: u64, emoji_id: u64) -> Result<()> {
+    pub async fn delete_emoji(&self, guild_id: u64, emoji_id: u64, audit_log_reason: Option<&str>) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::DeleteEmoji { guild_id, emoji_id },
         }).await
     }
@@ -369,38 +381,38 @@ impl Http {
     }
 
     /// Removes an integration from a guild.
-    pub async fn delete_guild_integration(&self, guild_id: u64, integration_id: u64) -> Result<()> {
+    pub async fn delete_guild_integration(&self, guild_id: u64, integration_id: u64, audit_log_reason: Option<&str>) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::DeleteGuildIntegration { guild_id, integration_id },
         }).await
     }
 
     /// Deletes an invite by code.
-    pub async fn delete_invite(&self, code: &str) -> Result<Invite> {
+    pub async fn delete_invite(&self, code: &str, audit_log_reason: Option<&str>) -> Result<Invite> {
         self.fire(Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::DeleteInvite { code },
         }).await
     }
 
     /// Deletes a message if created by us or we have
     /// specific permissions.
-    pub async fn delete_message(&self, channel_id: u64, message_id: u64) -> Result<()> {
+    pub async fn delete_message(&self, channel_id: u64, message_id: u64, audit_log_reason: Option<&str>) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::DeleteMessage { channel_id, message_id },
         }).await
     }
 
     /// Deletes a bunch of messages, only works for bots.
-    pub async fn delete_messages(&self, channel_id: u64, map: &Value) -> Result<()> {
+    pub async fn delete_messages(&self, channel_id: u64, map: &Value, audit_log_reason: Option<&str>) -> Result<()> {
         self.wind(204, Request {
             body: Some(map.to_string().as_bytes()),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::DeleteMessages { channel_id },
         }).await
     }
@@ -453,10 +465,10 @@ impl Http {
     }
 
     /// Deletes a permission override from a role or a member in a channel.
-    pub async fn delete_permission(&self, channel_id: u64, target_id: u64) -> Result<()> {
+    pub async fn delete_permission(&self, channel_id: u64, target_id: u64, audit_log_reason: Option<&str>) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::DeletePermission { channel_id, target_id },
         }).await
     }
@@ -487,10 +499,10 @@ impl Http {
     }
 
     /// Deletes a role from a server. Can't remove the default everyone role.
-    pub async fn delete_role(&self, guild_id: u64, role_id: u64) -> Result<()> {
+    pub async fn delete_role(&self, guild_id: u64, role_id: u64, audit_log_reason: Option<&str>) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::DeleteRole { guild_id, role_id },
         }).await
     }
@@ -512,17 +524,17 @@ impl Http {
     /// // must have set the token first.
     /// let http = Http::default();
     ///
-    /// http.delete_webhook(245037420704169985).await?;
+    /// http.delete_webhook(245037420704169985, None).await?;
     ///       Ok(())
     /// # }
     /// ```
     ///
     /// [`Webhook`]: ../../model/webhook/struct.Webhook.html
     /// [`delete_webhook_with_token`]: fn.delete_webhook_with_token.html
-    pub async fn delete_webhook(&self, webhook_id: u64) -> Result<()> {
+    pub async fn delete_webhook(&self, webhook_id: u64, audit_log_reason: Option<&str>) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::DeleteWebhook { webhook_id },
         }).await
     }
@@ -558,34 +570,34 @@ impl Http {
     }
 
     /// Changes channel information.
-    pub async fn edit_channel(&self, channel_id: u64, map: &JsonMap) -> Result<GuildChannel> {
+    pub async fn edit_channel(&self, channel_id: u64, map: &JsonMap, audit_log_reason: Option<&str>) -> Result<GuildChannel> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::EditChannel {channel_id },
         }).await
     }
 
     /// Changes emoji information.
-    pub async fn edit_emoji(&self, guild_id: u64, emoji_id: u64, map: &Value) -> Result<Emoji> {
+    pub async fn edit_emoji(&self, guild_id: u64, emoji_id: u64, map: &Value, audit_log_reason: Option<&str>) -> Result<Emoji> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::EditEmoji { guild_id, emoji_id },
         }).await
     }
 
     /// Changes guild information.
-    pub async fn edit_guild(&self, guild_id: u64, map: &JsonMap) -> Result<PartialGuild> {
+    pub async fn edit_guild(&self, guild_id: u64, map: &JsonMap, audit_log_reason: Option<&str>) -> Result<PartialGuild> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::EditGuild { guild_id },
         }).await
     }
@@ -594,13 +606,14 @@ impl Http {
     pub async fn edit_guild_channel_positions(
         &self,
         guild_id: u64,
-        value: &Value
+        value: &Value,
+        audit_log_reason: Option<&str>,
     ) -> Result<()> {
         let body = serde_json::to_vec(value)?;
 
         self.wind(204, Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::EditGuildChannels { guild_id },
         }).await
     }
@@ -608,23 +621,49 @@ impl Http {
     /// Edits a [`Guild`]'s embed setting.
     ///
     /// [`Guild`]: ../../model/guild/struct.Guild.html
-    pub async fn edit_guild_embed(&self, guild_id: u64, map: &Value) -> Result<GuildEmbed> {
+    pub async fn edit_guild_embed(&self, guild_id: u64, map: &Value, audit_log_reason: Option<&str>) -> Result<GuildEmbed> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::EditGuildEmbed { guild_id },
         }).await
     }
 
+    /// Edits a [`Guild`]'s membership screening form.
+    ///
+    /// [`Guild`]: ../../model/guild/struct.Guild.html
+    pub async fn edit_guild_membership_screening(&self, guild_id: u64, map: &JsonMap, audit_log_reason: Option<&str>) -> Result<MembershipScreening> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: audit_log_reason.map(reason_into_header),
+            route: RouteInfo::EditGuildMembershipScreening { guild_id },
+        }).await
+    }
+
+    /// Edits a [`Guild`]'s welcome screen.
+    ///
+    /// [`Guild`]: ../../model/guild/struct.Guild.html
+    pub async fn edit_guild_welcome_screen(&self, guild_id: u64, map: &JsonMap, audit_log_reason: Option<&str>) -> Result<GuildWelcomeScreen> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: audit_log_reason.map(reason_into_header),
+            route: RouteInfo::EditGuildWelcomeScreen { guild_id },
+        }).await
+    }
+
     /// Does specific actions to a member.
-    pub async fn edit_member(&self, guild_id: u64, user_id: u64, map: &JsonMap) -> Result<()> {
+    pub async fn edit_member(&self, guild_id: u64, user_id: u64, map: &JsonMap, audit_log_reason: Option<&str>) -> Result<()> {
         let body = serde_json::to_vec(map)?;
 
         self.wind(204, Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::EditMember { guild_id, user_id },
         }).await
     }
@@ -647,13 +686,13 @@ impl Http {
     /// Pass `None` to reset the nickname.
     ///
     /// [`Guild`]: ../../model/guild/struct.Guild.html
-    pub async fn edit_nickname(&self, guild_id: u64, new_nickname: Option<&str>) -> Result<()> {
+    pub async fn edit_nickname(&self, guild_id: u64, new_nickname: Option<&str>, audit_log_reason: Option<&str>) -> Result<()> {
         let map = json!({ "nick": new_nickname });
         let body = serde_json::to_vec(&map)?;
 
         self.wind(200, Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::EditNickname { guild_id },
         }).await
     }
@@ -672,11 +711,11 @@ impl Http {
     }
 
     /// Changes a role in a guild.
-    pub async fn edit_role(&self, guild_id: u64, role_id: u64, map: &JsonMap) -> Result<Role> {
+    pub async fn edit_role(&self, guild_id: u64, role_id: u64, map: &JsonMap, audit_log_reason: Option<&str>) -> Result<Role> {
         let body = serde_json::to_vec(&map)?;
         let mut value = self.request(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::EditRole { guild_id, role_id },
         }).await?.json::<Value>().await?;
 
@@ -688,7 +727,7 @@ impl Http {
     }
 
     /// Changes the position of a role in a guild.
-    pub async fn edit_role_position(&self, guild_id: u64, role_id: u64, position: u64) -> Result<Vec<Role>> {
+    pub async fn edit_role_position(&self, guild_id: u64, role_id: u64, position: u64, audit_log_reason: Option<&str>) -> Result<Vec<Role>> {
         let body = serde_json::to_vec(&json!([{
             "id": role_id,
             "position": position,
@@ -696,7 +735,7 @@ impl Http {
 
         let mut value = self.request(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::EditRolePosition { guild_id },
         }).await?.json::<Value>().await?;
 
@@ -741,17 +780,17 @@ impl Http {
     ///     "avatar": image,
     /// });
     ///
-    /// let edited = http.edit_webhook(id, &map).await?;
+    /// let edited = http.edit_webhook(id, &map, None).await?;
     /// #     Ok(())
     /// # }
     /// ```
     ///
     /// [`create_webhook`]: fn.create_webhook.html
     /// [`edit_webhook_with_token`]: fn.edit_webhook_with_token.html
-    pub async fn edit_webhook(&self, webhook_id: u64, map: &Value) -> Result<Webhook> {
+    pub async fn edit_webhook(&self, webhook_id: u64, map: &Value, audit_log_reason: Option<&str>) -> Result<Webhook> {
         self.fire(Request {
             body: Some(map.to_string().as_bytes()),
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::EditWebhook { webhook_id },
         }).await
     }
@@ -872,6 +911,23 @@ impl Http {
             .map_err(From::from)
     }
 
+    /// Follows a news channel, making its crossposted messages be posted in
+    /// the target channel given in the map via a webhook.
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission in the target
+    /// channel.
+    ///
+    /// [Manage Webhooks]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_WEBHOOKS
+    pub async fn follow_news_channel(&self, news_channel_id: u64, map: &Value) -> Result<FollowedChannel> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::FollowNewsChannel { channel_id: news_channel_id },
+        }).await
+    }
+
     /// Gets the active maintenances from Discord's Status API.
     ///
     /// Does not require authentication.
@@ -1118,6 +1174,25 @@ impl Http {
         serde_json::from_value::<Vec<Member>>(value).map_err(From::from)
     }
 
+    /// Gets a guild's membership screening form.
+    pub async fn get_guild_membership_screening(&self, guild_id: u64) -> Result<MembershipScreening> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildMembershipScreening { guild_id },
+        }).await
+    }
+
+    /// Gets guild preview information, which is also available for
+    /// discoverable guilds the current user is not a member of.
+    pub async fn get_guild_preview(&self, guild_id: u64) -> Result<GuildPreview> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildPreview { guild_id },
+        }).await
+    }
+
     /// Gets the amount of users that can be pruned.
     pub async fn get_guild_prune_count(&self, guild_id: u64, map: &Value) -> Result<GuildPrune> {
         // Note for 0.6.x: turn this into a function parameter.
@@ -1198,6 +1273,15 @@ impl Http {
         }).await
     }
 
+    /// Gets a guild's welcome screen.
+    pub async fn get_guild_welcome_screen(&self, guild_id: u64) -> Result<GuildWelcomeScreen> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildWelcomeScreen { guild_id },
+        }).await
+    }
+
     /// Gets a paginated list of the current user's guilds.
     ///
     /// The `limit` has a maximum value of 100.
@@ -1584,19 +1668,19 @@ impl Http {
     }
 
     /// Pins a message in a channel.
-    pub async fn pin_message(&self, channel_id: u64, message_id: u64) -> Result<()> {
+    pub async fn pin_message(&self, channel_id: u64, message_id: u64, audit_log_reason: Option<&str>) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::PinMessage { channel_id, message_id },
         }).await
     }
 
     /// Unbans a user from a guild.
-    pub async fn remove_ban(&self, guild_id: u64, user_id: u64) -> Result<()> {
+    pub async fn remove_ban(&self, guild_id: u64, user_id: u64, audit_log_reason: Option<&str>) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::RemoveBan { guild_id, user_id },
         }).await
     }
@@ -1610,16 +1694,44 @@ impl Http {
     /// [`Member`]: ../../model/guild/struct.Member.html
     /// [`Role`]: ../../model/guild/struct.Role.html
     /// [Manage Roles]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
-    pub async fn remove_member_role(&self, guild_id: u64, user_id: u64, role_id: u64) -> Result<()> {
+    pub async fn remove_member_role(&self, guild_id: u64, user_id: u64, role_id: u64, audit_log_reason: Option<&str>) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::RemoveMemberRole { guild_id, user_id, role_id },
         }).await
     }
 
+    /// Searches the members of a guild whose username or nickname starts with
+    /// the given `query`. Optionally pass a `limit`, defaulting to 1 and with
+    /// a maximum of 1000.
+    pub async fn search_guild_members(
+        &self,
+        guild_id: u64,
+        query: &str,
+        limit: Option<u64>
+    ) -> Result<Vec<Member>> {
+        let mut value = self.request(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::SearchGuildMembers { guild_id, query, limit },
+        }).await?.json::<Value>().await?;
+
+        if let Some(values) = value.as_array_mut() {
+            let num = Value::Number(Number::from(guild_id));
+
+            for value in values {
+                if let Some(element) = value.as_object_mut() {
+                    element.insert("guild_id".to_string(), num.clone());
+                }
+            }
+        }
+
+        serde_json::from_value::<Vec<Member>>(value).map_err(From::from)
+    }
+
     /// Starts removing some members from a guild based on the last time they've been online.
-    pub async fn start_guild_prune(&self, guild_id: u64, map: &Value) -> Result<GuildPrune> {
+    pub async fn start_guild_prune(&self, guild_id: u64, map: &Value, audit_log_reason: Option<&str>) -> Result<GuildPrune> {
         // Note for 0.6.x: turn this into a function parameter.
         #[derive(Deserialize)]
         struct StartGuildPruneRequest {
@@ -1630,7 +1742,7 @@ impl Http {
 
         self.fire(Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::StartGuildPrune {
                 days: req.days,
                 guild_id,
@@ -1639,19 +1751,19 @@ impl Http {
     }
 
     /// Starts syncing an integration with a guild.
-    pub async fn start_integration_sync(&self, guild_id: u64, integration_id: u64) -> Result<()> {
+    pub async fn start_integration_sync(&self, guild_id: u64, integration_id: u64, audit_log_reason: Option<&str>) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::StartIntegrationSync { guild_id, integration_id },
         }).await
     }
 
     /// Unpins a message from a channel.
-    pub async fn unpin_message(&self, channel_id: u64, message_id: u64) -> Result<()> {
+    pub async fn unpin_message(&self, channel_id: u64, message_id: u64, audit_log_reason: Option<&str>) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::UnpinMessage { channel_id, message_id },
         }).await
     }
@@ -1777,6 +1889,23 @@ impl Http {
     }
 }
 
+/// Builds the headers attaching an audit log reason to a request.
+///
+/// Discord expects the `X-Audit-Log-Reason` header to be URL-encoded, so that
+/// reasons may contain any UTF-8 characters.
+fn reason_into_header(reason: &str) -> Headers {
+    let mut headers = Headers::with_capacity(1);
+    let encoded = utf8_percent_encode(reason, NON_ALPHANUMERIC).to_string();
+
+    // Percent-encoding only leaves visible ASCII characters, which are always
+    // valid in a header value.
+    if let Ok(value) = HeaderValue::from_str(&encoded) {
+        headers.insert("X-Audit-Log-Reason", value);
+    }
+
+    headers
+}
+
 #[cfg(not(feature = "native_tls_backend"))]
 fn configure_client_backend(builder: ClientBuilder) -> ClientBuilder {
     builder.use_rustls_tls()
@@ -1804,3 +1933,16 @@ impl Default for Http {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::reason_into_header;
+
+    #[test]
+    fn reason_header_is_percent_encoded() {
+        let headers = reason_into_header("Spam: ÿ\nbye");
+        let value = headers.get("X-Audit-Log-Reason").unwrap();
+
+        assert_eq!(value.to_str().unwrap(), "Spam%3A%20%C3%BF%0Abye");
+    }
+}
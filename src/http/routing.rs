@@ -3,6 +3,7 @@ use std::{
     fmt::{Display, Write},
 };
 use super::LightMethod;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 /// A representation of all routes registered within the library. These are safe
 /// and memory-efficient representations of each path that functions exist for
@@ -17,6 +18,12 @@ pub enum Route {
     ///
     /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
     ChannelsId(u64),
+    /// Route for the `/channels/:channel_id/followers` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdFollowers(u64),
     /// Route for the `/channels/:channel_id/invites` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -56,6 +63,13 @@ pub enum Route {
     ///
     /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
     ChannelsIdMessagesIdAck(u64),
+    /// Route for the `/channels/:channel_id/messages/:message_id/crosspost`
+    /// path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdMessagesIdCrosspost(u64),
     /// Route for the `/channels/:channel_id/messages/:message_id/reactions`
     /// path.
     ///
@@ -203,6 +217,24 @@ pub enum Route {
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdMembersMeNick(u64),
+    /// Route for the `/guilds/:guild_id/members/search` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdMembersSearch(u64),
+    /// Route for the `/guilds/:guild_id/member-verification` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdMemberVerification(u64),
+    /// Route for the `/guilds/:guild_id/preview` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdPreview(u64),
     /// Route for the `/guilds/:guild_id/prune` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -239,6 +271,12 @@ pub enum Route {
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdWebhooks(u64),
+    /// Route for the `/guilds/:guild_id/welcome-screen` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdWelcomeScreen(u64),
     /// Route for the `/invites/:code` path.
     InvitesCode,
     /// Route for the `/users/:user_id` path.
@@ -270,6 +308,10 @@ impl Route {
         format!(api!("/channels/{}"), channel_id)
     }
 
+    pub fn channel_followers(channel_id: u64) -> String {
+        format!(api!("/channels/{}/followers"), channel_id)
+    }
+
     pub fn channel_invites(channel_id: u64) -> String {
         format!(api!("/channels/{}/invites"), channel_id)
     }
@@ -278,6 +320,10 @@ impl Route {
         format!(api!("/channels/{}/messages/{}"), channel_id, message_id)
     }
 
+    pub fn channel_message_crosspost(channel_id: u64, message_id: u64) -> String {
+        format!(api!("/channels/{}/messages/{}/crosspost"), channel_id, message_id)
+    }
+
     pub fn channel_message_reaction<D, T>(
         channel_id: u64,
         message_id: u64,
@@ -425,7 +471,7 @@ impl Route {
             guild_id,
             user_id,
             delete_message_days,
-            reason,
+            utf8_percent_encode(reason, NON_ALPHANUMERIC),
         )
     }
 
@@ -438,7 +484,7 @@ impl Route {
             api!("/guilds/{}/members/{}?reason={}"),
             guild_id,
             user_id,
-            reason,
+            utf8_percent_encode(reason, NON_ALPHANUMERIC),
         )
     }
 
@@ -527,10 +573,33 @@ impl Route {
         s
     }
 
+    pub fn guild_members_search(
+        guild_id: u64,
+        query: &str,
+        limit: Option<u64>,
+    ) -> String {
+        let query: String = url::form_urlencoded::byte_serialize(query.as_bytes()).collect();
+        let mut s = format!(api!("/guilds/{}/members/search?query={}"), guild_id, query);
+
+        if let Some(limit) = limit {
+            let _ = write!(s, "&limit={}", limit);
+        }
+
+        s
+    }
+
+    pub fn guild_member_verification(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/member-verification"), guild_id)
+    }
+
     pub fn guild_nickname(guild_id: u64) -> String {
         format!(api!("/guilds/{}/members/@me/nick"), guild_id)
     }
 
+    pub fn guild_preview(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/preview"), guild_id)
+    }
+
     pub fn guild_prune(guild_id: u64, days: u64) -> String {
         format!(api!("/guilds/{}/prune?days={}"), guild_id, days)
     }
@@ -555,6 +624,10 @@ impl Route {
         format!(api!("/guilds/{}/webhooks"), guild_id)
     }
 
+    pub fn guild_welcome_screen(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/welcome-screen"), guild_id)
+    }
+
     pub fn guilds() -> &'static str {
         api!("/guilds")
     }
@@ -690,6 +763,10 @@ pub enum RouteInfo<'a> {
     CreateWebhook {
         channel_id: u64,
     },
+    CrosspostMessage {
+        channel_id: u64,
+        message_id: u64,
+    },
     DeleteChannel {
         channel_id: u64,
     },
@@ -760,6 +837,12 @@ pub enum RouteInfo<'a> {
     EditGuildEmbed {
         guild_id: u64,
     },
+    EditGuildMembershipScreening {
+        guild_id: u64,
+    },
+    EditGuildWelcomeScreen {
+        guild_id: u64,
+    },
     EditMember {
         guild_id: u64,
         user_id: u64,
@@ -791,6 +874,9 @@ pub enum RouteInfo<'a> {
         wait: bool,
         webhook_id: u64,
     },
+    FollowNewsChannel {
+        channel_id: u64,
+    },
     GetActiveMaintenance,
     GetAuditLogs {
         action_type: Option<u8>,
@@ -842,6 +928,12 @@ pub enum RouteInfo<'a> {
         limit: Option<u64>,
         guild_id: u64,
     },
+    GetGuildMembershipScreening {
+        guild_id: u64,
+    },
+    GetGuildPreview {
+        guild_id: u64,
+    },
     GetGuildPruneCount {
         days: u64,
         guild_id: u64,
@@ -858,6 +950,9 @@ pub enum RouteInfo<'a> {
     GetGuildWebhooks {
         guild_id: u64,
     },
+    GetGuildWelcomeScreen {
+        guild_id: u64,
+    },
     GetGuilds {
         after: Option<u64>,
         before: Option<u64>,
@@ -927,6 +1022,11 @@ pub enum RouteInfo<'a> {
         role_id: u64,
         user_id: u64,
     },
+    SearchGuildMembers {
+        guild_id: u64,
+        query: &'a str,
+        limit: Option<u64>,
+    },
     StartGuildPrune {
         days: u64,
         guild_id: u64,
@@ -1035,6 +1135,11 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsIdWebhooks(channel_id),
                 Cow::from(Route::channel_webhooks(channel_id)),
             ),
+            RouteInfo::CrosspostMessage { channel_id, message_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdMessagesIdCrosspost(channel_id),
+                Cow::from(Route::channel_message_crosspost(channel_id, message_id)),
+            ),
             RouteInfo::DeleteChannel { channel_id } => (
                 LightMethod::Delete,
                 Route::ChannelsId(channel_id),
@@ -1147,6 +1252,16 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmbed(guild_id),
                 Cow::from(Route::guild_embed(guild_id)),
             ),
+            RouteInfo::EditGuildMembershipScreening { guild_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdMemberVerification(guild_id),
+                Cow::from(Route::guild_member_verification(guild_id)),
+            ),
+            RouteInfo::EditGuildWelcomeScreen { guild_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdWelcomeScreen(guild_id),
+                Cow::from(Route::guild_welcome_screen(guild_id)),
+            ),
             RouteInfo::EditMember { guild_id, user_id } => (
                 LightMethod::Patch,
                 Route::GuildsIdMembersId(guild_id),
@@ -1196,6 +1311,11 @@ impl<'a> RouteInfo<'a> {
                     wait,
                 )),
             ),
+            RouteInfo::FollowNewsChannel { channel_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdFollowers(channel_id),
+                Cow::from(Route::channel_followers(channel_id)),
+            ),
             RouteInfo::GetActiveMaintenance => (
                 LightMethod::Get,
                 Route::None,
@@ -1298,6 +1418,16 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembers(guild_id),
                 Cow::from(Route::guild_members_optioned(guild_id, after, limit)),
             ),
+            RouteInfo::GetGuildMembershipScreening { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdMemberVerification(guild_id),
+                Cow::from(Route::guild_member_verification(guild_id)),
+            ),
+            RouteInfo::GetGuildPreview { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdPreview(guild_id),
+                Cow::from(Route::guild_preview(guild_id)),
+            ),
             RouteInfo::GetGuildPruneCount { days, guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdPrune(guild_id),
@@ -1323,6 +1453,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdWebhooks(guild_id),
                 Cow::from(Route::guild_webhooks(guild_id)),
             ),
+            RouteInfo::GetGuildWelcomeScreen { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdWelcomeScreen(guild_id),
+                Cow::from(Route::guild_welcome_screen(guild_id)),
+            ),
             RouteInfo::GetGuilds { after, before, limit } => (
                 LightMethod::Get,
                 Route::UsersMeGuilds,
@@ -1447,6 +1582,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersIdRolesId(guild_id),
                 Cow::from(Route::guild_member_role(guild_id, user_id, role_id)),
             ),
+            RouteInfo::SearchGuildMembers { guild_id, query, limit } => (
+                LightMethod::Get,
+                Route::GuildsIdMembersSearch(guild_id),
+                Cow::from(Route::guild_members_search(guild_id, query, limit)),
+            ),
             RouteInfo::StartGuildPrune { days, guild_id } => (
                 LightMethod::Post,
                 Route::GuildsIdPrune(guild_id),
@@ -71,7 +71,29 @@ impl ChannelId {
     /// [`PermissionOverwrite`]: ../channel/struct.PermissionOverwrite.html
     /// [`Role`]: ../guild/struct.Role.html
     /// [Manage Channels]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    #[inline]
     pub async fn create_permission(self, http: impl AsRef<Http>, target: &PermissionOverwrite) -> Result<()> {
+        self._create_permission(http.as_ref(), target, None).await
+    }
+
+    /// Creates a [permission overwrite][`PermissionOverwrite`] with a reason
+    /// shown in the guild's audit log.
+    ///
+    /// Refer to [`create_permission`] for more information.
+    ///
+    /// [`create_permission`]: #method.create_permission
+    /// [`PermissionOverwrite`]: ../channel/struct.PermissionOverwrite.html
+    #[inline]
+    pub async fn create_permission_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        target: &PermissionOverwrite,
+        reason: &str,
+    ) -> Result<()> {
+        self._create_permission(http.as_ref(), target, Some(reason)).await
+    }
+
+    async fn _create_permission(self, http: &Http, target: &PermissionOverwrite, reason: Option<&str>) -> Result<()> {
         let (id, kind) = match target.kind {
             PermissionOverwriteType::Member(id) => (id.0, "member"),
             PermissionOverwriteType::Role(id) => (id.0, "role"),
@@ -85,7 +107,7 @@ impl ChannelId {
             "type": kind,
         });
 
-        http.as_ref().create_permission(self.0, id, &map).await
+        http.create_permission(self.0, id, &map, reason).await
     }
 
     /// React to a [`Message`] with a custom [`Emoji`] or unicode character.
@@ -110,10 +132,32 @@ impl ChannelId {
         http.as_ref().create_reaction(self.0, message_id.into().0, &reaction_type.into()).await
     }
 
+    /// Crossposts a [`Message`], publishing it to every channel following
+    /// this news channel.
+    ///
+    /// **Note**: Requires the [Send Messages] permission for messages sent by
+    /// the current user, and the [Manage Messages] permission otherwise.
+    ///
+    /// **Note**: Only available on news channels.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    #[inline]
+    pub async fn crosspost(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>) -> Result<Message> {
+        http.as_ref().crosspost_message(self.0, message_id.into().0).await
+    }
+
     /// Deletes this channel, returning the channel on a successful deletion.
     #[inline]
     pub async fn delete(self, http: impl AsRef<Http>) -> Result<Channel> {
-        http.as_ref().delete_channel(self.0).await
+        http.as_ref().delete_channel(self.0, None).await
+    }
+
+    /// Deletes this channel with a reason shown in the guild's audit log.
+    #[inline]
+    pub async fn delete_with_reason(self, http: impl AsRef<Http>, reason: &str) -> Result<Channel> {
+        http.as_ref().delete_channel(self.0, Some(reason)).await
     }
 
     /// Deletes a [`Message`] given its Id.
@@ -128,7 +172,24 @@ impl ChannelId {
     /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
     #[inline]
     pub async fn delete_message(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>) -> Result<()> {
-        http.as_ref().delete_message(self.0, message_id.into().0).await
+        http.as_ref().delete_message(self.0, message_id.into().0, None).await
+    }
+
+    /// Deletes a [`Message`] given its Id, with a reason shown in the guild's
+    /// audit log.
+    ///
+    /// **Note**: Discord only logs the deletion of messages sent by other
+    /// users.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    #[inline]
+    pub async fn delete_message_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reason: &str,
+    ) -> Result<()> {
+        http.as_ref().delete_message(self.0, message_id.into().0, Some(reason)).await
     }
 
     /// Deletes all messages by Ids from the given vector in the given channel.
@@ -149,8 +210,33 @@ impl ChannelId {
     /// [`Channel::delete_messages`]: ../channel/enum.Channel.html#method.delete_messages
     /// [`ModelError::BulkDeleteAmount`]: ../error/enum.Error.html#variant.BulkDeleteAmount
     /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    #[inline]
     pub async fn delete_messages<T, It>(self, http: impl AsRef<Http>, message_ids: It) -> Result<()>
     where T: AsRef<MessageId>, It: IntoIterator<Item=T>,
+    {
+        self._delete_messages(http.as_ref(), message_ids, None).await
+    }
+
+    /// Deletes all messages by Ids from the given vector in the given channel,
+    /// with a reason shown in the guild's audit log.
+    ///
+    /// Refer to [`delete_messages`] for more information.
+    ///
+    /// [`delete_messages`]: #method.delete_messages
+    #[inline]
+    pub async fn delete_messages_with_reason<T, It>(
+        self,
+        http: impl AsRef<Http>,
+        message_ids: It,
+        reason: &str,
+    ) -> Result<()>
+    where T: AsRef<MessageId>, It: IntoIterator<Item=T>,
+    {
+        self._delete_messages(http.as_ref(), message_ids, Some(reason)).await
+    }
+
+    async fn _delete_messages<T, It>(self, http: &Http, message_ids: It, reason: Option<&str>) -> Result<()>
+    where T: AsRef<MessageId>, It: IntoIterator<Item=T>,
     {
         let ids = message_ids
             .into_iter()
@@ -164,11 +250,11 @@ impl ChannelId {
         }
 
         if ids.len() == 1 {
-            self.delete_message(http, ids[0]).await
+            http.delete_message(self.0, ids[0], reason).await
         } else {
             let map = json!({ "messages": ids });
 
-            http.as_ref().delete_messages(self.0, &map).await
+            http.delete_messages(self.0, &map, reason).await
         }
     }
 
@@ -177,18 +263,45 @@ impl ChannelId {
     /// **Note**: Requires the [Manage Channel] permission.
     ///
     /// [Manage Channel]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    #[inline]
     pub async fn delete_permission(
         self,
         http: impl AsRef<Http>,
         permission_type: PermissionOverwriteType
     ) -> Result<()> {
-        http.as_ref().delete_permission(
+        self._delete_permission(http.as_ref(), permission_type, None).await
+    }
+
+    /// Deletes all permission overrides in the channel from a member or role,
+    /// with a reason shown in the guild's audit log.
+    ///
+    /// **Note**: Requires the [Manage Channel] permission.
+    ///
+    /// [Manage Channel]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    #[inline]
+    pub async fn delete_permission_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        permission_type: PermissionOverwriteType,
+        reason: &str,
+    ) -> Result<()> {
+        self._delete_permission(http.as_ref(), permission_type, Some(reason)).await
+    }
+
+    async fn _delete_permission(
+        self,
+        http: &Http,
+        permission_type: PermissionOverwriteType,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        http.delete_permission(
             self.0,
             match permission_type {
                 PermissionOverwriteType::Member(id) => id.0,
                 PermissionOverwriteType::Role(id) => id.0,
                 PermissionOverwriteType::__Nonexhaustive => unreachable!(),
             },
+            reason,
         ).await
     }
 
@@ -270,7 +383,7 @@ impl ChannelId {
 
         let map = utils::hashmap_to_json_map(channel.0);
 
-        http.as_ref().edit_channel(self.0, &map).await
+        http.as_ref().edit_channel(self.0, &map, channel.1.as_deref()).await
     }
 
     /// Edits a [`Message`] in the channel given its Id.
@@ -462,12 +575,38 @@ impl ChannelId {
         })
     }
 
+    /// Follows this news channel, making messages crossposted in it be posted
+    /// in the `target_channel_id` as well.
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission in the target
+    /// channel.
+    ///
+    /// **Note**: Only available on news channels.
+    ///
+    /// [Manage Webhooks]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_WEBHOOKS
+    pub async fn follow(self, http: impl AsRef<Http>, target_channel_id: impl Into<ChannelId>) -> Result<FollowedChannel> {
+        let map = json!({
+            "webhook_channel_id": target_channel_id.into().0,
+        });
+
+        http.as_ref().follow_news_channel(self.0, &map).await
+    }
+
     /// Pins a [`Message`] to the channel.
     ///
     /// [`Message`]: ../channel/struct.Message.html
     #[inline]
     pub async fn pin(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>) -> Result<()> {
-        http.as_ref().pin_message(self.0, message_id.into().0).await
+        http.as_ref().pin_message(self.0, message_id.into().0, None).await
+    }
+
+    /// Pins a [`Message`] to the channel, with a reason shown in the guild's
+    /// audit log.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    #[inline]
+    pub async fn pin_with_reason(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>, reason: &str) -> Result<()> {
+        http.as_ref().pin_message(self.0, message_id.into().0, Some(reason)).await
     }
 
     /// Gets the list of [`Message`]s which are pinned to the channel.
@@ -684,7 +823,19 @@ impl ChannelId {
     /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
     #[inline]
     pub async fn unpin(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>) -> Result<()> {
-        http.as_ref().unpin_message(self.0, message_id.into().0).await
+        http.as_ref().unpin_message(self.0, message_id.into().0, None).await
+    }
+
+    /// Unpins a [`Message`] in the channel given by its Id, with a reason shown
+    /// in the guild's audit log.
+    ///
+    /// Requires the [Manage Messages] permission.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    #[inline]
+    pub async fn unpin_with_reason(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>, reason: &str) -> Result<()> {
+        http.as_ref().unpin_message(self.0, message_id.into().0, Some(reason)).await
     }
 
     /// Retrieves the channel's webhooks.
@@ -157,7 +157,7 @@ impl GuildChannel {
 
         let map = serenity_utils::hashmap_to_json_map(invite.0);
 
-        cache_http.http().create_invite(self.id.0, &map).await
+        cache_http.http().create_invite(self.id.0, &map, invite.1.as_deref()).await
     }
 
     /// Creates a [permission overwrite][`PermissionOverwrite`] for either a
@@ -372,7 +372,7 @@ impl GuildChannel {
         f(&mut edit_channel);
         let edited = serenity_utils::hashmap_to_json_map(edit_channel.0);
 
-        *self = cache_http.http().edit_channel(self.id.0, &edited).await?;
+        *self = cache_http.http().edit_channel(self.id.0, &edited, edit_channel.1.as_deref()).await?;
 
         Ok(())
     }
@@ -560,7 +560,11 @@ impl GuildChannel {
     /// Returns a [`ModelError::GuildNotFound`] if the channel's guild could
     /// not be found in the [`Cache`].
     ///
+    /// Use [`Cache::member_permissions`] instead to get `None` if the member,
+    /// one of their roles or the @everyone role is not cached either.
+    ///
     /// [`Cache`]: ../../cache/struct.Cache.html
+    /// [`Cache::member_permissions`]: ../../cache/struct.Cache.html#method.member_permissions
     /// [`ModelError::GuildNotFound`]: ../error/enum.Error.html#variant.GuildNotFound
     /// [`Guild`]: ../guild/struct.Guild.html
     /// [`Member`]: ../guild/struct.Member.html
@@ -571,8 +575,13 @@ impl GuildChannel {
     #[cfg(feature = "cache")]
     #[inline]
     pub async fn permissions_for_user(&self, cache: impl AsRef<Cache>, user_id: impl Into<UserId>) -> Result<Permissions> {
-        let guild = self.guild(&cache).await.ok_or(Error::Model(ModelError::GuildNotFound))?;
-        Ok(guild.user_permissions_in(self.id, user_id))
+        let (channel_id, user_id) = (self.id, user_id.into());
+
+        cache
+            .as_ref()
+            .guild_field(self.guild_id, |guild| guild.user_permissions_in(channel_id, user_id))
+            .await
+            .ok_or(Error::Model(ModelError::GuildNotFound))
     }
 
     /// Calculates the permissions of a role.
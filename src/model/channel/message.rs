@@ -108,8 +108,14 @@ pub struct Message {
     pub activity: Option<MessageActivity>,
     /// Sent with Rich Presence-related chat embeds.
     pub application: Option<MessageApplication>,
-    /// Reference data sent with crossposted messages.
+    /// Reference data sent with crossposted messages and inline replies.
     pub message_reference: Option<MessageReference>,
+    /// The message that was replied to using this message, if this message is
+    /// an inline reply.
+    ///
+    /// This is `None` if the referenced message was deleted, or if Discord did
+    /// not attempt to resolve it.
+    pub referenced_message: Option<Box<Message>>,
     /// Bit flags describing extra features of the message.
     pub flags: Option<MessageFlags>,
     #[serde(skip)]
@@ -134,6 +140,58 @@ impl Message {
         self.author.id == cache.as_ref().current_user().await.id
     }
 
+    /// Crossposts this message, publishing it to every channel following the
+    /// news channel it was sent in.
+    ///
+    /// **Note**: The logged in user must either be the author of the message
+    /// and have the [Send Messages] permission, or have the [Manage Messages]
+    /// permission.
+    ///
+    /// **Note**: Only available on news channels.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` feature is enabled, then returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// Returns a [`ModelError::MessageAlreadyCrossposted`] if the message has
+    /// already been crossposted, and a [`ModelError::CannotCrosspostMessage`]
+    /// if the message is itself a crosspost from another channel.
+    ///
+    /// [`ModelError::CannotCrosspostMessage`]: ../error/enum.Error.html#variant.CannotCrosspostMessage
+    /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
+    /// [`ModelError::MessageAlreadyCrossposted`]: ../error/enum.Error.html#variant.MessageAlreadyCrossposted
+    /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    pub async fn crosspost(&self, cache_http: impl CacheHttp) -> Result<Message> {
+        if let Some(flags) = self.flags {
+            if flags.contains(MessageFlags::CROSSPOSTED) {
+                return Err(Error::Model(ModelError::MessageAlreadyCrossposted));
+            } else if flags.contains(MessageFlags::IS_CROSSPOST) {
+                return Err(Error::Model(ModelError::CannotCrosspostMessage));
+            }
+        }
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                let is_author = self.author.id == cache.current_user().await.id;
+                let req = if is_author {
+                    Permissions::SEND_MESSAGES
+                } else {
+                    Permissions::MANAGE_MESSAGES
+                };
+
+                if !utils::user_has_perms(&cache, self.channel_id, self.guild_id, req).await? {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.channel_id.crosspost(cache_http.http(), self.id).await
+    }
+
     /// Deletes the message.
     ///
     /// **Note**: The logged in user must either be the author of the message or
@@ -410,7 +468,7 @@ impl Message {
     /// [`guild_id`]: #method.guild_id
     #[cfg(feature = "cache")]
     pub async fn guild_field<Ret, Fun>(&self, cache: impl AsRef<Cache>, field_accessor: Fun) -> Option<Ret>
-    where Ret: Clone, Fun: FnOnce(&Guild) -> Ret
+    where Ret: Clone + Send, Fun: FnOnce(&Guild) -> Ret + Send
     {
         cache
             .as_ref()
@@ -468,7 +526,23 @@ impl Message {
     ///
     /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
     /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES.html
+    #[inline]
     pub async fn pin(&self, cache_http: impl CacheHttp) -> Result<()> {
+        self._pin(cache_http, None).await
+    }
+
+    /// Pins this message to its channel, with a reason shown in the guild's
+    /// audit log.
+    ///
+    /// Refer to [`pin`] for more information.
+    ///
+    /// [`pin`]: #method.pin
+    #[inline]
+    pub async fn pin_with_reason(&self, cache_http: impl CacheHttp, reason: &str) -> Result<()> {
+        self._pin(cache_http, Some(reason)).await
+    }
+
+    async fn _pin(&self, cache_http: impl CacheHttp, reason: Option<&str>) -> Result<()> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -482,7 +556,7 @@ impl Message {
             }
         }
 
-        self.channel_id.pin(cache_http.http(), self.id.0).await
+        cache_http.http().pin_message(self.channel_id.0, self.id.0, reason).await
     }
 
     /// React to the message with a custom [`Emoji`] or unicode character.
@@ -542,6 +616,9 @@ impl Message {
     ///
     /// User mentions are generally around 20 or 21 characters long.
     ///
+    /// Refer to [`reply_inline`] to send an inline reply referencing this
+    /// message instead.
+    ///
     /// **Note**: Requires the [Send Messages] permission.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
@@ -558,8 +635,60 @@ impl Message {
     ///
     /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
     /// [`ModelError::MessageTooLong`]: ../error/enum.Error.html#variant.MessageTooLong
+    /// [`reply_inline`]: #method.reply_inline
     /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    #[inline]
     pub async fn reply(&self, cache_http: impl CacheHttp, content: impl Display) -> Result<Message> {
+        self._reply(cache_http, content, None).await
+    }
+
+    /// Replies to the message as an inline reply, referencing this message
+    /// without mentioning its author.
+    ///
+    /// Refer to [`reply_ping`] to also mention the author.
+    ///
+    /// **Note**: Requires the [Send Messages] and [Read Message History]
+    /// permissions.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] if the content of the message
+    /// is over the above limit, containing the number of unicode code points
+    /// over the limit.
+    ///
+    /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
+    /// [`ModelError::MessageTooLong`]: ../error/enum.Error.html#variant.MessageTooLong
+    /// [`reply_ping`]: #method.reply_ping
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    #[inline]
+    pub async fn reply_inline(&self, cache_http: impl CacheHttp, content: impl Display) -> Result<Message> {
+        self._reply(cache_http, content, Some(false)).await
+    }
+
+    /// Replies to the message as an inline reply, referencing this message
+    /// and mentioning its author.
+    ///
+    /// Refer to [`reply_inline`] for more information.
+    ///
+    /// [`reply_inline`]: #method.reply_inline
+    #[inline]
+    pub async fn reply_ping(&self, cache_http: impl CacheHttp, content: impl Display) -> Result<Message> {
+        self._reply(cache_http, content, Some(true)).await
+    }
+
+    /// Sends a reply to the message.
+    ///
+    /// If `inlined` is `None`, the author is mentioned in front of the content.
+    /// Otherwise, the reply references this message, mentioning the author
+    /// depending on the inner value.
+    async fn _reply(&self, cache_http: impl CacheHttp, content: impl Display, inlined: Option<bool>) -> Result<Message> {
         if let Some(length_over) = Message::overflow_length(&content.to_string()) {
             return Err(Error::Model(ModelError::MessageTooLong(length_over)));
         }
@@ -569,7 +698,11 @@ impl Message {
             if let Some(cache) = cache_http.cache() {
 
                 if self.guild_id.is_some() {
-                    let req = Permissions::SEND_MESSAGES;
+                    let req = if inlined.is_some() {
+                        Permissions::SEND_MESSAGES | Permissions::READ_MESSAGE_HISTORY
+                    } else {
+                        Permissions::SEND_MESSAGES
+                    };
 
                     if !super::utils::user_has_perms(cache, self.channel_id, self.guild_id, req).await? {
                         return Err(Error::Model(ModelError::InvalidPermissions(req)));
@@ -578,12 +711,24 @@ impl Message {
             }
         }
 
-        let gen = format!("{} {}", self.author.mention(), content);
-
-        let map = json!({
-            "content": gen,
-            "tts": false,
-        });
+        let map = match inlined {
+            Some(ping_author) => json!({
+                "content": content.to_string(),
+                "tts": false,
+                "message_reference": {
+                    "message_id": self.id.0,
+                    "channel_id": self.channel_id.0,
+                },
+                "allowed_mentions": {
+                    "parse": ["everyone", "users", "roles"],
+                    "replied_user": ping_author,
+                },
+            }),
+            None => json!({
+                "content": format!("{} {}", self.author.mention(), content),
+                "tts": false,
+            }),
+        };
 
         cache_http.http().send_message(self.channel_id.0, &map).await
     }
@@ -668,7 +813,23 @@ impl Message {
     ///
     /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
     /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    #[inline]
     pub async fn unpin(&self, cache_http: impl CacheHttp) -> Result<()> {
+        self._unpin(cache_http, None).await
+    }
+
+    /// Unpins the message from its channel, with a reason shown in the guild's
+    /// audit log.
+    ///
+    /// Refer to [`unpin`] for more information.
+    ///
+    /// [`unpin`]: #method.unpin
+    #[inline]
+    pub async fn unpin_with_reason(&self, cache_http: impl CacheHttp, reason: &str) -> Result<()> {
+        self._unpin(cache_http, Some(reason)).await
+    }
+
+    async fn _unpin(&self, cache_http: impl CacheHttp, reason: Option<&str>) -> Result<()> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -682,7 +843,7 @@ impl Message {
             }
         }
 
-        cache_http.http().unpin_message(self.channel_id.0, self.id.0).await
+        cache_http.http().unpin_message(self.channel_id.0, self.id.0, reason).await
     }
 
     /// Tries to return author's nickname in the current channel's guild.
@@ -845,6 +1006,8 @@ pub enum MessageType {
     NitroTier2 = 10,
     /// An indicator that the guild has reached nitro tier 3
     NitroTier3 = 11,
+    /// An inline reply to another message.
+    InlineReply = 19,
     #[doc(hidden)]
     __Nonexhaustive,
 }
@@ -863,6 +1026,7 @@ enum_number!(
         NitroTier1,
         NitroTier2,
         NitroTier3,
+        InlineReply,
     }
 );
 
@@ -883,6 +1047,7 @@ impl MessageType {
             NitroTier1 => 9,
             NitroTier2 => 10,
             NitroTier3 => 11,
+            InlineReply => 19,
             __Nonexhaustive => unreachable!(),
         }
     }
@@ -951,7 +1116,7 @@ pub struct MessageActivity {
     pub(crate) _nonexhaustive: (),
 }
 
-/// Reference data sent with crossposted messages.
+/// Reference data sent with crossposted messages and inline replies.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageReference {
     /// ID of the originating message.
@@ -964,6 +1129,28 @@ pub struct MessageReference {
     pub(crate) _nonexhaustive: (),
 }
 
+impl From<&Message> for MessageReference {
+    fn from(m: &Message) -> Self {
+        Self {
+            message_id: Some(m.id),
+            channel_id: m.channel_id,
+            guild_id: m.guild_id,
+            _nonexhaustive: (),
+        }
+    }
+}
+
+impl From<(ChannelId, MessageId)> for MessageReference {
+    fn from(pair: (ChannelId, MessageId)) -> Self {
+        Self {
+            message_id: Some(pair.1),
+            channel_id: pair.0,
+            guild_id: None,
+            _nonexhaustive: (),
+        }
+    }
+}
+
 /// Channel Mention Object
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChannelMention {
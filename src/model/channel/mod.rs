@@ -365,6 +365,19 @@ impl ChannelType {
     }
 }
 
+/// The result of following a news channel, created via
+/// [`ChannelId::follow`].
+///
+/// [`ChannelId::follow`]: ../id/struct.ChannelId.html#method.follow
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct FollowedChannel {
+    /// The source news channel.
+    pub channel_id: ChannelId,
+    /// The created webhook which posts crossposted messages in the target
+    /// channel.
+    pub webhook_id: WebhookId,
+}
+
 #[derive(Deserialize, Serialize)]
 struct PermissionOverwriteData {
     allow: Permissions,
@@ -127,6 +127,16 @@ pub enum Error {
     ///
     /// [`ChannelType`]: ../channel/enum.ChannelType.html
     InvalidChannelType,
+    /// Indicates that the [`Message`] has already been crossposted to the
+    /// channels following its news channel.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    MessageAlreadyCrossposted,
+    /// Indicates that the [`Message`] cannot be crossposted, as it is itself a
+    /// crosspost from another channel.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    CannotCrosspostMessage,
     #[doc(hidden)]
     __Nonexhaustive,
 }
@@ -146,6 +156,8 @@ impl Display for Error {
             Error::ItemMissing => f.write_str("The required item is missing from the cache."),
             Error::MessageTooLong(_) => f.write_str("Message too large."),
             Error::MessagingBot => f.write_str("Attempted to message another bot user."),
+            Error::MessageAlreadyCrossposted => f.write_str("Message already crossposted."),
+            Error::CannotCrosspostMessage => f.write_str("Cannot crosspost this message type."),
             Error::__Nonexhaustive => unreachable!(),
         }
     }
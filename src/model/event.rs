@@ -15,12 +15,57 @@ use crate::constants::{OpCode, VoiceOpCode};
 use crate::internal::prelude::*;
 
 #[cfg(feature = "cache")]
-use crate::cache::{Cache, CacheUpdate};
+use crate::cache::{has_capacity, is_watched, notify, truncate_map, Cache, CacheResources, CacheUpdate, Settings};
 #[cfg(feature = "cache")]
-use std::mem;
+use std::{iter, mem};
 #[cfg(feature = "cache")]
 use async_trait::async_trait;
 
+/// The guild a channel belongs to, used as context for channel changes.
+#[cfg(feature = "cache")]
+fn channel_guild_id(channel: &Channel) -> Option<GuildId> {
+    match channel {
+        Channel::Guild(channel) => Some(channel.guild_id),
+        Channel::Category(category) => Some(category.guild_id),
+        _ => None,
+    }
+}
+
+/// Drops the parts of a received guild that the cache is configured not to
+/// store.
+#[cfg(feature = "cache")]
+fn strip_uncached(guild: &mut Guild, settings: &Settings, current_user_id: UserId) {
+    // The current user's own member is always kept, as it is needed to
+    // compute the current user's permissions.
+    if settings.caches(CacheResources::MEMBERS) {
+        truncate_map(&mut guild.members, settings.max_members_per_guild, Some(&current_user_id));
+    } else {
+        guild.members.retain(|id, _| *id == current_user_id);
+    }
+
+    if settings.caches(CacheResources::PRESENCES) {
+        truncate_map(&mut guild.presences, settings.max_presences, None);
+    } else {
+        guild.presences.clear();
+    }
+
+    if !settings.caches(CacheResources::CHANNELS) {
+        guild.channels.clear();
+    }
+
+    if !settings.caches(CacheResources::ROLES) {
+        guild.roles.clear();
+    }
+
+    if !settings.caches(CacheResources::EMOJIS) {
+        guild.emojis.clear();
+    }
+
+    if !settings.caches(CacheResources::VOICE_STATES) {
+        guild.voice_states.clear();
+    }
+}
+
 /// Event data for the channel creation event.
 ///
 /// This is fired when:
@@ -58,23 +103,24 @@ impl Serialize for ChannelCreateEvent {
 #[async_trait]
 impl CacheUpdate for ChannelCreateEvent {
     type Output = Channel;
+    const KIND: &'static str = "ChannelCreateEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        match self.channel {
+        if !cache.settings().await.caches(CacheResources::CHANNELS) {
+            return None;
+        }
+
+        let old_channel = match self.channel {
             Channel::Guild(ref channel) => {
                 let (guild_id, channel_id) = (channel.guild_id, channel.id);
 
-                let old_channel = cache
-                    .guilds
-                    .write().await
-                    .get_mut(&guild_id)
-                    .and_then(|g| g.channels.insert(channel_id, channel.clone()))
-                    .map(Channel::Guild);
+                let mut old_channel = None;
 
-                cache
-                    .channels
-                    .write().await
-                    .insert(channel_id, channel.clone());
+                cache.backend.update_guild(guild_id, &mut |g| {
+                    old_channel = g.channels.insert(channel_id, channel.clone()).map(Channel::Guild);
+                }).await;
+
+                cache.backend.insert_guild_channel(channel.clone()).await;
 
                 old_channel
             },
@@ -91,21 +137,29 @@ impl CacheUpdate for ChannelCreateEvent {
                     };
 
                     cache
-                        .users
-                        .read()
+                        .backend
+                        .user(user_id)
                         .await
-                        .get(&user_id)
-                        .map(|u| channel.recipient = u.clone());
+                        .map(|u| channel.recipient = u);
 
                     channel.id
                 };
 
-                cache
+                cache.reference_users(iter::once(channel.recipient.id)).await;
+
+                let old_channel = cache
                     .private_channels
                     .write()
                     .await
-                    .insert(id, channel.clone())
-                    .map(Channel::Private)
+                    .insert(id, channel.clone());
+
+                if let Some(ref old_channel) = old_channel {
+                    cache.release_users(iter::once(old_channel.recipient.id)).await;
+                }
+
+                cache.evict_users().await;
+
+                old_channel.map(Channel::Private)
             },
             Channel::Category(ref category) => {
                 cache
@@ -116,7 +170,13 @@ impl CacheUpdate for ChannelCreateEvent {
                     .map(Channel::Category)
             },
             Channel::__Nonexhaustive => unreachable!(),
+        };
+
+        if is_watched(&cache.changes.channels) {
+            notify(&cache.changes.channels, channel_guild_id(&self.channel), old_channel.clone(), Some(self.channel.clone()));
         }
+
+        old_channel
     }
 }
 
@@ -130,42 +190,50 @@ pub struct ChannelDeleteEvent {
 #[async_trait]
 impl CacheUpdate for ChannelDeleteEvent {
     type Output = ();
+    const KIND: &'static str = "ChannelDeleteEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
-        match self.channel {
+        // Channels are removed regardless of `CacheResources::CHANNELS`, as
+        // removing what was never cached is a no-op.
+        let old_channel = match self.channel {
             Channel::Guild(ref channel) => {
                 let (guild_id, channel_id) = (channel.guild_id, channel.id);
 
-                cache
-                    .channels
-                    .write()
-                    .await
-                    .remove(&channel_id);
+                cache.backend.update_guild(guild_id, &mut |g| {
+                    g.channels.remove(&channel_id);
+                }).await;
 
-                cache
-                    .guilds
-                    .write()
-                    .await
-                    .get_mut(&guild_id)
-                    .map(|g| g.channels.remove(&channel_id));
+                cache.backend.remove_guild_channel(channel_id).await.map(Channel::Guild)
             },
             Channel::Category(ref category) => {
                 let channel_id = category.id;
 
-                cache.categories.write().await.remove(&channel_id);
+                cache.categories.write().await.remove(&channel_id).map(Channel::Category)
             },
             Channel::Private(ref channel) => {
                 let id = {
                     channel.id
                 };
 
-                cache.private_channels.write().await.remove(&id);
+                let old_channel = cache.private_channels.write().await.remove(&id);
+
+                if let Some(ref old_channel) = old_channel {
+                    cache.release_users(iter::once(old_channel.recipient.id)).await;
+                }
+
+                cache.evict_users().await;
+
+                old_channel.map(Channel::Private)
             },
             Channel::__Nonexhaustive => unreachable!(),
         };
 
+        if is_watched(&cache.changes.channels) {
+            notify(&cache.changes.channels, channel_guild_id(&self.channel), old_channel, None);
+        }
+
         // Remove the cached messages for the channel.
-        cache.messages.write().await.remove(&self.channel.id());
+        cache.backend.remove_channel_messages(self.channel.id()).await;
 
         None
     }
@@ -200,18 +268,39 @@ pub struct ChannelPinsUpdateEvent {
 #[async_trait]
 impl CacheUpdate for ChannelPinsUpdateEvent {
     type Output = ();
+    const KIND: &'static str = "ChannelPinsUpdateEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
-        if let Some(channel) = cache.channels.write().await.get_mut(&self.channel_id) {
-            channel.last_pin_timestamp = self.last_pin_timestamp;
-
+        if !cache.settings().await.caches(CacheResources::CHANNELS) {
             return None;
         }
 
-        if let Some(channel) = cache.private_channels.write().await.get_mut(&self.channel_id) {
-            channel.last_pin_timestamp = self.last_pin_timestamp;
+        let last_pin_timestamp = self.last_pin_timestamp;
+        let watched = is_watched(&cache.changes.channels);
+        let mut change = None;
 
-            return None;
+        let found = cache.backend.update_guild_channel(self.channel_id, &mut |channel| {
+            let old = if watched { Some(channel.clone()) } else { None };
+            channel.last_pin_timestamp = last_pin_timestamp;
+
+            if let Some(old) = old {
+                change = Some((Some(channel.guild_id), Channel::Guild(old), Channel::Guild(channel.clone())));
+            }
+        }).await;
+
+        if !found {
+            if let Some(channel) = cache.private_channels.write().await.get_mut(&self.channel_id) {
+                let old = if watched { Some(channel.clone()) } else { None };
+                channel.last_pin_timestamp = self.last_pin_timestamp;
+
+                if let Some(old) = old {
+                    change = Some((None, Channel::Private(old), Channel::Private(channel.clone())));
+                }
+            }
+        }
+
+        if let Some((guild_id, old, new)) = change {
+            notify(&cache.changes.channels, guild_id, Some(old), Some(new));
         }
 
         None
@@ -228,20 +317,24 @@ pub struct ChannelUpdateEvent {
 #[async_trait]
 impl CacheUpdate for ChannelUpdateEvent {
     type Output = ();
+    const KIND: &'static str = "ChannelUpdateEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
-        match self.channel {
+        if !cache.settings().await.caches(CacheResources::CHANNELS) {
+            return None;
+        }
+
+        let (old_channel, updated) = match self.channel {
             Channel::Guild(ref channel) => {
                 let (guild_id, channel_id) = (channel.guild_id, channel.id);
 
-                cache.channels.write().await.insert(channel_id, channel.clone());
+                cache.backend.update_guild(guild_id, &mut |g| {
+                    g.channels.insert(channel_id, channel.clone());
+                }).await;
 
-                cache
-                    .guilds
-                    .write()
-                    .await
-                    .get_mut(&guild_id)
-                    .map(|g| g.channels.insert(channel_id, channel.clone()));
+                let old_channel = cache.backend.insert_guild_channel(channel.clone()).await;
+
+                (old_channel.map(Channel::Guild), true)
             },
             Channel::Private(ref channel) => {
                 cache
@@ -249,7 +342,8 @@ impl CacheUpdate for ChannelUpdateEvent {
                     .write()
                     .await
                     .get_mut(&channel.id)
-                    .map(|c| c.clone_from(channel));
+                    .map(|c| mem::replace(c, channel.clone()))
+                    .map_or((None, false), |c| (Some(Channel::Private(c)), true))
             },
             Channel::Category(ref category) => {
                 cache
@@ -257,9 +351,14 @@ impl CacheUpdate for ChannelUpdateEvent {
                     .write()
                     .await
                     .get_mut(&category.id)
-                    .map(|c| c.clone_from(category));
+                    .map(|c| mem::replace(c, category.clone()))
+                    .map_or((None, false), |c| (Some(Channel::Category(c)), true))
             },
             Channel::__Nonexhaustive => unreachable!(),
+        };
+
+        if updated && is_watched(&cache.changes.channels) {
+            notify(&cache.changes.channels, channel_guild_id(&self.channel), old_channel, Some(self.channel.clone()));
         }
 
         None
@@ -308,22 +407,45 @@ pub struct GuildCreateEvent {
 #[async_trait]
 impl CacheUpdate for GuildCreateEvent {
     type Output = ();
+    const KIND: &'static str = "GuildCreateEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
         cache.unavailable_guilds.write().await.remove(&self.guild.id);
+
+        let settings = cache.settings().await;
+
+        if !settings.caches(CacheResources::GUILDS) {
+            return None;
+        }
+
         let mut guild = self.guild.clone();
 
+        strip_uncached(&mut guild, &settings, cache.current_user_id().await);
+
         for (user_id, member) in &mut guild.members {
             cache.update_user_entry(&member.user).await;
-            cache.user(user_id).await.map(|u| member.user = u);
+            cache._user(*user_id).await.map(|u| member.user = u);
+        }
+
+        for channel in guild.channels.values() {
+            cache.backend.insert_guild_channel(channel.clone()).await;
+        }
+
+        cache.reference_users(guild.members.keys().copied()).await;
+
+        let guild_id = guild.id;
+        let new_guild = if is_watched(&cache.changes.guilds) { Some(guild.clone()) } else { None };
+        let old_guild = cache.backend.insert_guild(guild).await;
+
+        if let Some(ref old_guild) = old_guild {
+            cache.release_users(old_guild.members.keys().copied()).await;
         }
 
-        cache.channels.write().await.extend(guild.channels.clone().into_iter());
-        cache
-            .guilds
-            .write()
-            .await
-            .insert(self.guild.id, guild);
+        if new_guild.is_some() {
+            notify(&cache.changes.guilds, Some(guild_id), old_guild, new_guild);
+        }
+
+        cache.evict_users().await;
 
         None
     }
@@ -355,16 +477,30 @@ pub struct GuildDeleteEvent {
 #[async_trait]
 impl CacheUpdate for GuildDeleteEvent {
     type Output = Guild;
+    const KIND: &'static str = "GuildDeleteEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        match cache.guilds.write().await.remove(&self.guild.id) {
+        match cache.backend.remove_guild(self.guild.id).await {
             Some(guild) => {
+                let watched = is_watched(&cache.changes.channels);
+
                 for channel_id in guild.channels.keys() {
                     // Remove the channel from the cache.
-                    cache.channels.write().await.remove(channel_id);
+                    let old_channel = cache.backend.remove_guild_channel(*channel_id).await;
+
+                    if watched {
+                        notify(&cache.changes.channels, Some(guild.id), old_channel.map(Channel::Guild), None);
+                    }
 
                     // Remove the channel's cached messages.
-                    cache.messages.write().await.remove(channel_id);
+                    cache.backend.remove_channel_messages(*channel_id).await;
+                }
+
+                cache.release_users(guild.members.keys().copied()).await;
+                cache.evict_users().await;
+
+                if is_watched(&cache.changes.guilds) {
+                    notify(&cache.changes.guilds, Some(guild.id), Some(guild.clone()), None);
                 }
 
                 Some(guild)
@@ -402,10 +538,28 @@ pub struct GuildEmojisUpdateEvent {
 #[async_trait]
 impl CacheUpdate for GuildEmojisUpdateEvent {
     type Output = ();
+    const KIND: &'static str = "GuildEmojisUpdateEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
-        if let Some(guild) = cache.guilds.write().await.get_mut(&self.guild_id) {
-            guild.emojis.clone_from(&self.emojis);
+        if !cache.settings().await.caches(CacheResources::EMOJIS) {
+            return None;
+        }
+
+        let emojis = &self.emojis;
+        let watched = is_watched(&cache.changes.guilds);
+        let mut change = None;
+
+        cache.backend.update_guild(self.guild_id, &mut |guild| {
+            let old = if watched { Some(guild.clone()) } else { None };
+            guild.emojis.clone_from(emojis);
+
+            if let Some(old) = old {
+                change = Some((old, guild.clone()));
+            }
+        }).await;
+
+        if let Some((old, new)) = change {
+            notify(&cache.changes.guilds, Some(self.guild_id), Some(old), Some(new));
         }
 
         None
@@ -430,17 +584,39 @@ pub struct GuildMemberAddEvent {
 #[async_trait]
 impl CacheUpdate for GuildMemberAddEvent {
     type Output = ();
+    const KIND: &'static str = "GuildMemberAddEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
         let user_id = self.member.user.id;
         cache.update_user_entry(&self.member.user).await;
-        cache.user(user_id).await.map(|u| self.member.user = u);
+        cache._user(user_id).await.map(|u| self.member.user = u);
+
+        let settings = cache.settings().await;
 
-        if let Some(guild) = cache.guilds.write().await.get_mut(&self.guild_id) {
+        let member = &self.member;
+        let mut change = None;
+
+        cache.backend.update_guild(self.guild_id, &mut |guild| {
             guild.member_count += 1;
-            guild.members.insert(user_id, self.member.clone());
+
+            if settings.caches(CacheResources::MEMBERS)
+                && has_capacity(&guild.members, &user_id, settings.max_members_per_guild) {
+                change = Some(guild.members.insert(user_id, member.clone()));
+            }
+        }).await;
+
+        if let Some(old_member) = change {
+            if old_member.is_none() {
+                cache.reference_users(iter::once(user_id)).await;
+            }
+
+            if is_watched(&cache.changes.members) {
+                notify(&cache.changes.members, Some(self.guild_id), old_member, Some(self.member.clone()));
+            }
         }
 
+        cache.evict_users().await;
+
         None
     }
 }
@@ -490,14 +666,32 @@ pub struct GuildMemberRemoveEvent {
 #[async_trait]
 impl CacheUpdate for GuildMemberRemoveEvent {
     type Output = Member;
+    const KIND: &'static str = "GuildMemberRemoveEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        if let Some(guild) = cache.guilds.write().await.get_mut(&self.guild_id) {
+        let user_id = self.user.id;
+        let mut member = None;
+
+        let found = cache.backend.update_guild(self.guild_id, &mut |guild| {
             guild.member_count -= 1;
-            return guild.members.remove(&self.user.id);
+            member = guild.members.remove(&user_id);
+        }).await;
+
+        if !found {
+            return None;
         }
 
-        None
+        if member.is_some() {
+            cache.release_users(iter::once(user_id)).await;
+        }
+
+        if is_watched(&cache.changes.members) {
+            notify(&cache.changes.members, Some(self.guild_id), member.clone(), None);
+        }
+
+        cache.evict_users().await;
+
+        member
     }
 }
 
@@ -505,6 +699,8 @@ impl CacheUpdate for GuildMemberRemoveEvent {
 pub struct GuildMemberUpdateEvent {
     pub guild_id: GuildId,
     pub nick: Option<String>,
+    #[serde(default)]
+    pub pending: bool,
     pub roles: Vec<RoleId>,
     pub user: User,
     #[serde(skip)]
@@ -515,47 +711,63 @@ pub struct GuildMemberUpdateEvent {
 #[async_trait]
 impl CacheUpdate for GuildMemberUpdateEvent {
     type Output = Member;
+    const KIND: &'static str = "GuildMemberUpdateEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         cache.update_user_entry(&self.user).await;
 
-        if let Some(guild) = cache.guilds.write().await.get_mut(&self.guild_id) {
-            let mut found = false;
-
-            let item = if let Some(member) = guild.members.get_mut(&self.user.id) {
-                let item = Some(member.clone());
-
-                member.nick.clone_from(&self.nick);
-                member.roles.clone_from(&self.roles);
-                member.user.clone_from(&self.user);
+        let settings = cache.settings().await;
+        let event = &*self;
+        let watched = is_watched(&cache.changes.members);
+        let mut item = None;
+        let mut new_member = None;
+        let mut added = false;
 
-                found = true;
+        cache.backend.update_guild(self.guild_id, &mut |guild| {
+            if let Some(member) = guild.members.get_mut(&event.user.id) {
+                item = Some(member.clone());
 
-                item
-            } else {
-                None
-            };
+                member.nick.clone_from(&event.nick);
+                member.pending = event.pending;
+                member.roles.clone_from(&event.roles);
+                member.user.clone_from(&event.user);
 
-            if !found {
+                if watched {
+                    new_member = Some(member.clone());
+                }
+            } else if settings.caches(CacheResources::MEMBERS)
+                && has_capacity(&guild.members, &event.user.id, settings.max_members_per_guild) {
                 guild.members.insert(
-                    self.user.id,
+                    event.user.id,
                     Member {
                         deaf: false,
-                        guild_id: self.guild_id,
+                        guild_id: event.guild_id,
                         joined_at: None,
                         mute: false,
-                        nick: self.nick.clone(),
-                        roles: self.roles.clone(),
-                        user: self.user.clone(),
+                        nick: event.nick.clone(),
+                        pending: event.pending,
+                        roles: event.roles.clone(),
+                        user: event.user.clone(),
                         _nonexhaustive: (),
                     },
                 );
+                added = true;
+
+                if watched {
+                    new_member = guild.members.get(&event.user.id).cloned();
+                }
             }
+        }).await;
 
-            item
-        } else {
-            None
+        if added {
+            cache.reference_users(iter::once(self.user.id)).await;
         }
+
+        if new_member.is_some() {
+            notify(&cache.changes.members, Some(self.guild_id), item.clone(), new_member);
+        }
+
+        item
     }
 }
 
@@ -572,16 +784,47 @@ pub struct GuildMembersChunkEvent {
 #[async_trait]
 impl CacheUpdate for GuildMembersChunkEvent {
     type Output = ();
+    const KIND: &'static str = "GuildMembersChunkEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
         for member in self.members.values() {
             cache.update_user_entry(&member.user).await;
         }
 
-        cache
-            .guild(self.guild_id)
-            .await
-            .map(|mut g| g.members.extend(self.members.clone()));
+        let settings = cache.settings().await;
+
+        if !settings.caches(CacheResources::MEMBERS) {
+            return None;
+        }
+
+        let members = &self.members;
+        let watched = is_watched(&cache.changes.members);
+        let mut changes = Vec::new();
+        let mut added = Vec::new();
+
+        cache.backend.update_guild(self.guild_id, &mut |guild| {
+            for (user_id, member) in members {
+                if has_capacity(&guild.members, user_id, settings.max_members_per_guild) {
+                    let old_member = guild.members.insert(*user_id, member.clone());
+
+                    if old_member.is_none() {
+                        added.push(*user_id);
+                    }
+
+                    if watched {
+                        changes.push((old_member, member.clone()));
+                    }
+                }
+            }
+        }).await;
+
+        cache.reference_users(added).await;
+
+        for (old_member, new_member) in changes {
+            notify(&cache.changes.members, Some(self.guild_id), old_member, Some(new_member));
+        }
+
+        cache.evict_users().await;
 
         None
     }
@@ -646,14 +889,25 @@ pub struct GuildRoleCreateEvent {
 #[async_trait]
 impl CacheUpdate for GuildRoleCreateEvent {
     type Output = ();
+    const KIND: &'static str = "GuildRoleCreateEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
-        cache
-            .guilds
-            .write()
-            .await
-            .get_mut(&self.guild_id)
-            .map(|g| g.roles.insert(self.role.id, self.role.clone()));
+        if !cache.settings().await.caches(CacheResources::ROLES) {
+            return None;
+        }
+
+        let role = &self.role;
+        let mut change = None;
+
+        cache.backend.update_guild(self.guild_id, &mut |g| {
+            change = Some(g.roles.insert(role.id, role.clone()));
+        }).await;
+
+        if let Some(old_role) = change {
+            if is_watched(&cache.changes.roles) {
+                notify(&cache.changes.roles, Some(self.guild_id), old_role, Some(self.role.clone()));
+            }
+        }
 
         None
     }
@@ -701,14 +955,19 @@ pub struct GuildRoleDeleteEvent {
 #[async_trait]
 impl CacheUpdate for GuildRoleDeleteEvent {
     type Output = Role;
+    const KIND: &'static str = "GuildRoleDeleteEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        cache
-            .guilds
-            .write()
-            .await
-            .get_mut(&self.guild_id)
-            .and_then(|g| g.roles.remove(&self.role_id))
+        let role_id = self.role_id;
+        let mut role = None;
+
+        cache.backend.update_guild(self.guild_id, &mut |g| role = g.roles.remove(&role_id)).await;
+
+        if is_watched(&cache.changes.roles) {
+            notify(&cache.changes.roles, Some(self.guild_id), role.clone(), None);
+        }
+
+        role
     }
 }
 
@@ -724,16 +983,27 @@ pub struct GuildRoleUpdateEvent {
 #[async_trait]
 impl CacheUpdate for GuildRoleUpdateEvent {
     type Output = Role;
+    const KIND: &'static str = "GuildRoleUpdateEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        if let Some(guild) = cache.guilds.write().await.get_mut(&self.guild_id) {
+        if !cache.settings().await.caches(CacheResources::ROLES) {
+            return None;
+        }
+
+        let new_role = &self.role;
+        let mut old_role = None;
 
-            if let Some(role) = guild.roles.get_mut(&self.role.id) {
-                return Some(mem::replace(role, self.role.clone()));
+        cache.backend.update_guild(self.guild_id, &mut |guild| {
+            if let Some(role) = guild.roles.get_mut(&new_role.id) {
+                old_role = Some(mem::replace(role, new_role.clone()));
             }
+        }).await;
+
+        if old_role.is_some() && is_watched(&cache.changes.roles) {
+            notify(&cache.changes.roles, Some(self.guild_id), old_role.clone(), Some(self.role.clone()));
         }
 
-        None
+        old_role
     }
 }
 
@@ -778,10 +1048,26 @@ pub struct GuildUnavailableEvent {
 #[async_trait]
 impl CacheUpdate for GuildUnavailableEvent {
     type Output = ();
+    const KIND: &'static str = "GuildUnavailableEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
         cache.unavailable_guilds.write().await.insert(self.guild_id);
-        cache.guilds.write().await.remove(&self.guild_id);
+
+        if !cache.settings().await.caches(CacheResources::GUILDS) {
+            return None;
+        }
+
+        let old_guild = cache.backend.remove_guild(self.guild_id).await;
+
+        if let Some(ref old_guild) = old_guild {
+            cache.release_users(old_guild.members.keys().copied()).await;
+        }
+
+        if is_watched(&cache.changes.guilds) {
+            notify(&cache.changes.guilds, Some(self.guild_id), old_guild, None);
+        }
+
+        cache.evict_users().await;
 
         None
     }
@@ -797,17 +1083,38 @@ pub struct GuildUpdateEvent {
 #[async_trait]
 impl CacheUpdate for GuildUpdateEvent {
     type Output = ();
+    const KIND: &'static str = "GuildUpdateEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
-        if let Some(guild) = cache.guilds.write().await.get_mut(&self.guild.id) {
-            guild.afk_timeout = self.guild.afk_timeout;
-            guild.afk_channel_id.clone_from(&self.guild.afk_channel_id);
-            guild.icon.clone_from(&self.guild.icon);
-            guild.name.clone_from(&self.guild.name);
-            guild.owner_id.clone_from(&self.guild.owner_id);
-            guild.region.clone_from(&self.guild.region);
-            guild.roles.clone_from(&self.guild.roles);
-            guild.verification_level = self.guild.verification_level;
+        let cache_roles = cache.settings().await.caches(CacheResources::ROLES);
+
+        let partial = &self.guild;
+        let watched = is_watched(&cache.changes.guilds);
+        let mut change = None;
+
+        cache.backend.update_guild(partial.id, &mut |guild| {
+            let old = if watched { Some(guild.clone()) } else { None };
+
+            guild.afk_timeout = partial.afk_timeout;
+            guild.afk_channel_id.clone_from(&partial.afk_channel_id);
+            guild.icon.clone_from(&partial.icon);
+            guild.name.clone_from(&partial.name);
+            guild.owner_id.clone_from(&partial.owner_id);
+            guild.region.clone_from(&partial.region);
+
+            if cache_roles {
+                guild.roles.clone_from(&partial.roles);
+            }
+
+            guild.verification_level = partial.verification_level;
+
+            if let Some(old) = old {
+                change = Some((old, guild.clone()));
+            }
+        }).await;
+
+        if let Some((old, new)) = change {
+            notify(&cache.changes.guilds, Some(partial.id), Some(old), Some(new));
         }
 
         None
@@ -841,36 +1148,17 @@ pub struct MessageCreateEvent {
 impl CacheUpdate for MessageCreateEvent {
     /// The oldest message, if the channel's message cache was already full.
     type Output = Message;
+    const KIND: &'static str = "MessageCreateEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        let max = cache.settings().await.max_messages;
+        let settings = cache.settings().await;
+        let max = settings.max_messages;
 
-        if max == 0 {
+        if max == 0 || !settings.caches(CacheResources::MESSAGES) {
             return None;
         }
 
-
-        let mut messages_map = cache.messages.write().await;
-        let messages = messages_map.entry(self.message.channel_id).or_insert_with(Default::default);
-        let mut message_queues = cache.message_queue.write().await;
-
-        let queue = message_queues
-            .entry(self.message.channel_id)
-            .or_insert_with(Default::default);
-
-        let mut removed_msg = None;
-
-        if messages.len() == max {
-
-            if let Some(id) = queue.pop_front() {
-                removed_msg = messages.remove(&id);
-            }
-        }
-
-        queue.push_back(self.message.id);
-        messages.insert(self.message.id, self.message.clone());
-
-        removed_msg
+        cache.backend.insert_message(self.message.clone(), max).await
     }
 }
 
@@ -934,46 +1222,44 @@ pub struct MessageUpdateEvent {
 #[async_trait]
 impl CacheUpdate for MessageUpdateEvent {
     type Output = Message;
+    const KIND: &'static str = "MessageUpdateEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        if let Some(messages) = cache.messages.write().await.get_mut(&self.channel_id) {
+        let mut item = None;
 
-            if let Some(message) = messages.get_mut(&self.id) {
-                let item = message.clone();
-
-                if let Some(attachments) = self.attachments.clone() {
-                    message.attachments = attachments;
-                }
+        cache.backend.update_message(self.channel_id, self.id, &mut |message| {
+            item = Some(message.clone());
 
-                if let Some(content) = self.content.clone() {
-                    message.content = content;
-                }
+            if let Some(attachments) = self.attachments.clone() {
+                message.attachments = attachments;
+            }
 
-                if let Some(edited_timestamp) = self.edited_timestamp {
-                    message.edited_timestamp = Some(edited_timestamp);
-                }
+            if let Some(content) = self.content.clone() {
+                message.content = content;
+            }
 
-                if let Some(mentions) = self.mentions.clone() {
-                    message.mentions = mentions;
-                }
+            if let Some(edited_timestamp) = self.edited_timestamp {
+                message.edited_timestamp = Some(edited_timestamp);
+            }
 
-                if let Some(mention_everyone) = self.mention_everyone {
-                    message.mention_everyone = mention_everyone;
-                }
+            if let Some(mentions) = self.mentions.clone() {
+                message.mentions = mentions;
+            }
 
-                if let Some(mention_roles) = self.mention_roles.clone() {
-                    message.mention_roles = mention_roles;
-                }
+            if let Some(mention_everyone) = self.mention_everyone {
+                message.mention_everyone = mention_everyone;
+            }
 
-                if let Some(pinned) = self.pinned {
-                    message.pinned = pinned;
-                }
+            if let Some(mention_roles) = self.mention_roles.clone() {
+                message.mention_roles = mention_roles;
+            }
 
-                return Some(item);
+            if let Some(pinned) = self.pinned {
+                message.pinned = pinned;
             }
-        }
+        }).await;
 
-        None
+        item
     }
 }
 
@@ -990,56 +1276,96 @@ pub struct PresenceUpdateEvent {
 #[async_trait]
 impl CacheUpdate for PresenceUpdateEvent {
     type Output = ();
+    const KIND: &'static str = "PresenceUpdateEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
         let user_id = self.presence.user_id;
 
         if let Some(user) = self.presence.user.as_mut() {
             cache.update_user_entry(&user).await;
-            cache.user(user_id).await.map(|u| *user = u);
+            cache._user(user_id).await.map(|u| *user = u);
         }
 
+        let settings = cache.settings().await;
+
+        let mut presence_change = None;
+        let watched = is_watched(&cache.changes.members);
+        let presences_watched = is_watched(&cache.changes.presences);
+
         if let Some(guild_id) = self.guild_id {
-            if let Some(guild) = cache.guilds.write().await.get_mut(&guild_id) {
+            let event = &*self;
+            let mut new_member = None;
+            let mut added = false;
+
+            cache.backend.update_guild(guild_id, &mut |guild| {
                 // If the member went offline, remove them from the presence list.
-                if self.presence.status == OnlineStatus::Offline {
-                    guild.presences.remove(&self.presence.user_id);
-                } else {
-                    guild
+                if event.presence.status == OnlineStatus::Offline {
+                    presence_change = Some((guild.presences.remove(&event.presence.user_id), None));
+                } else if settings.caches(CacheResources::PRESENCES) && has_capacity(&guild.presences, &event.presence.user_id, settings.max_presences) {
+                    let old_presence = guild
                         .presences
-                        .insert(self.presence.user_id, self.presence.clone());
+                        .insert(event.presence.user_id, event.presence.clone());
+                    let new_presence = if presences_watched { Some(event.presence.clone()) } else { None };
+                    presence_change = Some((old_presence, new_presence));
                 }
 
                 // Create a partial member instance out of the presence update
                 // data. This includes everything but `deaf`, `mute`, and
                 // `joined_at`.
-                if !guild.members.contains_key(&self.presence.user_id) {
-                    if let Some(user) = self.presence.user.as_ref() {
-                        let roles = self.roles.clone().unwrap_or_default();
+                if settings.caches(CacheResources::MEMBERS)
+                    && !guild.members.contains_key(&event.presence.user_id)
+                    && has_capacity(&guild.members, &event.presence.user_id, settings.max_members_per_guild) {
+                    if let Some(user) = event.presence.user.as_ref() {
+                        let roles = event.roles.clone().unwrap_or_default();
 
-                        guild.members.insert(self.presence.user_id, Member {
+                        let member = Member {
                             deaf: false,
                             guild_id,
                             joined_at: None,
                             mute: false,
-                            nick: self.presence.nick.clone(),
+                            nick: event.presence.nick.clone(),
+                            pending: false,
                             user: user.clone(),
                             roles,
                             _nonexhaustive: (),
-                        });
+                        };
+
+                        if watched {
+                            new_member = Some(member.clone());
+                        }
+
+                        guild.members.insert(event.presence.user_id, member);
+                        added = true;
                     }
                 }
+            }).await;
+
+            if added {
+                cache.reference_users(iter::once(user_id)).await;
             }
+
+            notify(&cache.changes.members, Some(guild_id), None, new_member);
         } else if self.presence.status == OnlineStatus::Offline {
-            cache.presences.write().await.remove(&self.presence.user_id);
-        } else {
-            cache
-                .presences
-                .write()
-                .await
-                .insert(self.presence.user_id, self.presence.clone());
+            let old_presence = cache.presences.write().await.remove(&self.presence.user_id);
+            presence_change = Some((old_presence, None));
+        } else if settings.caches(CacheResources::PRESENCES) {
+            let mut presences = cache.presences.write().await;
+
+            if has_capacity(&presences, &self.presence.user_id, settings.max_presences) {
+                let old_presence = presences.insert(self.presence.user_id, self.presence.clone());
+                let new_presence = if presences_watched { Some(self.presence.clone()) } else { None };
+                presence_change = Some((old_presence, new_presence));
+            }
         }
 
+        if let Some((old_presence, new_presence)) = presence_change {
+            if presences_watched {
+                notify(&cache.changes.presences, self.guild_id, old_presence, new_presence);
+            }
+        }
+
+        cache.evict_users().await;
+
         None
     }
 }
@@ -1080,17 +1406,27 @@ pub struct PresencesReplaceEvent {
 #[async_trait]
 impl CacheUpdate for PresencesReplaceEvent {
     type Output = ();
+    const KIND: &'static str = "PresencesReplaceEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
-        cache.presences.write().await.extend({
-            let mut p: HashMap<UserId, Presence> = HashMap::default();
+        let settings = cache.settings().await;
 
-            for presence in &self.presences {
-                p.insert(presence.user_id, presence.clone());
-            }
+        if !settings.caches(CacheResources::PRESENCES) {
+            return None;
+        }
+
+        let mut presences = cache.presences.write().await;
+        let watched = is_watched(&cache.changes.presences);
 
-            p
-        });
+        for presence in &self.presences {
+            if has_capacity(&presences, &presence.user_id, settings.max_presences) {
+                let old_presence = presences.insert(presence.user_id, presence.clone());
+
+                if watched {
+                    notify(&cache.changes.presences, None, old_presence, Some(presence.clone()));
+                }
+            }
+        }
 
         None
     }
@@ -1184,19 +1520,56 @@ pub struct ReadyEvent {
 #[async_trait]
 impl CacheUpdate for ReadyEvent {
     type Output = ();
+    const KIND: &'static str = "ReadyEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
         let mut ready = self.ready.clone();
+        let settings = cache.settings().await;
+
+        let cache_guilds = settings.caches(CacheResources::GUILDS);
 
+        // The unavailable guilds are tracked even if guilds are not cached, as
+        // they determine when the cache is ready.
         for guild in ready.guilds {
             match guild {
                 GuildStatus::Offline(unavailable) => {
-                    cache.guilds.write().await.remove(&unavailable.id);
                     cache.unavailable_guilds.write().await.insert(unavailable.id);
+
+                    if cache_guilds {
+                        let old_guild = cache.backend.remove_guild(unavailable.id).await;
+
+                        if let Some(ref old_guild) = old_guild {
+                            cache.release_users(old_guild.members.keys().copied()).await;
+                        }
+
+                        if is_watched(&cache.changes.guilds) {
+                            notify(&cache.changes.guilds, Some(unavailable.id), old_guild, None);
+                        }
+                    }
                 },
-                GuildStatus::OnlineGuild(guild) => {
+                GuildStatus::OnlineGuild(mut guild) => {
                     cache.unavailable_guilds.write().await.remove(&guild.id);
-                    cache.guilds.write().await.insert(guild.id, guild);
+
+                    if !cache_guilds {
+                        continue;
+                    }
+
+                    strip_uncached(&mut guild, &settings, ready.user.id);
+
+                    cache.reference_users(guild.members.keys().copied()).await;
+
+                    let guild_id = guild.id;
+                    let new_guild = if is_watched(&cache.changes.guilds) { Some(guild.clone()) } else { None };
+
+                    let old_guild = cache.backend.insert_guild(guild).await;
+
+                    if let Some(ref old_guild) = old_guild {
+                        cache.release_users(old_guild.members.keys().copied()).await;
+                    }
+
+                    if new_guild.is_some() {
+                        notify(&cache.changes.guilds, Some(guild_id), old_guild, new_guild);
+                    }
                 },
                 GuildStatus::OnlinePartialGuild(_) => {},
                 GuildStatus::__Nonexhaustive => unreachable!(),
@@ -1211,16 +1584,22 @@ impl CacheUpdate for ReadyEvent {
                 cache.update_user_entry(user).await;
             }
 
-            presence.user = match cache.user(user_id).await {
+            presence.user = match cache._user(*user_id).await {
                 Some(user) => Some(user),
                 None => None,
             };
         }
 
-        cache.presences.write().await.extend(ready.presences);
+        if settings.caches(CacheResources::PRESENCES) {
+            truncate_map(&mut ready.presences, settings.max_presences, None);
+            cache.presences.write().await.extend(ready.presences);
+        }
+
         *cache.shard_count.write().await = ready.shard.map_or(1, |s| s[1]);
         *cache.user.write().await = ready.user;
 
+        cache.evict_users().await;
+
         None
     }
 }
@@ -1276,6 +1655,7 @@ pub struct UserUpdateEvent {
 #[async_trait]
 impl CacheUpdate for UserUpdateEvent {
     type Output = CurrentUser;
+    const KIND: &'static str = "UserUpdateEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         let mut user = cache.user.write().await;
@@ -1320,26 +1700,32 @@ pub struct VoiceStateUpdateEvent {
 #[async_trait]
 impl CacheUpdate for VoiceStateUpdateEvent {
     type Output = VoiceState;
+    const KIND: &'static str = "VoiceStateUpdateEvent";
 
     async fn update(&mut self, cache: &Cache) -> Option<VoiceState> {
-        if let Some(guild_id) = self.guild_id {
+        if !cache.settings().await.caches(CacheResources::VOICE_STATES) {
+            return None;
+        }
 
-            if let Some(guild) = cache.guilds.write().await.get_mut(&guild_id) {
+        if let Some(guild_id) = self.guild_id {
+            let voice_state = &self.voice_state;
+            let mut old_voice_state = None;
 
-                if self.voice_state.channel_id.is_some() {
+            cache.backend.update_guild(guild_id, &mut |guild| {
+                old_voice_state = if voice_state.channel_id.is_some() {
                     // Update or add to the voice state list
                     guild
                         .voice_states
-                        .insert(self.voice_state.user_id, self.voice_state.clone())
+                        .insert(voice_state.user_id, voice_state.clone())
                 } else {
                     // Remove the user from the voice state list
                     guild
                         .voice_states
-                        .remove(&self.voice_state.user_id)
-                }
-            } else {
-                None
-            }
+                        .remove(&voice_state.user_id)
+                };
+            }).await;
+
+            old_voice_state
         } else {
             None
         }
@@ -6,8 +6,9 @@ use serde::de::{
     MapAccess,
     Visitor
 };
-use serde::ser::Serializer;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
 use super::super::prelude::*;
+use super::super::utils::deserialize_u64;
 use std::{
     collections::HashMap,
     mem::transmute,
@@ -15,7 +16,7 @@ use std::{
 };
 
 /// Determines to what entity an action was used on.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
 pub enum Target {
     Guild = 10,
@@ -31,7 +32,7 @@ pub enum Target {
 }
 
 /// Determines the action that was done on a target.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Action {
     GuildUpdate,
     Channel(ActionChannel),
@@ -67,7 +68,7 @@ impl Action {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
 pub enum ActionChannel {
     Create = 10,
@@ -88,7 +89,7 @@ impl ActionChannel {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
 pub enum ActionChannelOverwrite {
     Create = 13,
@@ -109,7 +110,7 @@ impl ActionChannelOverwrite {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
 pub enum ActionMember {
     Kick = 20,
@@ -142,7 +143,7 @@ impl ActionMember {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
 pub enum ActionRole {
     Create = 30,
@@ -163,7 +164,7 @@ impl ActionRole {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
 pub enum ActionInvite {
     Create = 40,
@@ -184,7 +185,7 @@ impl ActionInvite {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
 pub enum ActionWebhook {
     Create = 50,
@@ -205,12 +206,12 @@ impl ActionWebhook {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
 pub enum ActionEmoji {
     Create = 60,
-    Delete = 61,
-    Update = 62,
+    Update = 61,
+    Delete = 62,
     #[doc(hidden)]
     __Nonexhaustive,
 }
@@ -226,7 +227,7 @@ impl ActionEmoji {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
 pub enum ActionMessage {
     Delete = 72,
@@ -250,7 +251,7 @@ impl ActionMessage {
 }
 
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
 pub enum ActionIntegration {
     Create = 80,
@@ -271,12 +272,243 @@ impl ActionIntegration {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Change {
-    #[serde(rename = "key")] pub name: String,
-    // TODO: Change these to an actual type.
-    #[serde(rename = "old_value")] pub old: Option<Value>,
-    #[serde(rename = "new_value")] pub new: Option<Value>,
+/// A role added to or removed from a member, as part of a
+/// [`Change::RolesAdded`] or [`Change::RolesRemoved`].
+///
+/// [`Change::RolesAdded`]: enum.Change.html#variant.RolesAdded
+/// [`Change::RolesRemoved`]: enum.Change.html#variant.RolesRemoved
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PartialRole {
+    pub id: RoleId,
+    pub name: String,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+macro_rules! generate_change {
+    (@parse $value:ident, $type:ty) => {
+        <$type>::deserialize($value)
+    };
+    (@parse $value:ident, $type:ty, $with:path) => {
+        $with($value)
+    };
+    ( $(
+        $(#[$attr:meta])*
+        $key:literal => $variant:ident ($type:ty) $(with $with:path)?,
+    )* ) => {
+        /// A change made to an entity, holding the values of the changed key
+        /// before and after the action.
+        ///
+        /// Either value may be missing, such as when an entity was created or
+        /// deleted.
+        #[derive(Clone, Debug)]
+        pub enum Change {
+            $(
+                $(#[$attr])*
+                $variant {
+                    old: Option<$type>,
+                    new: Option<$type>,
+                },
+            )*
+            /// A change of a key not yet known to the library.
+            Other {
+                name: String,
+                old: Option<Value>,
+                new: Option<Value>,
+            },
+            #[doc(hidden)]
+            __Nonexhaustive,
+        }
+
+        impl Change {
+            /// The name of the changed key, as sent by Discord.
+            pub fn key(&self) -> &str {
+                match self {
+                    $( Change::$variant { .. } => $key, )*
+                    Change::Other { name, .. } => name,
+                    Change::__Nonexhaustive => unreachable!(),
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Change {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+                #[derive(Deserialize)]
+                struct RawChange {
+                    key: String,
+                    #[serde(default)]
+                    old_value: Option<Value>,
+                    #[serde(default)]
+                    new_value: Option<Value>,
+                }
+
+                let raw = RawChange::deserialize(deserializer)?;
+
+                // A value which fails to parse into the known type, such as
+                // one of a format newer than the library, is kept raw instead
+                // of failing the whole entry.
+                Ok(match raw.key.as_str() {
+                    $(
+                        $key => {
+                            let old = change_value(raw.old_value.as_ref(), |v| generate_change!(@parse v, $type $(, $with)?));
+                            let new = change_value(raw.new_value.as_ref(), |v| generate_change!(@parse v, $type $(, $with)?));
+
+                            match (old, new) {
+                                (Ok(old), Ok(new)) => Change::$variant { old, new },
+                                _ => Change::Other {
+                                    name: raw.key,
+                                    old: raw.old_value,
+                                    new: raw.new_value,
+                                },
+                            }
+                        },
+                    )*
+                    _ => Change::Other {
+                        name: raw.key,
+                        old: raw.old_value,
+                        new: raw.new_value,
+                    },
+                })
+            }
+        }
+
+        impl Serialize for Change {
+            fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+                let mut state = serializer.serialize_struct("Change", 3)?;
+                state.serialize_field("key", self.key())?;
+
+                match self {
+                    $(
+                        Change::$variant { old, new } => {
+                            state.serialize_field("old_value", old)?;
+                            state.serialize_field("new_value", new)?;
+                        },
+                    )*
+                    Change::Other { old, new, .. } => {
+                        state.serialize_field("old_value", old)?;
+                        state.serialize_field("new_value", new)?;
+                    },
+                    Change::__Nonexhaustive => unreachable!(),
+                }
+
+                state.end()
+            }
+        }
+    };
+}
+
+fn change_value<T>(
+    value: Option<&Value>,
+    parse: impl FnOnce(&Value) -> serde_json::Result<T>,
+) -> serde_json::Result<Option<T>> {
+    match value {
+        Some(Value::Null) | None => Ok(None),
+        Some(value) => parse(value).map(Some),
+    }
+}
+
+/// Deserializes permissions sent either as a number or, as in newer API
+/// versions, as a string.
+fn deserialize_permissions<'de, D: Deserializer<'de>>(deserializer: D) -> StdResult<Permissions, D::Error> {
+    deserialize_u64(deserializer).map(Permissions::from_bits_truncate)
+}
+
+generate_change! {
+    /// The name of a guild, channel, role or emoji.
+    "name" => Name(String),
+    /// The description of a guild.
+    "description" => Description(String),
+    /// The icon of a guild.
+    "icon_hash" => IconHash(String),
+    /// The invite splash of a guild.
+    "splash_hash" => SplashHash(String),
+    /// The owner of a guild.
+    "owner_id" => OwnerId(UserId),
+    /// The voice region of a guild.
+    "region" => Region(String),
+    /// The AFK channel of a guild.
+    "afk_channel_id" => AfkChannelId(ChannelId),
+    /// The AFK timeout of a guild, in seconds.
+    "afk_timeout" => AfkTimeout(u64),
+    /// The two-factor authentication requirement of a guild.
+    "mfa_level" => MfaLevel(MfaLevel),
+    /// The verification level of a guild.
+    "verification_level" => VerificationLevel(VerificationLevel),
+    /// The explicit content filter of a guild.
+    "explicit_content_filter" => ExplicitContentFilter(ExplicitContentFilter),
+    /// The default notification level of a guild.
+    "default_message_notifications" => DefaultMessageNotifications(DefaultMessageNotificationLevel),
+    /// The vanity invite code of a guild.
+    "vanity_url_code" => VanityUrlCode(String),
+    /// Roles given to a member.
+    "$add" => RolesAdded(Vec<PartialRole>),
+    /// Roles taken from a member.
+    "$remove" => RolesRemoved(Vec<PartialRole>),
+    /// The number of days of inactivity after which members were pruned.
+    "prune_delete_days" => PruneDeleteDays(u64),
+    /// Whether the widget of a guild is enabled.
+    "widget_enabled" => WidgetEnabled(bool),
+    /// The channel the widget of a guild points to.
+    "widget_channel_id" => WidgetChannelId(ChannelId),
+    /// The channel system messages of a guild are sent to.
+    "system_channel_id" => SystemChannelId(ChannelId),
+    /// The position of a channel.
+    "position" => Position(u64),
+    /// The topic of a channel.
+    "topic" => Topic(String),
+    /// The bitrate of a voice channel.
+    "bitrate" => Bitrate(u64),
+    /// The permission overwrites of a channel.
+    "permission_overwrites" => PermissionOverwrites(Vec<PermissionOverwrite>),
+    /// Whether a channel is NSFW.
+    "nsfw" => Nsfw(bool),
+    /// The application of a webhook or bot.
+    "application_id" => ApplicationId(ApplicationId),
+    /// The slowmode of a channel, in seconds.
+    "rate_limit_per_user" => RateLimitPerUser(u64),
+    /// The permissions of a role.
+    "permissions" => Permissions(Permissions) with deserialize_permissions,
+    /// The colour of a role.
+    "color" => Colour(u64),
+    /// Whether a role is shown separately in the member list.
+    "hoist" => Hoist(bool),
+    /// Whether a role is mentionable.
+    "mentionable" => Mentionable(bool),
+    /// The permissions allowed by a permission overwrite.
+    "allow" => Allow(Permissions) with deserialize_permissions,
+    /// The permissions denied by a permission overwrite.
+    "deny" => Deny(Permissions) with deserialize_permissions,
+    /// The code of an invite.
+    "code" => Code(String),
+    /// The channel of an invite, or the channel of a webhook.
+    "channel_id" => ChannelId(ChannelId),
+    /// The creator of an invite.
+    "inviter_id" => InviterId(UserId),
+    /// The maximum number of uses of an invite.
+    "max_uses" => MaxUses(u64),
+    /// The number of times an invite was used.
+    "uses" => Uses(u64),
+    /// How long an invite lasts, in seconds.
+    "max_age" => MaxAge(u64),
+    /// Whether an invite grants temporary membership.
+    "temporary" => Temporary(bool),
+    /// Whether a member is server deafened.
+    "deaf" => Deaf(bool),
+    /// Whether a member is server muted.
+    "mute" => Mute(bool),
+    /// The nickname of a member.
+    "nick" => Nick(String),
+    /// The avatar of a user or webhook.
+    "avatar_hash" => AvatarHash(String),
+    /// The Id of the changed entity.
+    "id" => Id(u64) with deserialize_u64,
+    /// Whether emoticons of an integration are synced.
+    "enable_emoticons" => EnableEmoticons(bool),
+    /// What happens to subscribers of an integration whose subscription
+    /// expired.
+    "expire_behavior" => ExpireBehavior(u64),
+    /// The grace period of expired subscribers of an integration, in days.
+    "expire_grace_period" => ExpireGracePeriod(u64),
 }
 
 #[derive(Debug)]
@@ -287,7 +519,7 @@ pub struct AuditLogs {
     pub(crate) _nonexhaustive: (),
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AuditLogEntry {
     /// Determines to what entity an [`action`] was used on.
     ///
@@ -316,7 +548,7 @@ pub struct AuditLogEntry {
     pub(crate) _nonexhaustive: (),
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Options {
     /// Number of days after which inactive members were kicked.
     #[serde(default, with = "option_u64_handler")]
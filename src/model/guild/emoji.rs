@@ -91,10 +91,26 @@ impl Emoji {
     #[cfg(feature = "cache")]
     #[inline]
     pub async fn delete(&self, cache_http: impl CacheHttp) -> Result<()> {
+        self._delete(cache_http, None).await
+    }
+
+    /// Deletes the emoji, with a reason shown in the guild's audit log.
+    ///
+    /// Refer to [`delete`] for more information.
+    ///
+    /// [`delete`]: #method.delete
+    #[cfg(feature = "cache")]
+    #[inline]
+    pub async fn delete_with_reason(&self, cache_http: impl CacheHttp, reason: &str) -> Result<()> {
+        self._delete(cache_http, Some(reason)).await
+    }
+
+    #[cfg(feature = "cache")]
+    async fn _delete(&self, cache_http: impl CacheHttp, reason: Option<&str>) -> Result<()> {
         let cache = cache_http.cache().ok_or(Error::Model(ModelError::ItemMissing))?;
 
         match self.find_guild_id(&cache).await {
-            Some(guild_id) => cache_http.http().delete_emoji(guild_id.0, self.id.0).await,
+            Some(guild_id) => cache_http.http().delete_emoji(guild_id.0, self.id.0, reason).await,
             None => Err(Error::Model(ModelError::ItemMissing)),
         }
     }
@@ -107,7 +123,25 @@ impl Emoji {
     ///
     /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
     #[cfg(feature = "cache")]
+    #[inline]
     pub async fn edit(&mut self, cache_http: impl CacheHttp, name: &str) -> Result<()> {
+        self._edit(cache_http, name, None).await
+    }
+
+    /// Edits the emoji by updating it with a new name, with a reason shown in
+    /// the guild's audit log.
+    ///
+    /// Refer to [`edit`] for more information.
+    ///
+    /// [`edit`]: #method.edit
+    #[cfg(feature = "cache")]
+    #[inline]
+    pub async fn edit_with_reason(&mut self, cache_http: impl CacheHttp, name: &str, reason: &str) -> Result<()> {
+        self._edit(cache_http, name, Some(reason)).await
+    }
+
+    #[cfg(feature = "cache")]
+    async fn _edit(&mut self, cache_http: impl CacheHttp, name: &str, reason: Option<&str>) -> Result<()> {
         let cache = cache_http.cache().ok_or(Error::Model(ModelError::ItemMissing))?;
 
         match self.find_guild_id(&cache).await {
@@ -118,7 +152,7 @@ impl Emoji {
 
                 *self = cache_http
                     .http()
-                    .edit_emoji(guild_id.0, self.id.0, &map)
+                    .edit_emoji(guild_id.0, self.id.0, &map, reason)
                     .await?;
 
                 Ok(())
@@ -161,13 +195,17 @@ impl Emoji {
     /// ```
     #[cfg(feature = "cache")]
     pub async fn find_guild_id(&self, cache: impl AsRef<Cache>) -> Option<GuildId> {
-        for guild in cache.as_ref().guilds.read().await.values() {
+        let mut guild_id = None;
+
+        cache.as_ref().backend.for_each_guild(&mut |guild| {
             if guild.emojis.contains_key(&self.id) {
-                return Some(guild.id);
+                guild_id = Some(guild.id);
             }
-        }
 
-        None
+            guild_id.is_none()
+        }).await;
+
+        guild_id
     }
 
     /// Generates a URL to the emoji's image.
@@ -3,7 +3,7 @@ use crate::model::prelude::*;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 #[cfg(feature = "model")]
-use crate::builder::{EditGuild, EditMember, EditRole};
+use crate::builder::{EditGuild, EditGuildWelcomeScreen, EditMember, EditMembershipScreening, EditRole};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
@@ -12,7 +12,7 @@ use crate::utils;
 use crate::builder::CreateChannel;
 #[cfg(feature = "model")]
 use serde_json::json;
-#[cfg(feature = "cache")]
+#[cfg(feature = "model")]
 use futures::stream::Stream;
 #[cfg(feature = "collector")]
 use crate::client::bridge::gateway::ShardMessenger;
@@ -26,6 +26,71 @@ use crate::http::{Http, CacheHttp};
 
 #[cfg(feature = "model")]
 impl GuildId {
+    /// Adds a [`Role`] to each of the given members of the guild.
+    ///
+    /// The requests are performed one after another, respecting the
+    /// ratelimiter. A failure for one member does not stop the remaining
+    /// members from being processed.
+    ///
+    /// Returns the Ids of the members that could not be given the role, along
+    /// with the error that occurred for each of them. An empty list means that
+    /// every request succeeded.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`Role`]: ../guild/struct.Role.html
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub async fn add_role_to_members<It>(
+        self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        user_ids: It,
+    ) -> Vec<(UserId, Error)>
+        where It: IntoIterator, It::Item: Into<UserId> {
+        self._add_role_to_members(http.as_ref(), role_id.into(), user_ids, None).await
+    }
+
+    /// Gives a [`Role`] to each of the given members of the guild, with a
+    /// reason shown in the guild's audit log.
+    ///
+    /// Refer to [`add_role_to_members`] for more information.
+    ///
+    /// [`Role`]: ../guild/struct.Role.html
+    /// [`add_role_to_members`]: #method.add_role_to_members
+    #[inline]
+    pub async fn add_role_to_members_with_reason<It>(
+        self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        user_ids: It,
+        reason: &str,
+    ) -> Vec<(UserId, Error)>
+        where It: IntoIterator, It::Item: Into<UserId> {
+        self._add_role_to_members(http.as_ref(), role_id.into(), user_ids, Some(reason)).await
+    }
+
+    async fn _add_role_to_members<It>(
+        self,
+        http: &Http,
+        role_id: RoleId,
+        user_ids: It,
+        reason: Option<&str>,
+    ) -> Vec<(UserId, Error)>
+        where It: IntoIterator, It::Item: Into<UserId> {
+        let mut failures = Vec::new();
+
+        for user_id in user_ids {
+            let user_id = user_id.into();
+
+            if let Err(why) = http.add_member_role(self.0, user_id.0, role_id.0, reason).await {
+                failures.push((user_id, why));
+            }
+        }
+
+        failures
+    }
+
     /// Ban a [`User`] from the guild, deleting a number of
     /// days' worth of messages (`dmd`) between the range 0 and 7.
     ///
@@ -92,6 +157,62 @@ impl GuildId {
         http.as_ref().ban_user(self.0, user.0, dmd, reason).await
     }
 
+    /// Bans each of the given [`User`]s from the guild with a reason, deleting
+    /// a number of days' worth of their messages (`dmd`) between the range 0
+    /// and 7.
+    ///
+    /// The requests are performed one after another, respecting the
+    /// ratelimiter. A failure for one user does not stop the remaining users
+    /// from being processed.
+    ///
+    /// Returns the Ids of the users that could not be banned, along with the
+    /// error that occurred for each of them. An empty list means that every
+    /// request succeeded.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::DeleteMessageDaysAmount`] if the number of
+    /// days' worth of messages to delete is over the maximum, and an
+    /// [`Error::ExceededLimit`] if the reason is too long. No request is
+    /// performed in either case.
+    ///
+    /// [`Error::ExceededLimit`]: ../../enum.Error.html#variant.ExceededLimit
+    /// [`ModelError::DeleteMessageDaysAmount`]: ../error/enum.Error.html#variant.DeleteMessageDaysAmount
+    /// [`User`]: ../user/struct.User.html
+    /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
+    pub async fn ban_members<It>(
+        self,
+        http: impl AsRef<Http>,
+        users: It,
+        dmd: u8,
+        reason: impl AsRef<str>,
+    ) -> Result<Vec<(UserId, Error)>>
+        where It: IntoIterator, It::Item: Into<UserId> {
+        let reason = reason.as_ref();
+
+        if dmd > 7 {
+            return Err(Error::Model(ModelError::DeleteMessageDaysAmount(dmd)));
+        }
+
+        if reason.len() > 512 {
+            return Err(Error::ExceededLimit(reason.to_string(), 512));
+        }
+
+        let mut failures = Vec::new();
+
+        for user in users {
+            let user = user.into();
+
+            if let Err(why) = http.as_ref().ban_user(self.0, user.0, dmd, reason).await {
+                failures.push((user, why));
+            }
+        }
+
+        Ok(failures)
+    }
+
     /// Gets a list of the guild's bans.
     ///
     /// Requires the [Ban Members] permission.
@@ -103,16 +224,73 @@ impl GuildId {
     }
 
     /// Gets a list of the guild's audit log entries
+    ///
+    /// The entries may be filtered to a single kind of [`Action`] and to the
+    /// user who performed it.
+    ///
+    /// [`Action`]: ../guild/enum.Action.html
     #[inline]
     pub async fn audit_logs(
         self,
         http: impl AsRef<Http>,
-        action_type: Option<u8>,
+        action_type: Option<Action>,
         user_id: Option<UserId>,
         before: Option<AuditLogEntryId>,
         limit: Option<u8>
     ) -> Result<AuditLogs> {
-        http.as_ref().get_audit_logs(self.0, action_type, user_id.map(|u| u.0), before.map(|a| a.0), limit).await
+        http.as_ref().get_audit_logs(
+            self.0,
+            action_type.map(|a| a.num()),
+            user_id.map(|u| u.0),
+            before.map(|a| a.0),
+            limit,
+        ).await
+    }
+
+    /// Streams over the guild's audit log entries, newest first.
+    ///
+    /// This is accomplished and equivalent to repeated calls to
+    /// [`audit_logs`], paginating backwards with `before`. The entries may be
+    /// filtered the same way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::GuildId;
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let guild_id = GuildId::default();
+    /// # let ctx = Http::default();
+    /// use serenity::model::guild::{Action, ActionMember};
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut entries = guild_id
+    ///     .audit_logs_iter(&ctx, Some(Action::Member(ActionMember::BanAdd)), None)
+    ///     .boxed();
+    ///
+    /// while let Some(entry_result) = entries.next().await {
+    ///     match entry_result {
+    ///         Ok(entry) => println!(
+    ///             "{} banned {:?}: {:?}",
+    ///             entry.user_id,
+    ///             entry.target_id,
+    ///             entry.reason,
+    ///         ),
+    ///         Err(error) => eprintln!("Uh oh! Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`audit_logs`]: #method.audit_logs
+    pub fn audit_logs_iter<H: AsRef<Http>>(
+        self,
+        http: H,
+        action_type: Option<Action>,
+        user_id: Option<UserId>,
+    ) -> impl Stream<Item=Result<AuditLogEntry>> {
+        AuditLogEntriesIter::<H>::stream(http, self, action_type, user_id)
     }
 
     /// Gets all of the guild's channels over the REST API.
@@ -164,7 +342,7 @@ impl GuildId {
 
         let map = utils::hashmap_to_json_map(builder.0);
 
-        http.as_ref().create_channel(self.0, &map).await
+        http.as_ref().create_channel(self.0, &map, builder.1.as_deref()).await
     }
 
     /// Creates an emoji in the guild with a name and base64-encoded image.
@@ -191,7 +369,23 @@ impl GuildId {
             "image": image,
         });
 
-        http.as_ref().create_emoji(self.0, &map).await
+        http.as_ref().create_emoji(self.0, &map, None).await
+    }
+
+    /// Creates an emoji in the guild with a name and base64-encoded image,
+    /// with a reason shown in the guild's audit log.
+    ///
+    /// Refer to [`create_emoji`] for more information.
+    ///
+    /// [`create_emoji`]: #method.create_emoji
+    #[inline]
+    pub async fn create_emoji_with_reason(self, http: impl AsRef<Http>, name: &str, image: &str, reason: &str) -> Result<Emoji> {
+        let map = json!({
+            "name": name,
+            "image": image,
+        });
+
+        http.as_ref().create_emoji(self.0, &map, Some(reason)).await
     }
 
     /// Creates an integration for the guild.
@@ -207,7 +401,30 @@ impl GuildId {
             "type": kind,
         });
 
-        http.as_ref().create_guild_integration(self.0, integration_id.0, &map).await
+        http.as_ref().create_guild_integration(self.0, integration_id.0, &map, None).await
+    }
+
+    /// Creates an integration for the guild, with a reason shown in the
+    /// guild's audit log.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn create_integration_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        integration_id: impl Into<IntegrationId>,
+        kind: &str,
+        reason: &str,
+    ) -> Result<()> {
+        let integration_id = integration_id.into();
+        let map = json!({
+            "id": integration_id.0,
+            "type": kind,
+        });
+
+        http.as_ref().create_guild_integration(self.0, integration_id.0, &map, Some(reason)).await
     }
 
     /// Creates a new role in the guild with the data set, if any.
@@ -225,10 +442,11 @@ impl GuildId {
         f(&mut edit_role);
         let map = utils::hashmap_to_json_map(edit_role.0);
 
-        let role = http.as_ref().create_role(self.0, &map).await?;
+        let reason = edit_role.1.as_deref();
+        let role = http.as_ref().create_role(self.0, &map, reason).await?;
 
         if let Some(position) = map.get("position").and_then(Value::as_u64) {
-            self.edit_role_position(&http, role.id, position).await?;
+            http.as_ref().edit_role_position(self.0, role.id.0, position, reason).await?;
         }
 
         Ok(role)
@@ -255,7 +473,19 @@ impl GuildId {
     /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
     #[inline]
     pub async fn delete_emoji(self, http: impl AsRef<Http>, emoji_id: impl Into<EmojiId>) -> Result<()> {
-        http.as_ref().delete_emoji(self.0, emoji_id.into().0).await
+        http.as_ref().delete_emoji(self.0, emoji_id.into().0, None).await
+    }
+
+    /// Deletes an [`Emoji`] from the guild, with a reason shown in the
+    /// guild's audit log.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// [`Emoji`]: ../guild/struct.Emoji.html
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[inline]
+    pub async fn delete_emoji_with_reason(self, http: impl AsRef<Http>, emoji_id: impl Into<EmojiId>, reason: &str) -> Result<()> {
+        http.as_ref().delete_emoji(self.0, emoji_id.into().0, Some(reason)).await
     }
 
     /// Deletes an integration by Id from the guild.
@@ -265,7 +495,23 @@ impl GuildId {
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     #[inline]
     pub async fn delete_integration(self, http: impl AsRef<Http>, integration_id: impl Into<IntegrationId>) -> Result<()> {
-        http.as_ref().delete_guild_integration(self.0, integration_id.into().0).await
+        http.as_ref().delete_guild_integration(self.0, integration_id.into().0, None).await
+    }
+
+    /// Deletes an integration by Id from the guild, with a reason shown in the
+    /// guild's audit log.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn delete_integration_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        integration_id: impl Into<IntegrationId>,
+        reason: &str,
+    ) -> Result<()> {
+        http.as_ref().delete_guild_integration(self.0, integration_id.into().0, Some(reason)).await
     }
 
     /// Deletes a [`Role`] by Id from the guild.
@@ -280,7 +526,19 @@ impl GuildId {
     /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
     #[inline]
     pub async fn delete_role(self, http: impl AsRef<Http>, role_id: impl Into<RoleId>) -> Result<()> {
-        http.as_ref().delete_role(self.0, role_id.into().0).await
+        http.as_ref().delete_role(self.0, role_id.into().0, None).await
+    }
+
+    /// Deletes a [`Role`] by Id from the guild, with a reason shown in the
+    /// guild's audit log.
+    ///
+    /// Requires the [Manage Roles] permission.
+    ///
+    /// [`Role`]: ../guild/struct.Role.html
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub async fn delete_role_with_reason(self, http: impl AsRef<Http>, role_id: impl Into<RoleId>, reason: &str) -> Result<()> {
+        http.as_ref().delete_role(self.0, role_id.into().0, Some(reason)).await
     }
 
     /// Edits the current guild with new data where specified.
@@ -300,7 +558,7 @@ impl GuildId {
         f(&mut edit_guild);
         let map = utils::hashmap_to_json_map(edit_guild.0);
 
-        http.as_ref().edit_guild(self.0, &map).await
+        http.as_ref().edit_guild(self.0, &map, edit_guild.1.as_deref()).await
     }
 
     /// Edits an [`Emoji`]'s name in the guild.
@@ -319,7 +577,29 @@ impl GuildId {
             "name": name,
         });
 
-        http.as_ref().edit_emoji(self.0, emoji_id.into().0, &map).await
+        http.as_ref().edit_emoji(self.0, emoji_id.into().0, &map, None).await
+    }
+
+    /// Edits an [`Emoji`]'s name in the guild, with a reason shown in the
+    /// guild's audit log.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// [`Emoji`]: ../guild/struct.Emoji.html
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[inline]
+    pub async fn edit_emoji_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        emoji_id: impl Into<EmojiId>,
+        name: &str,
+        reason: &str,
+    ) -> Result<Emoji> {
+        let map = json!({
+            "name": name,
+        });
+
+        http.as_ref().edit_emoji(self.0, emoji_id.into().0, &map, Some(reason)).await
     }
 
     /// Edits the properties of member of the guild, such as muting or
@@ -342,7 +622,26 @@ impl GuildId {
         f(&mut edit_member);
         let map = utils::hashmap_to_json_map(edit_member.0);
 
-        http.as_ref().edit_member(self.0, user_id.into().0, &map).await
+        http.as_ref().edit_member(self.0, user_id.into().0, &map, edit_member.1.as_deref()).await
+    }
+
+    /// Edits the guild's membership screening form.
+    ///
+    /// Refer to [`EditMembershipScreening`]'s documentation for a full list of
+    /// methods.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`EditMembershipScreening`]: ../../builder/struct.EditMembershipScreening.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn edit_membership_screening<F>(self, http: impl AsRef<Http>, f: F) -> Result<MembershipScreening>
+        where F: FnOnce(&mut EditMembershipScreening) -> &mut EditMembershipScreening {
+        let mut edit_screening = EditMembershipScreening::default();
+        f(&mut edit_screening);
+        let map = utils::hashmap_to_json_map(edit_screening.0);
+
+        http.as_ref().edit_guild_membership_screening(self.0, &map, edit_screening.1.as_deref()).await
     }
 
     /// Edits the current user's nickname for the guild.
@@ -354,7 +653,39 @@ impl GuildId {
     /// [Change Nickname]: ../permissions/struct.Permissions.html#associatedconstant.CHANGE_NICKNAME
     #[inline]
     pub async fn edit_nickname(self, http: impl AsRef<Http>, new_nickname: Option<&str>) -> Result<()> {
-        http.as_ref().edit_nickname(self.0, new_nickname).await
+        http.as_ref().edit_nickname(self.0, new_nickname, None).await
+    }
+
+    /// Edits the current user's nickname for the guild, with a reason shown in
+    /// the guild's audit log.
+    ///
+    /// Pass `None` to reset the nickname.
+    ///
+    /// Requires the [Change Nickname] permission.
+    ///
+    /// [Change Nickname]: ../permissions/struct.Permissions.html#associatedconstant.CHANGE_NICKNAME
+    #[inline]
+    pub async fn edit_nickname_with_reason(self, http: impl AsRef<Http>, new_nickname: Option<&str>, reason: &str) -> Result<()> {
+        http.as_ref().edit_nickname(self.0, new_nickname, Some(reason)).await
+    }
+
+    /// Edits the guild's welcome screen.
+    ///
+    /// Refer to [`EditGuildWelcomeScreen`]'s documentation for a full list of
+    /// methods.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`EditGuildWelcomeScreen`]: ../../builder/struct.EditGuildWelcomeScreen.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn edit_welcome_screen<F>(self, http: impl AsRef<Http>, f: F) -> Result<GuildWelcomeScreen>
+        where F: FnOnce(&mut EditGuildWelcomeScreen) -> &mut EditGuildWelcomeScreen {
+        let mut edit_welcome_screen = EditGuildWelcomeScreen::default();
+        f(&mut edit_welcome_screen);
+        let map = utils::hashmap_to_json_map(edit_welcome_screen.0);
+
+        http.as_ref().edit_guild_welcome_screen(self.0, &map, edit_welcome_screen.1.as_deref()).await
     }
 
     /// Edits a [`Role`], optionally setting its new fields.
@@ -381,7 +712,7 @@ impl GuildId {
         f(&mut edit_role);
         let map = utils::hashmap_to_json_map(edit_role.0);
 
-        http.as_ref().edit_role(self.0, role_id.into().0, &map).await
+        http.as_ref().edit_role(self.0, role_id.into().0, &map, edit_role.1.as_deref()).await
     }
 
     /// Edits the order of [`Role`]s
@@ -400,7 +731,25 @@ impl GuildId {
     /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
     #[inline]
     pub async fn edit_role_position(self, http: impl AsRef<Http>, role_id: impl Into<RoleId>, position: u64) -> Result<Vec<Role>> {
-        http.as_ref().edit_role_position(self.0, role_id.into().0, position).await
+        http.as_ref().edit_role_position(self.0, role_id.into().0, position, None).await
+    }
+
+    /// Edits the order of [`Role`]s, with a reason shown in the guild's audit
+    /// log.
+    ///
+    /// Requires the [Manage Roles] permission.
+    ///
+    /// [`Role`]: ../guild/struct.Role.html
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub async fn edit_role_position_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        position: u64,
+        reason: &str,
+    ) -> Result<Vec<Role>> {
+        http.as_ref().edit_role_position(self.0, role_id.into().0, position, Some(reason)).await
     }
 
     /// Tries to find the [`Guild`] by its Id in the cache.
@@ -550,6 +899,26 @@ impl GuildId {
         MembersIter::<H>::stream(http, self)
     }
 
+    /// Searches the guild's members whose username or nickname starts with
+    /// the given `query`.
+    ///
+    /// Optionally pass in the `limit` to limit the number of results. Defaults
+    /// to 1, and the maximum value is 1000.
+    #[inline]
+    pub async fn search_members(self, http: impl AsRef<Http>, query: &str, limit: Option<u64>) -> Result<Vec<Member>> {
+        http.as_ref().search_guild_members(self.0, query, limit).await
+    }
+
+    /// Gets the guild's membership screening form.
+    ///
+    /// [`Member::pending`] indicates whether a member has passed it yet.
+    ///
+    /// [`Member::pending`]: ../guild/struct.Member.html#structfield.pending
+    #[inline]
+    pub async fn membership_screening(self, http: impl AsRef<Http>) -> Result<MembershipScreening> {
+        http.as_ref().get_guild_membership_screening(self.0).await
+    }
+
     /// Moves a member to a specific voice channel.
     ///
     /// Requires the [Move Members] permission.
@@ -568,7 +937,30 @@ impl GuildId {
             Value::Number(Number::from(channel_id.into().0)),
         );
 
-        http.as_ref().edit_member(self.0, user_id.into().0, &map).await
+        http.as_ref().edit_member(self.0, user_id.into().0, &map, None).await
+    }
+
+    /// Moves a member to a specific voice channel, with a reason shown in the
+    /// guild's audit log.
+    ///
+    /// Requires the [Move Members] permission.
+    ///
+    /// [Move Members]: ../permissions/struct.Permissions.html#associatedconstant.MOVE_MEMBERS
+    #[inline]
+    pub async fn move_member_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+        channel_id: impl Into<ChannelId>,
+        reason: &str,
+    ) -> Result<()> {
+        let mut map = Map::new();
+        map.insert(
+            "channel_id".to_string(),
+            Value::Number(Number::from(channel_id.into().0)),
+        );
+
+        http.as_ref().edit_member(self.0, user_id.into().0, &map, Some(reason)).await
     }
 
     /// Returns the name of whatever guild this id holds.
@@ -590,7 +982,34 @@ impl GuildId {
             "channel_id".to_string(),
             Value::Null
         );
-        http.as_ref().edit_member(self.0, user_id.into().0, &map).await
+        http.as_ref().edit_member(self.0, user_id.into().0, &map, None).await
+    }
+
+    /// Disconnects a member from a voice channel in the guild, with a reason
+    /// shown in the guild's audit log.
+    ///
+    /// Requires the [Move Members] permission.
+    ///
+    /// [Move Members]: ../permissions/struct.Permissions.html#associatedconstant.MOVE_MEMBERS
+    #[inline]
+    pub async fn disconnect_member_with_reason(self, http: impl AsRef<Http>, user_id: impl Into<UserId>, reason: &str) -> Result<()> {
+        let mut map = Map::new();
+        map.insert(
+            "channel_id".to_string(),
+            Value::Null
+        );
+        http.as_ref().edit_member(self.0, user_id.into().0, &map, Some(reason)).await
+    }
+
+    /// Gets the [`GuildPreview`] of the guild.
+    ///
+    /// **Note**: The current user must be a member of the guild unless it is
+    /// discoverable.
+    ///
+    /// [`GuildPreview`]: ../guild/struct.GuildPreview.html
+    #[inline]
+    pub async fn preview(self, http: impl AsRef<Http>) -> Result<GuildPreview> {
+        http.as_ref().get_guild_preview(self.0).await
     }
 
     /// Gets the number of [`Member`]s that would be pruned with the given
@@ -609,6 +1028,66 @@ impl GuildId {
         http.as_ref().get_guild_prune_count(self.0, &map).await
     }
 
+    /// Removes a [`Role`] from each of the given members of the guild.
+    ///
+    /// Refer to [`add_role_to_members`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`Role`]: ../guild/struct.Role.html
+    /// [`add_role_to_members`]: #method.add_role_to_members
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub async fn remove_role_from_members<It>(
+        self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        user_ids: It,
+    ) -> Vec<(UserId, Error)>
+        where It: IntoIterator, It::Item: Into<UserId> {
+        self._remove_role_from_members(http.as_ref(), role_id.into(), user_ids, None).await
+    }
+
+    /// Removes a [`Role`] from each of the given members of the guild, with a
+    /// reason shown in the guild's audit log.
+    ///
+    /// Refer to [`add_role_to_members`] for more information.
+    ///
+    /// [`Role`]: ../guild/struct.Role.html
+    /// [`add_role_to_members`]: #method.add_role_to_members
+    #[inline]
+    pub async fn remove_role_from_members_with_reason<It>(
+        self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        user_ids: It,
+        reason: &str,
+    ) -> Vec<(UserId, Error)>
+        where It: IntoIterator, It::Item: Into<UserId> {
+        self._remove_role_from_members(http.as_ref(), role_id.into(), user_ids, Some(reason)).await
+    }
+
+    async fn _remove_role_from_members<It>(
+        self,
+        http: &Http,
+        role_id: RoleId,
+        user_ids: It,
+        reason: Option<&str>,
+    ) -> Vec<(UserId, Error)>
+        where It: IntoIterator, It::Item: Into<UserId> {
+        let mut failures = Vec::new();
+
+        for user_id in user_ids {
+            let user_id = user_id.into();
+
+            if let Err(why) = http.remove_member_role(self.0, user_id.0, role_id.0, reason).await {
+                failures.push((user_id, why));
+            }
+        }
+
+        failures
+    }
+
     /// Re-orders the channels of the guild.
     ///
     /// Accepts an iterator of a tuple of the channel ID to modify and its new
@@ -620,6 +1099,25 @@ impl GuildId {
     #[inline]
     pub async fn reorder_channels<It>(self, http: impl AsRef<Http>, channels: It) -> Result<()>
     where It: IntoIterator<Item = (ChannelId, u64)>
+    {
+        self._reorder_channels(http.as_ref(), channels, None).await
+    }
+
+    /// Re-orders the channels of the guild, with a reason shown in the guild's
+    /// audit log.
+    ///
+    /// Refer to [`reorder_channels`] for more information.
+    ///
+    /// [`reorder_channels`]: #method.reorder_channels
+    #[inline]
+    pub async fn reorder_channels_with_reason<It>(self, http: impl AsRef<Http>, channels: It, reason: &str) -> Result<()>
+    where It: IntoIterator<Item = (ChannelId, u64)>
+    {
+        self._reorder_channels(http.as_ref(), channels, Some(reason)).await
+    }
+
+    async fn _reorder_channels<It>(self, http: &Http, channels: It, reason: Option<&str>) -> Result<()>
+    where It: IntoIterator<Item = (ChannelId, u64)>
     {
         let items = channels
             .into_iter()
@@ -628,7 +1126,7 @@ impl GuildId {
                 "position": pos,
             })).collect();
 
-        http.as_ref().edit_guild_channel_positions(self.0, &Value::Array(items)).await
+        http.edit_guild_channel_positions(self.0, &Value::Array(items), reason).await
     }
 
     /// Returns the Id of the shard associated with the guild.
@@ -681,7 +1179,23 @@ impl GuildId {
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     #[inline]
     pub async fn start_integration_sync(self, http: impl AsRef<Http>, integration_id: impl Into<IntegrationId>) -> Result<()> {
-        http.as_ref().start_integration_sync(self.0, integration_id.into().0).await
+        http.as_ref().start_integration_sync(self.0, integration_id.into().0, None).await
+    }
+
+    /// Starts an integration sync for the given integration Id, with a reason
+    /// shown in the guild's audit log.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn start_integration_sync_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        integration_id: impl Into<IntegrationId>,
+        reason: &str,
+    ) -> Result<()> {
+        http.as_ref().start_integration_sync(self.0, integration_id.into().0, Some(reason)).await
     }
 
     /// Starts a prune of [`Member`]s.
@@ -699,7 +1213,23 @@ impl GuildId {
             "days": days,
         });
 
-        http.as_ref().start_guild_prune(self.0, &map).await
+        http.as_ref().start_guild_prune(self.0, &map, None).await
+    }
+
+    /// Starts a prune of [`Member`]s, with a reason shown in the guild's audit
+    /// log.
+    ///
+    /// **Note**: Requires the [Kick Members] permission.
+    ///
+    /// [`Member`]: ../guild/struct.Member.html
+    /// [Kick Members]: ../permissions/struct.Permissions.html#associatedconstant.KICK_MEMBERS
+    #[inline]
+    pub async fn start_prune_with_reason(self, http: impl AsRef<Http>, days: u16, reason: &str) -> Result<GuildPrune> {
+        let map = json!({
+            "days": days,
+        });
+
+        http.as_ref().start_guild_prune(self.0, &map, Some(reason)).await
     }
 
     /// Unbans a [`User`] from the guild.
@@ -710,7 +1240,19 @@ impl GuildId {
     /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
     #[inline]
     pub async fn unban(self, http: impl AsRef<Http>, user_id: impl Into<UserId>) -> Result<()> {
-        http.as_ref().remove_ban(self.0, user_id.into().0).await
+        http.as_ref().remove_ban(self.0, user_id.into().0, None).await
+    }
+
+    /// Unbans a [`User`] from the guild, with a reason shown in the guild's
+    /// audit log.
+    ///
+    /// Requires the [Ban Members] permission.
+    ///
+    /// [`User`]: ../user/struct.User.html
+    /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
+    #[inline]
+    pub async fn unban_with_reason(self, http: impl AsRef<Http>, user_id: impl Into<UserId>, reason: &str) -> Result<()> {
+        http.as_ref().remove_ban(self.0, user_id.into().0, Some(reason)).await
     }
 
     /// Retrieve's the guild's vanity URL.
@@ -733,6 +1275,12 @@ impl GuildId {
         http.as_ref().get_guild_webhooks(self.0).await
     }
 
+    /// Gets the guild's welcome screen.
+    #[inline]
+    pub async fn welcome_screen(self, http: impl AsRef<Http>) -> Result<GuildWelcomeScreen> {
+        http.as_ref().get_guild_welcome_screen(self.0).await
+    }
+
     /// Returns a future that will await one message sent in this guild.
     #[cfg(feature = "collector")]
     pub fn await_reply<'a>(&self, shard_messenger: &'a impl AsRef<ShardMessenger>) -> CollectReply<'a> {
@@ -896,3 +1444,116 @@ impl<H: AsRef<Http>> MembersIter<H> {
         })
     }
 }
+
+/// A helper class returned by [`GuildId::audit_logs_iter`]
+///
+/// [`GuildId::audit_logs_iter`]: ../id/struct.GuildId.html#method.audit_logs_iter
+#[derive(Clone, Debug)]
+#[cfg(feature = "model")]
+pub struct AuditLogEntriesIter<H: AsRef<Http>> {
+    guild_id: GuildId,
+    http: H,
+    action_type: Option<Action>,
+    user_id: Option<UserId>,
+    buffer: Vec<AuditLogEntry>,
+    before: Option<AuditLogEntryId>,
+    tried_fetch: bool,
+}
+
+#[cfg(feature = "model")]
+impl<H: AsRef<Http>> AuditLogEntriesIter<H> {
+    fn new(guild_id: GuildId, http: H, action_type: Option<Action>, user_id: Option<UserId>) -> AuditLogEntriesIter<H> {
+        AuditLogEntriesIter {
+            guild_id,
+            http,
+            action_type,
+            user_id,
+            buffer: Vec::new(),
+            before: None,
+            tried_fetch: false,
+        }
+    }
+
+    /// Fills the `self.buffer` cache of entries.
+    ///
+    /// This drops any entries that were currently in the buffer, so it should
+    /// only be called when `self.buffer` is empty. Additionally, this updates
+    /// `self.before` so that the next call does not return duplicate items.
+    /// If there are no more entries to be fetched, then this marks
+    /// `self.before` as None, indicating that no more calls ought to be made.
+    ///
+    /// The entries are sorted such that the newest entry is the last element
+    /// of the buffer and the oldest is the first.
+    async fn refresh(&mut self) -> Result<()> {
+        // Number of entries to fetch.
+        let grab_size: u8 = 100;
+
+        let logs = self.guild_id
+            .audit_logs(&self.http, self.action_type, self.user_id, self.before, Some(grab_size))
+            .await?;
+
+        self.buffer = logs.entries.into_iter().map(|(_, entry)| entry).collect();
+        self.buffer.sort_by_key(|entry| entry.id);
+
+        // If fewer entries than requested were returned, there are no older
+        // ones left.
+        self.before = if self.buffer.len() < grab_size as usize {
+            None
+        } else {
+            self.buffer.first().map(|entry| entry.id)
+        };
+
+        self.tried_fetch = true;
+
+        Ok(())
+    }
+
+    /// Streams over the audit log entries of a guild, newest first.
+    ///
+    /// This is accomplished and equivalent to repeated calls to
+    /// [`audit_logs`]. A buffer of at most 100 entries is used to reduce the
+    /// number of calls necessary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::GuildId;
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let guild_id = GuildId::default();
+    /// # let ctx = Http::default();
+    /// use serenity::model::guild::AuditLogEntriesIter;
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut entries = AuditLogEntriesIter::<Http>::stream(&ctx, guild_id, None, None).boxed();
+    /// while let Some(entry_result) = entries.next().await {
+    ///     match entry_result {
+    ///         Ok(entry) => println!("{:?} by {}", entry.action, entry.user_id),
+    ///         Err(error) => eprintln!("Uh oh! Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`audit_logs`]: ../id/struct.GuildId.html#method.audit_logs
+    pub fn stream(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+        action_type: Option<Action>,
+        user_id: Option<UserId>,
+    ) -> impl Stream<Item=Result<AuditLogEntry>> {
+        let init_state = AuditLogEntriesIter::new(guild_id, http, action_type, user_id);
+
+        futures::stream::unfold(init_state, |mut state| async {
+            if state.buffer.is_empty() && state.before.is_some() || !state.tried_fetch {
+                if let Err(error) = state.refresh().await {
+                    return Some((Err(error), state));
+                }
+            }
+
+            // `pop()` returns the last element which is the newest entry.
+            state.buffer.pop().map(|entry| (Ok(entry), state))
+        })
+    }
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+/// Preview information about a [`Guild`], available for lurkable (discoverable)
+/// guilds even if the current user is not a member.
+///
+/// [`Guild`]: struct.Guild.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildPreview {
+    /// The guild Id.
+    pub id: GuildId,
+    /// The guild name.
+    pub name: String,
+    /// The guild icon hash if it has one.
+    pub icon: Option<String>,
+    /// The guild splash hash if it has one.
+    pub splash: Option<String>,
+    /// The guild discovery splash hash it it has one.
+    pub discovery_splash: Option<String>,
+    /// The custom guild emojis.
+    pub emojis: Vec<Emoji>,
+    /// The guild features. See [`Guild::features`]
+    ///
+    /// [`Guild::features`]: struct.Guild.html#structfield.features
+    pub features: Vec<String>,
+    /// Approximate number of members in this guild.
+    pub approximate_member_count: u64,
+    /// Approximate number of online members in this guild.
+    pub approximate_presence_count: u64,
+    /// The description for the guild, if the guild has the `DISCOVERABLE`
+    /// feature.
+    pub description: Option<String>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+#[cfg(feature = "model")]
+impl GuildPreview {
+    /// Returns the formatted URL of the guild's icon, if the guild has an icon.
+    pub fn icon_url(&self) -> Option<String> {
+        self.icon
+            .as_ref()
+            .map(|icon| format!(cdn!("/icons/{}/{}.webp"), self.id, icon))
+    }
+
+    /// Returns the formatted URL of the guild's splash image, if one exists.
+    pub fn splash_url(&self) -> Option<String> {
+        self.splash
+            .as_ref()
+            .map(|splash| format!(cdn!("/splashes/{}/{}.webp"), self.id, splash))
+    }
+
+    /// Returns the formatted URL of the guild's discovery splash image, if one
+    /// exists.
+    pub fn discovery_splash_url(&self) -> Option<String> {
+        self.discovery_splash
+            .as_ref()
+            .map(|splash| format!(cdn!("/discovery-splashes/{}/{}.webp"), self.id, splash))
+    }
+}
+
+impl From<GuildPreview> for GuildId {
+    /// Gets the Id of the guild preview.
+    fn from(preview: GuildPreview) -> GuildId { preview.id }
+}
+
+impl<'a> From<&'a GuildPreview> for GuildId {
+    /// Gets the Id of the guild preview.
+    fn from(preview: &GuildPreview) -> GuildId { preview.id }
+}
@@ -16,6 +16,8 @@ use std::borrow::Cow;
 use crate::utils::Colour;
 #[cfg(all(feature = "cache"))]
 use crate::cache::Cache;
+#[cfg(all(feature = "cache", feature = "model"))]
+use std::collections::HashMap;
 #[cfg(feature = "model")]
 use crate::utils;
 #[cfg(feature = "model")]
@@ -36,6 +38,12 @@ pub struct Member {
     ///
     /// Can't be longer than 32 characters.
     pub nick: Option<String>,
+    /// Indicator of whether the member has not yet passed the guild's
+    /// [`MembershipScreening`] requirements.
+    ///
+    /// [`MembershipScreening`]: struct.MembershipScreening.html
+    #[serde(default)]
+    pub pending: bool,
     /// Vector of Ids of [`Role`](struct.Role.html)s given to the member.
     pub roles: Vec<RoleId>,
     /// Attached User struct.
@@ -55,15 +63,27 @@ impl Member {
     /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
     #[inline]
     pub async fn add_role(&mut self, http: impl AsRef<Http>, role_id: impl Into<RoleId>) -> Result<()> {
-        self._add_role(&http, role_id.into()).await
+        self._add_role(http.as_ref(), role_id.into(), None).await
+    }
+
+    /// Adds a [`Role`] to the member, with a reason shown in the guild's audit
+    /// log.
+    ///
+    /// Refer to [`add_role`] for more information.
+    ///
+    /// [`Role`]: struct.Role.html
+    /// [`add_role`]: #method.add_role
+    #[inline]
+    pub async fn add_role_with_reason(&mut self, http: impl AsRef<Http>, role_id: impl Into<RoleId>, reason: &str) -> Result<()> {
+        self._add_role(http.as_ref(), role_id.into(), Some(reason)).await
     }
 
-    async fn _add_role(&mut self, http: impl AsRef<Http>, role_id: RoleId) -> Result<()> {
+    async fn _add_role(&mut self, http: &Http, role_id: RoleId, reason: Option<&str>) -> Result<()> {
         if self.roles.contains(&role_id) {
             return Ok(());
         }
 
-        match http.as_ref().add_member_role(self.guild_id.0, self.user.id.0, role_id.0).await {
+        match http.add_member_role(self.guild_id.0, self.user.id.0, role_id.0, reason).await {
             Ok(()) => {
                 self.roles.push(role_id);
 
@@ -80,14 +100,31 @@ impl Member {
     ///
     /// [`Role`]: struct.Role.html
     /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
     pub async fn add_roles(&mut self, http: impl AsRef<Http>, role_ids: &[RoleId]) -> Result<()> {
+        self._add_roles(http.as_ref(), role_ids, None).await
+    }
+
+    /// Adds one or multiple [`Role`]s to the member, with a reason shown in the
+    /// guild's audit log.
+    ///
+    /// Refer to [`add_roles`] for more information.
+    ///
+    /// [`Role`]: struct.Role.html
+    /// [`add_roles`]: #method.add_roles
+    #[inline]
+    pub async fn add_roles_with_reason(&mut self, http: impl AsRef<Http>, role_ids: &[RoleId], reason: &str) -> Result<()> {
+        self._add_roles(http.as_ref(), role_ids, Some(reason)).await
+    }
+
+    async fn _add_roles(&mut self, http: &Http, role_ids: &[RoleId], reason: Option<&str>) -> Result<()> {
         self.roles.extend_from_slice(role_ids);
 
         let mut builder = EditMember::default();
         builder.roles(&self.roles);
         let map = utils::hashmap_to_json_map(builder.0);
 
-        match http.as_ref().edit_member(self.guild_id.0, self.user.id.0, &map).await {
+        match http.edit_member(self.guild_id.0, self.user.id.0, &map, reason).await {
             Ok(()) => Ok(()),
             Err(why) => {
                 self.roles.retain(|r| !role_ids.contains(r));
@@ -193,7 +230,7 @@ impl Member {
         f(&mut edit_member);
         let map = utils::hashmap_to_json_map(edit_member.0);
 
-        http.as_ref().edit_member(self.guild_id.0, self.user.id.0, &map).await
+        http.as_ref().edit_member(self.guild_id.0, self.user.id.0, &map, edit_member.1.as_deref()).await
     }
 
     /// Retrieves the ID and position of the member's highest role in the
@@ -210,27 +247,9 @@ impl Member {
     /// role with the lowest ID is the highest.
     #[cfg(feature = "cache")]
     pub async fn highest_role_info(&self, cache: impl AsRef<Cache>) -> Option<(RoleId, i64)> {
-        let guild_roles = cache.as_ref().guild_field(self.guild_id, |g| g.roles.clone()).await?;
-
-        let mut highest = None;
-
-        for role_id in &self.roles {
-            if let Some(role) = guild_roles.get(&role_id) {
-                // Skip this role if this role in iteration has:
-                //
-                // - a position less than the recorded highest
-                // - a position equal to the recorded, but a higher ID
-                if let Some((id, pos)) = highest {
-                    if role.position < pos || (role.position == pos && role.id > id) {
-                        continue;
-                    }
-                }
-
-                highest = Some((role.id, role.position));
-            }
-        }
+        let role_ids = &self.roles;
 
-        highest
+        cache.as_ref().guild_field(self.guild_id, |g| highest_role(&g.roles, role_ids)).await?
     }
 
     /// Kick the member from the guild.
@@ -301,14 +320,46 @@ impl Member {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                if let Some(guild) = cache.guilds.read().await.get(&self.guild_id) {
+                let current_id = cache.current_user_id().await;
+                let user_id = self.user.id;
+                let role_ids = &self.roles;
+
+                // Check within the cache, to avoid cloning the guild.
+                let checked = cache.guild_field(self.guild_id, |guild| {
                     let req = Permissions::KICK_MEMBERS;
 
-                    if !guild.has_perms(cache, req).await {
-                        return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                    if !guild.member_permissions(current_id).contains(req) {
+                        return Err(ModelError::InvalidPermissions(req));
+                    }
+
+                    if current_id == guild.owner_id {
+                        return Ok(());
+                    } else if user_id == guild.owner_id {
+                        return Err(ModelError::Hierarchy);
                     }
 
-                    guild.check_hierarchy(cache, self.user.id).await?;
+                    let current = match guild.members.get(&current_id) {
+                        Some(member) => highest_role(&guild.roles, &member.roles).unwrap_or((RoleId(0), 0)),
+                        None => return Ok(()),
+                    };
+                    let other = highest_role(&guild.roles, role_ids).unwrap_or((RoleId(0), 0));
+
+                    // Mirrors `Guild::greater_member_hierarchy`, where no one
+                    // winning does not prevent the kick either.
+                    let allowed = (current.1 == 0 && other.1 == 0)
+                        || current.0 == other.0
+                        || current.1 > other.1
+                        || (current.1 == other.1 && current.0 < other.0);
+
+                    if allowed {
+                        Ok(())
+                    } else {
+                        Err(ModelError::Hierarchy)
+                    }
+                }).await;
+
+                if let Some(Err(why)) = checked {
+                    return Err(Error::Model(why));
                 }
             }
         }
@@ -376,14 +427,29 @@ impl Member {
     ///
     /// [`Role`]: struct.Role.html
     /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
     pub async fn remove_role(&mut self, http: impl AsRef<Http>, role_id: impl Into<RoleId>) -> Result<()> {
-        let role_id = role_id.into();
+        self._remove_role(http.as_ref(), role_id.into(), None).await
+    }
 
+    /// Removes a [`Role`] from the member, with a reason shown in the guild's
+    /// audit log.
+    ///
+    /// Refer to [`remove_role`] for more information.
+    ///
+    /// [`Role`]: struct.Role.html
+    /// [`remove_role`]: #method.remove_role
+    #[inline]
+    pub async fn remove_role_with_reason(&mut self, http: impl AsRef<Http>, role_id: impl Into<RoleId>, reason: &str) -> Result<()> {
+        self._remove_role(http.as_ref(), role_id.into(), Some(reason)).await
+    }
+
+    async fn _remove_role(&mut self, http: &Http, role_id: RoleId, reason: Option<&str>) -> Result<()> {
         if !self.roles.contains(&role_id) {
             return Ok(());
         }
 
-        match http.as_ref().remove_member_role(self.guild_id.0, self.user.id.0, role_id.0).await {
+        match http.remove_member_role(self.guild_id.0, self.user.id.0, role_id.0, reason).await {
             Ok(()) => {
                 self.roles.retain(|r| r.0 != role_id.0);
 
@@ -399,14 +465,28 @@ impl Member {
     ///
     /// [`Role`]: struct.Role.html
     /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
     pub async fn remove_roles(&mut self, http: impl AsRef<Http>, role_ids: &[RoleId]) -> Result<()> {
+        self._remove_roles(http.as_ref(), role_ids, None).await
+    }
+
+    /// Removes one or multiple [`Role`]s from the member, with a reason shown in
+    /// the guild's audit log.
+    ///
+    /// [`Role`]: struct.Role.html
+    #[inline]
+    pub async fn remove_roles_with_reason(&mut self, http: impl AsRef<Http>, role_ids: &[RoleId], reason: &str) -> Result<()> {
+        self._remove_roles(http.as_ref(), role_ids, Some(reason)).await
+    }
+
+    async fn _remove_roles(&mut self, http: &Http, role_ids: &[RoleId], reason: Option<&str>) -> Result<()> {
         self.roles.retain(|r| !role_ids.contains(r));
 
         let mut builder = EditMember::default();
         builder.roles(&self.roles);
         let map = utils::hashmap_to_json_map(builder.0);
 
-        match http.as_ref().edit_member(self.guild_id.0, self.user.id.0, &map).await {
+        match http.edit_member(self.guild_id.0, self.user.id.0, &map, reason).await {
             Ok(()) => Ok(()),
             Err(why) => {
                 self.roles.extend_from_slice(role_ids);
@@ -447,7 +527,19 @@ impl Member {
     /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
     #[inline]
     pub async fn unban(&self, http: impl AsRef<Http>) -> Result<()> {
-        http.as_ref().remove_ban(self.guild_id.0, self.user.id.0).await
+        http.as_ref().remove_ban(self.guild_id.0, self.user.id.0, None).await
+    }
+
+    /// Unbans the [`User`] from the guild, with a reason shown in the guild's
+    /// audit log.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// [`User`]: ../user/struct.User.html
+    /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
+    #[inline]
+    pub async fn unban_with_reason(&self, http: impl AsRef<Http>, reason: &str) -> Result<()> {
+        http.as_ref().remove_ban(self.guild_id.0, self.user.id.0, Some(reason)).await
     }
 
     /// Retrieves the member's user ID.
@@ -466,6 +558,31 @@ impl Member {
     }
 }
 
+/// Returns the Id and position of the highest of `role_ids` in `roles`,
+/// preferring the lowest Id among roles of the same position.
+#[cfg(all(feature = "cache", feature = "model"))]
+fn highest_role(roles: &HashMap<RoleId, Role>, role_ids: &[RoleId]) -> Option<(RoleId, i64)> {
+    let mut highest = None;
+
+    for role_id in role_ids {
+        if let Some(role) = roles.get(role_id) {
+            // Skip this role if this role in iteration has:
+            //
+            // - a position less than the recorded highest
+            // - a position equal to the recorded, but a higher ID
+            if let Some((id, pos)) = highest {
+                if role.position < pos || (role.position == pos && role.id > id) {
+                    continue;
+                }
+            }
+
+            highest = Some((role.id, role.position));
+        }
+    }
+
+    highest
+}
+
 impl Display for Member {
     /// Mentions the user so that they receive a notification.
     ///
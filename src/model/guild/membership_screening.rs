@@ -0,0 +1,63 @@
+use super::*;
+
+/// The membership screening (member verification) form of a community
+/// [`Guild`], which new members must complete before they can interact with
+/// the guild.
+///
+/// Members which have not yet passed screening have [`Member::pending`] set.
+///
+/// [`Guild`]: struct.Guild.html
+/// [`Member::pending`]: struct.Member.html#structfield.pending
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MembershipScreening {
+    /// When the form was last updated.
+    pub version: DateTime<Utc>,
+    /// The questions of the form.
+    #[serde(default)]
+    pub form_fields: Vec<MembershipScreeningField>,
+    /// The guild description shown in the form.
+    pub description: Option<String>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// A single question of a [`MembershipScreening`] form.
+///
+/// [`MembershipScreening`]: struct.MembershipScreening.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MembershipScreeningField {
+    /// The kind of the field.
+    pub field_type: MembershipScreeningFieldType,
+    /// The title of the field.
+    pub label: String,
+    /// The values of the field, such as the list of rules for a
+    /// [`MembershipScreeningFieldType::Terms`] field.
+    ///
+    /// [`MembershipScreeningFieldType::Terms`]: enum.MembershipScreeningFieldType.html#variant.Terms
+    #[serde(default)]
+    pub values: Vec<String>,
+    /// Whether the member has to fill in this field.
+    pub required: bool,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The kind of a [`MembershipScreeningField`].
+///
+/// [`MembershipScreeningField`]: struct.MembershipScreeningField.html
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum MembershipScreeningFieldType {
+    /// The member has to agree to the server rules.
+    #[serde(rename = "TERMS")] Terms,
+    #[doc(hidden)]
+    __Nonexhaustive,
+}
+
+impl MembershipScreeningFieldType {
+    pub fn name(&self) -> &str {
+        match *self {
+            MembershipScreeningFieldType::Terms => "TERMS",
+            MembershipScreeningFieldType::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
@@ -2,21 +2,27 @@
 
 mod emoji;
 mod guild_id;
+mod guild_preview;
 mod integration;
 mod member;
+mod membership_screening;
 mod partial_guild;
 mod role;
 mod audit_log;
 mod premium_tier;
+mod welcome_screen;
 
 pub use self::emoji::*;
 pub use self::guild_id::*;
+pub use self::guild_preview::*;
 pub use self::integration::*;
 pub use self::member::*;
+pub use self::membership_screening::*;
 pub use self::partial_guild::*;
 pub use self::role::*;
 pub use self::audit_log::*;
 pub use self::premium_tier::*;
+pub use self::welcome_screen::*;
 
 use chrono::{DateTime, Utc};
 use crate::model::prelude::*;
@@ -334,7 +340,7 @@ impl Guild {
     pub async fn audit_logs(
         &self,
         http: impl AsRef<Http>,
-        action_type: Option<u8>,
+        action_type: Option<Action>,
         user_id: Option<UserId>,
         before: Option<AuditLogEntryId>,
         limit: Option<u8>
@@ -2300,6 +2306,7 @@ mod test {
                 joined_at: Some(dt),
                 mute: false,
                 nick: Some("aaaa".to_string()),
+                pending: false,
                 roles: vec1,
                 user: u,
                 _nonexhaustive: (),
@@ -83,7 +83,17 @@ impl Role {
     /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
     #[inline]
     pub async fn delete(&mut self, http: impl AsRef<Http>) -> Result<()> {
-        http.as_ref().delete_role(self.guild_id.0, self.id.0).await
+        http.as_ref().delete_role(self.guild_id.0, self.id.0, None).await
+    }
+
+    /// Deletes the role, with a reason shown in the guild's audit log.
+    ///
+    /// **Note** Requires the [Manage Roles] permission.
+    ///
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub async fn delete_with_reason(&mut self, http: impl AsRef<Http>, reason: &str) -> Result<()> {
+        http.as_ref().delete_role(self.guild_id.0, self.id.0, Some(reason)).await
     }
 
     /// Edits a [`Role`], optionally setting its new fields.
@@ -124,13 +134,18 @@ impl Role {
     #[cfg(feature = "cache")]
     #[deprecated(note = "replaced with the `guild_id` field", since = "0.9.0")]
     pub async fn find_guild(&self, cache: impl AsRef<Cache>) -> Result<GuildId> {
-        for guild in cache.as_ref().guilds.read().await.values() {
-            if guild.roles.contains_key(&RoleId(self.id.0)) {
-                return Ok(guild.id);
+        let role_id = RoleId(self.id.0);
+        let mut guild_id = None;
+
+        cache.as_ref().backend.for_each_guild(&mut |guild| {
+            if guild.roles.contains_key(&role_id) {
+                guild_id = Some(guild.id);
             }
-        }
 
-        Err(Error::Model(ModelError::GuildNotFound))
+            guild_id.is_none()
+        }).await;
+
+        guild_id.ok_or(Error::Model(ModelError::GuildNotFound))
     }
 
     /// Check that the role has the given permission.
@@ -189,17 +204,15 @@ impl RoleId {
     /// [`Role`]: ../guild/struct.Role.html
     #[cfg(feature = "cache")]
     pub async fn to_role_cached(self, cache: impl AsRef<Cache>) -> Option<Role> {
-        for guild in cache.as_ref().guilds.read().await.values() {
-            if !guild.roles.contains_key(&self) {
-                continue;
-            }
+        let mut role = None;
 
-            if let Some(role) = guild.roles.get(&self) {
-                return Some(role.clone());
-            }
-        }
+        cache.as_ref().backend.for_each_guild(&mut |guild| {
+            role = guild.roles.get(&self).cloned();
+
+            role.is_none()
+        }).await;
 
-        None
+        role
     }
 }
 
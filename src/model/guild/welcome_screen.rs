@@ -0,0 +1,33 @@
+use super::*;
+
+/// The welcome screen shown to new members of a community [`Guild`].
+///
+/// [`Guild`]: struct.Guild.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildWelcomeScreen {
+    /// The server description shown in the welcome screen.
+    pub description: Option<String>,
+    /// The channels shown in the welcome screen, up to 5.
+    #[serde(default)]
+    pub welcome_channels: Vec<GuildWelcomeChannel>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// A channel shown in the [`GuildWelcomeScreen`].
+///
+/// [`GuildWelcomeScreen`]: struct.GuildWelcomeScreen.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildWelcomeChannel {
+    /// The channel Id.
+    pub channel_id: ChannelId,
+    /// The description shown for the channel.
+    pub description: String,
+    /// The Id of the emoji, if the emoji is custom.
+    pub emoji_id: Option<EmojiId>,
+    /// The name of the emoji if custom, the unicode character if standard, or
+    /// `None` if no emoji is set.
+    pub emoji_name: Option<String>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
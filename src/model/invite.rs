@@ -104,9 +104,11 @@ impl Invite {
             }
         }
 
-        let map = utils::hashmap_to_json_map(f(CreateInvite::default()).0);
+        let invite = f(CreateInvite::default());
 
-        cache_http.http().create_invite(channel_id.0, &map).await
+        let map = utils::hashmap_to_json_map(invite.0);
+
+        cache_http.http().create_invite(channel_id.0, &map, invite.1.as_deref()).await
     }
 
     /// Deletes the invite.
@@ -121,7 +123,22 @@ impl Invite {
     /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     /// [permission]: ../permissions/index.html
+    #[inline]
     pub async fn delete(&self, cache_http: impl CacheHttp) -> Result<Invite> {
+        self._delete(cache_http, None).await
+    }
+
+    /// Deletes the invite, with a reason shown in the guild's audit log.
+    ///
+    /// Refer to [`delete`] for more information.
+    ///
+    /// [`delete`]: #method.delete
+    #[inline]
+    pub async fn delete_with_reason(&self, cache_http: impl CacheHttp, reason: &str) -> Result<Invite> {
+        self._delete(cache_http, Some(reason)).await
+    }
+
+    async fn _delete(&self, cache_http: impl CacheHttp, reason: Option<&str>) -> Result<Invite> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -134,7 +151,7 @@ impl Invite {
             }
         }
 
-        cache_http.http().as_ref().delete_invite(&self.code).await
+        cache_http.http().as_ref().delete_invite(&self.code, reason).await
     }
 
     /// Gets the information about an invite.
@@ -349,7 +366,22 @@ impl RichInvite {
     /// [`http::delete_invite`]: ../../http/fn.delete_invite.html
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD.html
     /// [permission]: ../permissions/index.html
+    #[inline]
     pub async fn delete(&self, cache_http: impl CacheHttp) -> Result<Invite> {
+        self._delete(cache_http, None).await
+    }
+
+    /// Deletes the invite, with a reason shown in the guild's audit log.
+    ///
+    /// Refer to [`delete`] for more information.
+    ///
+    /// [`delete`]: #method.delete
+    #[inline]
+    pub async fn delete_with_reason(&self, cache_http: impl CacheHttp, reason: &str) -> Result<Invite> {
+        self._delete(cache_http, Some(reason)).await
+    }
+
+    async fn _delete(&self, cache_http: impl CacheHttp, reason: Option<&str>) -> Result<Invite> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -362,7 +394,7 @@ impl RichInvite {
             }
         }
 
-        cache_http.http().as_ref().delete_invite(&self.code).await
+        cache_http.http().as_ref().delete_invite(&self.code, reason).await
     }
 
     /// Returns a URL to use for the invite.
@@ -352,6 +352,7 @@ mod test {
                 joined_at: None,
                 mute: false,
                 nick: None,
+                pending: false,
                 roles: vec![],
                 user: user.clone(),
                 _nonexhaustive: (),
@@ -880,6 +880,7 @@ mod test {
             joined_at: None,
             mute: false,
             nick: Some("Ferris".to_string()),
+            pending: false,
             roles: Vec::new(),
             user: user.clone(),
             _nonexhaustive: (),
@@ -920,9 +921,9 @@ mod test {
 
         guild.members.insert(user.id, member.clone());
         guild.roles.insert(role.id, role.clone());
-        cache.users.write().await.insert(user.id, user.clone());
-        cache.guilds.write().await.insert(guild.id, guild.clone());
-        cache.channels.write().await.insert(channel.id, channel.clone());
+        cache.backend.insert_user(user.clone()).await;
+        cache.backend.insert_guild(guild.clone()).await;
+        cache.backend.insert_guild_channel(channel.clone()).await;
 
 
         let with_user_metions = "<@!100000000000000000> <@!000000000000000000> <@123> <@!123> \
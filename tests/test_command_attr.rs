@@ -0,0 +1,64 @@
+#![cfg(feature = "standard_framework")]
+
+use serenity::client::Context;
+use serenity::framework::standard::{macros::command, Args, CommandResult, Rest};
+use serenity::model::channel::Message;
+use serenity::model::id::UserId;
+
+#[command]
+async fn warn(_ctx: &Context, _msg: &Message, _user: UserId, _amount: Option<u32>, _reason: Rest<String>) -> CommandResult {
+    Ok(())
+}
+
+#[command]
+async fn roll(_: &Context, _: &Message, sides: u32, times: Option<u32>) -> CommandResult {
+    assert!(sides > 0 && times != Some(0));
+
+    Ok(())
+}
+
+#[command]
+#[usage("<anything>")]
+#[min_args(0)]
+async fn note(_: &Context, _: &Message, text: Option<Rest<String>>) -> CommandResult {
+    drop(text);
+
+    Ok(())
+}
+
+#[command]
+async fn raw(_: &Context, _: &Message, _args: Args) -> CommandResult {
+    Ok(())
+}
+
+#[test]
+fn typed_parameters() {
+    let options = WARN_COMMAND.options;
+    assert_eq!(options.usage, Some("<user> [amount] <reason...>"));
+    assert_eq!(options.min_args, Some(2));
+    assert_eq!(options.max_args, None);
+    assert!(WARN_COMMAND.prepare.is_some());
+
+    let options = ROLL_COMMAND.options;
+    assert_eq!(options.usage, Some("<sides> [times]"));
+    assert_eq!(options.min_args, Some(1));
+    assert_eq!(options.max_args, Some(2));
+    assert!(ROLL_COMMAND.prepare.is_some());
+}
+
+#[test]
+fn typed_parameters_keep_given_options() {
+    let options = NOTE_COMMAND.options;
+    assert_eq!(options.usage, Some("<anything>"));
+    assert_eq!(options.min_args, Some(0));
+    assert_eq!(options.max_args, None);
+}
+
+#[test]
+fn raw_arguments() {
+    let options = RAW_COMMAND.options;
+    assert_eq!(options.usage, None);
+    assert_eq!(options.min_args, None);
+    assert_eq!(options.max_args, None);
+    assert!(RAW_COMMAND.prepare.is_none());
+}
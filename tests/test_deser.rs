@@ -29,6 +29,85 @@ fn activity() {
     p!(Activity, "activity_3");
 }
 
+#[test]
+fn audit_log_entry() {
+    let entry = p!(AuditLogEntry, "audit_log_entry_1");
+    let changes = entry.changes.unwrap();
+
+    assert_eq!(entry.action, Action::Member(ActionMember::RoleUpdate));
+
+    match &changes[0] {
+        Change::RolesAdded { old: None, new: Some(roles) } => {
+            assert_eq!(roles[0].id, RoleId(182894738100322304));
+            assert_eq!(roles[0].name, "Moderator");
+        },
+        other => panic!("unexpected change: {:?}", other),
+    }
+
+    match &changes[1] {
+        Change::Nick { old, new } => {
+            assert_eq!(old.as_deref(), Some("crab"));
+            assert_eq!(new.as_deref(), Some("ferris"));
+        },
+        other => panic!("unexpected change: {:?}", other),
+    }
+
+    match &changes[2] {
+        Change::Permissions { old: Some(old), new: Some(new) } => {
+            assert!(!old.administrator());
+            assert!(new.administrator());
+        },
+        other => panic!("unexpected change: {:?}", other),
+    }
+
+    assert_eq!(changes[3].key(), "some_future_key");
+}
+
+#[test]
+fn audit_logs() {
+    let f = File::open("./tests/resources/audit_log_1.json").expect("Opening test file");
+    let logs: AuditLogs = serde_json::from_reader(f).expect("Deserializing file");
+
+    let role_create = &logs.entries[&AuditLogEntryId(771448152234541096)];
+    let changes = role_create.changes.as_ref().unwrap();
+
+    assert_eq!(role_create.action, Action::Role(ActionRole::Create));
+
+    match &changes[1] {
+        Change::Permissions { old: None, new: Some(new) } => {
+            assert_eq!(new.bits(), 104324673);
+        },
+        other => panic!("unexpected change: {:?}", other),
+    }
+
+    // The overwrite uses a format the library does not know, so it is kept
+    // raw instead of failing the entry.
+    let channel_create = &logs.entries[&AuditLogEntryId(771448473941196800)];
+    let changes = channel_create.changes.as_ref().unwrap();
+
+    match &changes[2] {
+        Change::Other { name, old: None, new: Some(new) } => {
+            assert_eq!(name, "permission_overwrites");
+            assert_eq!(new[0]["deny"], "2048");
+        },
+        other => panic!("unexpected change: {:?}", other),
+    }
+
+    let overwrite_create = &logs.entries[&AuditLogEntryId(771448613523177492)];
+    let changes = overwrite_create.changes.as_ref().unwrap();
+
+    match (&changes[0], &changes[2]) {
+        (Change::Id { new: Some(id), .. }, Change::Allow { new: Some(allow), .. }) => {
+            assert_eq!(*id, 771448151924555807);
+            assert!(allow.read_messages() && allow.send_messages());
+        },
+        other => panic!("unexpected changes: {:?}", other),
+    }
+
+    assert_eq!(overwrite_create.options.as_ref().unwrap().id, Some(771448151924555807));
+    assert_eq!(logs.users[0].name, "ferris");
+}
+
 #[test]
 fn channel_create() {
     p!(ChannelCreateEvent, "channel_create_1");
@@ -106,6 +185,15 @@ fn guild_member_remove() {
 #[test]
 fn guild_member_update() {
     p!(GuildMemberUpdateEvent, "guild_member_update_1");
+
+    // member which has not yet passed membership screening
+    let event = p!(GuildMemberUpdateEvent, "guild_member_update_2");
+    assert!(event.pending);
+}
+
+#[test]
+fn guild_preview() {
+    p!(GuildPreview, "guild_preview_1");
 }
 
 #[test]
@@ -128,6 +216,11 @@ fn guild_update() {
     p!(GuildUpdateEvent, "guild_update_1");
 }
 
+#[test]
+fn guild_welcome_screen() {
+    p!(GuildWelcomeScreen, "guild_welcome_screen_1");
+}
+
 #[test]
 fn message_create() {
     // standard
@@ -138,6 +231,16 @@ fn message_create() {
 
     // message from guild with partial member data
     p!(MessageCreateEvent, "message_create_3");
+
+    // inline reply with the referenced message
+    let event = p!(MessageCreateEvent, "message_create_4");
+    assert_eq!(event.message.kind, MessageType::InlineReply);
+    assert_eq!(event.message.referenced_message.map(|m| m.id), Some(MessageId(302917639565475840)));
+}
+
+#[test]
+fn membership_screening() {
+    p!(MembershipScreening, "membership_screening_1");
 }
 
 #[test]